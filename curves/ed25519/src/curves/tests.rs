@@ -2,3 +2,6 @@ use crate::*;
 use ark_algebra_test_templates::*;
 
 test_group!(te; EdwardsProjective; te);
+
+#[cfg(feature = "ct")]
+test_constant_time!(te_ct; EdwardsProjective; group);
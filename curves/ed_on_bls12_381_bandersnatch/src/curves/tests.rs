@@ -2,3 +2,21 @@ use crate::*;
 use ark_algebra_test_templates::*;
 
 test_group!(te; EdwardsProjective; te);
+
+#[cfg(feature = "ct")]
+test_constant_time!(te_ct; EdwardsProjective; group);
+#[cfg(feature = "ct")]
+test_constant_time!(sw_ct; SWProjective; group);
+
+#[cfg(all(feature = "ct", debug_assertions))]
+#[test]
+#[should_panic(expected = "prime-order subgroup")]
+fn sw_mul_ct_outside_subgroup() {
+    // The curve has cofactor 4, so the complete formulas used by `mul_ct`
+    // only apply in the prime-order subgroup.
+    let point = (1u64..)
+        .filter_map(|x| SWAffine::get_point_from_x_unchecked(Fq::from(x), false))
+        .find(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    SWProjective::from(point).mul_ct(&Fr::from(3u64));
+}
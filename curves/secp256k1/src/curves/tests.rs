@@ -2,3 +2,6 @@ use crate::Projective;
 use ark_algebra_test_templates::*;
 
 test_group!(g1; Projective; sw);
+
+#[cfg(feature = "ct")]
+test_constant_time!(g1_ct; Projective; group);
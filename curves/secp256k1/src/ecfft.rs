@@ -0,0 +1,156 @@
+//! Auxiliary curves for the ECFFT of [`ark_ec::ecfft`] over the base and
+//! scalar fields of secp256k1, neither of which has a large multiplicative
+//! subgroup of smooth order.
+//!
+//! Each auxiliary curve has the form `y^2 = (x - e_1)(x - e_2)(x - e_3)`, and
+//! its generator, of order `2^(MAX_LOG_SIZE + 1)`, was found by halving a
+//! point `(e_i, 0)` of order two, as described in [`ark_ec::ecfft`].
+
+use ark_ec::{
+    ecfft::EcfftDomain,
+    models::CurveConfig,
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{Field, MontFp};
+
+use crate::{Fq, Fr};
+
+/// The base-2 logarithm of the size of the largest domains of [`fq_domain`]
+/// and [`fr_domain`].
+pub const MAX_LOG_SIZE: u32 = 15;
+
+/// Returns the ECFFT domain over `Fq` for polynomials with at most
+/// `2^log_size` coefficients, or `None` if `log_size` is larger than
+/// [`MAX_LOG_SIZE`].
+pub fn fq_domain(log_size: u32) -> Option<EcfftDomain<Fq>> {
+    domain(log_size, FQ_OFFSET)
+}
+
+/// Returns the ECFFT domain over `Fr` for polynomials with at most
+/// `2^log_size` coefficients, or `None` if `log_size` is larger than
+/// [`MAX_LOG_SIZE`].
+pub fn fr_domain(log_size: u32) -> Option<EcfftDomain<Fr>> {
+    domain(log_size, FR_OFFSET)
+}
+
+/// Builds the domain of the coset `offset + <2^(MAX_LOG_SIZE - log_size) G>`,
+/// whose subgroup has order `2^(log_size + 1)`.
+fn domain<P: SWCurveConfig>(log_size: u32, offset: Affine<P>) -> Option<EcfftDomain<P::BaseField>> {
+    let cofactor = 1u64 << MAX_LOG_SIZE.checked_sub(log_size)?;
+    let generator = P::GENERATOR.mul_bigint([cofactor]).into_affine();
+    EcfftDomain::new(generator, offset)
+}
+
+/// The auxiliary curve over `Fq`.
+///
+/// The ECFFT only uses the group law, so the scalar field and cofactor are
+/// placeholders.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+struct FqAuxConfig;
+
+impl CurveConfig for FqAuxConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[1];
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+impl SWCurveConfig for FqAuxConfig {
+    const COEFF_A: Fq =
+        MontFp!("11479600754246320881231915674419700001288092948590138236026228927818094209062");
+    const COEFF_B: Fq =
+        MontFp!("85331498799882104662286554286740490200854692203441953335082590659593350456315");
+    const GENERATOR: Affine<Self> = Affine::new_unchecked(
+        MontFp!("3276400089048659157388312586823231110442291319474595901940569178028941098090"),
+        MontFp!("87948640929189094108056071877758273464239379519173972356892381109016633916133"),
+    );
+}
+
+const FQ_OFFSET: Affine<FqAuxConfig> = Affine::new_unchecked(
+    MontFp!("2"),
+    MontFp!("101938899889424704836660132599231690481785509605229948354905463977471115968569"),
+);
+
+/// The auxiliary curve over `Fr`.
+///
+/// The ECFFT only uses the group law, so the scalar field and cofactor are
+/// placeholders.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+struct FrAuxConfig;
+
+impl CurveConfig for FrAuxConfig {
+    type BaseField = Fr;
+    type ScalarField = Fq;
+
+    const COFACTOR: &'static [u64] = &[1];
+    const COFACTOR_INV: Fq = Fq::ONE;
+}
+
+impl SWCurveConfig for FrAuxConfig {
+    const COEFF_A: Fr =
+        MontFp!("22310956933288651386806833619606469268116572276907463015415824098587697257592");
+    const COEFF_B: Fr =
+        MontFp!("48313091817103940367077311193874132871981605067515529742780755398324843179067");
+    const GENERATOR: Affine<Self> = Affine::new_unchecked(
+        MontFp!("65171568055243501521715602550951871992043897938825783496802425938600491772635"),
+        MontFp!("2446680791533386365286149048230288604501862486020652643530335885409084448923"),
+    );
+}
+
+const FR_OFFSET: Affine<FrAuxConfig> = Affine::new_unchecked(
+    MontFp!("1"),
+    MontFp!("102256391095993649738165046218702279917768026503928908450810360340586285067357"),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::AdditiveGroup;
+    use ark_ff::{PrimeField, Zero};
+    use ark_std::{test_rng, vec::*};
+
+    fn evaluate<F: Field>(coeffs: &[F], set: &[F]) -> Vec<F> {
+        set.iter()
+            .map(|x| coeffs.iter().rev().fold(F::zero(), |acc, c| acc * x + c))
+            .collect()
+    }
+
+    fn check_curve<P: SWCurveConfig>(offset: Affine<P>) {
+        assert!(P::GENERATOR.is_on_curve());
+        assert!(offset.is_on_curve());
+        let mut point = P::GENERATOR.into_group();
+        for _ in 0..MAX_LOG_SIZE {
+            point.double_in_place();
+        }
+        assert!(!point.is_zero());
+        assert!(point.double().is_zero());
+    }
+
+    fn check_domains<F: PrimeField>(domain: impl Fn(u32) -> Option<EcfftDomain<F>>) {
+        let mut rng = test_rng();
+        for log_size in 0..=8 {
+            let domain = domain(log_size).unwrap();
+            assert_eq!(domain.log_size(), log_size);
+            let coeffs: Vec<F> = (0..domain.size()).map(|_| F::rand(&mut rng)).collect();
+            let evals = domain.enter(&coeffs);
+            assert_eq!(evals, evaluate(&coeffs, domain.s()));
+            assert_eq!(domain.extend(&evals), evaluate(&coeffs, domain.s_prime()));
+            assert_eq!(domain.exit(&evals), coeffs);
+        }
+        assert!(domain(MAX_LOG_SIZE + 1).is_none());
+    }
+
+    #[test]
+    fn test_fq_domain() {
+        check_curve(FQ_OFFSET);
+        check_domains(fq_domain);
+    }
+
+    #[test]
+    fn test_fr_domain() {
+        check_curve(FR_OFFSET);
+        check_domains(fr_domain);
+    }
+}
@@ -21,6 +21,7 @@
 #[cfg(feature = "r1cs")]
 pub mod constraints;
 mod curves;
+pub mod ecfft;
 mod fields;
 
 pub use curves::*;
@@ -0,0 +1,596 @@
+//! Fast polynomial arithmetic over fields without smooth multiplicative
+//! subgroups, following the ECFFT of [\[BCKL21\]].
+//!
+//! Instead of a multiplicative subgroup, the evaluation domain is the set of
+//! `x`-coordinates of a coset `R + <G>` of a cyclic subgroup of order `2^n` of
+//! an auxiliary short Weierstrass curve `E_0`. The chain of 2-isogenies
+//! `E_0 -> E_1 -> ...` whose kernels lie in `<G>` maps this set onto the
+//! `x`-coordinates of smaller and smaller cosets, two to one, which plays the
+//! role of the squaring map of the usual FFT.
+//!
+//! Splitting the domain `L` into the `x`-coordinates `S` of `R + <2G>` and
+//! `S'` of `R + G + <2G>`, of size `2^(n-1)` each, [`EcfftDomain`] provides:
+//! - [`EcfftDomain::extend`], which maps the evaluations over `S` of a
+//!   polynomial of degree less than `|S|` to its evaluations over `S'`, in
+//!   `O(n log n)` operations;
+//! - [`EcfftDomain::enter`] and [`EcfftDomain::exit`], which convert between
+//!   the coefficients of such a polynomial and its evaluations over `S`, in
+//!   `O(n log^2 n)` operations.
+//!
+//! # Auxiliary curves
+//!
+//! A suitable curve is found without counting points: for random `e_1, e_2`
+//! and `e_3 = -e_1 - e_2`, the curve `y^2 = (x - e_1)(x - e_2)(x - e_3)` has
+//! its three points `(e_i, 0)` of order two. A point `(x, y)` is the double
+//! of another iff every `x - e_i` is a square, and then the `x`-coordinates
+//! of its halves are the values of `x + r_1 r_2 + r_1 r_3 + r_2 r_3` for the
+//! square roots `r_i` of `x - e_i`. Halving a point of order two `n - 1`
+//! times, when the curve allows it, yields a point `G` of order `2^n`, and
+//! any point `R` such that the `x`-coordinates of `R + <G>` are distinct and
+//! those of `R + <2G>` are nonzero completes the domain. The `ark-secp256k1`
+//! crate ships such curves for both fields of secp256k1.
+//!
+//! - [\[BCKL21\]] Ben-Sasson, E., Carmon, D., Kopparty, S., Levit, D. (2021).
+//!   Elliptic Curve Fast Fourier Transform (ECFFT) Part I: Fast polynomial
+//!   algorithms over all finite fields. <https://arxiv.org/abs/2107.08473>
+
+use crate::{
+    models::short_weierstrass::{Affine, Projective, SWCurveConfig},
+    AdditiveGroup, AffineRepr, CurveGroup,
+};
+use ark_ff::{batch_inversion, Field};
+use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial};
+use ark_std::{vec, vec::*, Zero};
+
+/// A `2 x 2` matrix `[[m[0], m[1]], [m[2], m[3]]]`.
+type Matrix<F> = [F; 4];
+
+/// The matrices of one step of [`EcfftDomain::extend`].
+///
+/// At this step, the points `s_j` and `s_{j + m/2}` of the current set `S` of
+/// size `m` are the two preimages of the `j`-th point `t_j` of the next set
+/// under the isogeny `psi(X) = u(X) / v(X)`, and every polynomial `P` of degree
+/// less than `m` can be written uniquely as
+/// `P(X) = (P_0(psi(X)) + X * P_1(psi(X))) * v(X)^(m/2 - 1)`, with `P_0` and
+/// `P_1` of degree less than `m/2`. The `j`-th matrix of `S` maps
+/// `(P_0(t_j), P_1(t_j))` to `(P(s_j), P(s_{j + m/2}))`.
+#[derive(Clone, Debug)]
+struct ExtendStep<F: Field> {
+    s: Vec<Matrix<F>>,
+    s_inv: Vec<Matrix<F>>,
+    s_prime: Vec<Matrix<F>>,
+    s_prime_inv: Vec<Matrix<F>>,
+}
+
+impl<F: Field> ExtendStep<F> {
+    /// Computes the matrices of the pairs of `set`, given the `x`-coordinate of
+    /// the kernel of the isogeny `psi(X) = X + t / (X - x0)`, so that
+    /// `v(X) = X - x0`.
+    fn matrices(set: &[F], x0: F) -> (Vec<Matrix<F>>, Vec<Matrix<F>>) {
+        let half = set.len() / 2;
+        let exp = [half as u64 - 1];
+        let (first, second) = set.split_at(half);
+        let matrices: Vec<Matrix<F>> = first
+            .iter()
+            .zip(second)
+            .map(|(&s0, &s1)| {
+                let (v0, v1) = ((s0 - x0).pow(exp), (s1 - x0).pow(exp));
+                [v0, s0 * v0, v1, s1 * v1]
+            })
+            .collect();
+        let mut dets: Vec<F> = first
+            .iter()
+            .zip(second)
+            .zip(&matrices)
+            .map(|((&s0, &s1), &[v0, _, v1, _])| v0 * v1 * (s1 - s0))
+            .collect();
+        batch_inversion(&mut dets);
+        let inverses = matrices
+            .iter()
+            .zip(dets)
+            .map(|(m, d)| [m[3] * d, -m[1] * d, -m[2] * d, m[0] * d])
+            .collect();
+        (matrices, inverses)
+    }
+
+    fn new(s: &[F], s_prime: &[F], x0: F) -> Self {
+        let (s, s_inv) = Self::matrices(s, x0);
+        let (s_prime, s_prime_inv) = Self::matrices(s_prime, x0);
+        Self {
+            s,
+            s_inv,
+            s_prime,
+            s_prime_inv,
+        }
+    }
+}
+
+/// The precomputed values for the sub-domain of the `x`-coordinates of
+/// `R + <2^k G>`, whose sets `S_k` and `S'_k` have size `m = 2^(n-k-1)`.
+///
+/// Since `S_k` is the whole domain of the next sub-domain, it splits into
+/// `A = S_{k+1}` and `A' = S'_{k+1}`, of size `h = m/2`, the points of `A` and
+/// `A'` being interleaved in `S_k`.
+#[derive(Clone, Debug)]
+struct SubDomain<F: Field> {
+    /// The steps of [`EcfftDomain::extend`] from `S_k` to `S'_k`.
+    steps: Vec<ExtendStep<F>>,
+    /// `s^h` for `s` in `S_k`.
+    s_pow: Vec<F>,
+    /// `a^(-h)` for `a` in `A`.
+    a_pow_inv: Vec<F>,
+    /// `Z_A(a')^(-1)` for `a'` in `A'`, where `Z_A` is the vanishing
+    /// polynomial of `A`.
+    z_inv: Vec<F>,
+    /// The evaluations over `S_k` of `Z_A^2 mod X^h`.
+    w: Vec<F>,
+}
+
+/// An ECFFT domain, built from a cyclic subgroup `<G>` of order `2^n` and a
+/// point `R` of an auxiliary short Weierstrass curve over `F`.
+///
+/// The polynomials handled by the domain have degree less than
+/// [`size`](Self::size) `= 2^(n-1)`, and are represented either by their
+/// coefficients or by their evaluations over [`s`](Self::s).
+#[derive(Clone, Debug)]
+pub struct EcfftDomain<F: Field> {
+    s: Vec<F>,
+    s_prime: Vec<F>,
+    /// The sub-domains `R + <2^k G>`, for `k = 0, ..., n - 1`.
+    sub_domains: Vec<SubDomain<F>>,
+}
+
+impl<F: Field> EcfftDomain<F> {
+    /// Builds the domain of the `x`-coordinates of `offset + <generator>`.
+    ///
+    /// Returns `None` if the order of `generator` is not a power of two
+    /// greater than one, if these `x`-coordinates are not pairwise distinct,
+    /// which happens iff `2 * offset` lies in `<generator>`, or if `S` contains
+    /// zero.
+    pub fn new<P: SWCurveConfig<BaseField = F>>(
+        generator: Affine<P>,
+        offset: Affine<P>,
+    ) -> Option<Self> {
+        // `doublings[i] = 2^i * generator`.
+        let mut doublings = vec![generator.into_group()];
+        while !doublings.last().unwrap().is_zero() {
+            if doublings.len() == usize::BITS as usize {
+                return None;
+            }
+            doublings.push(doublings.last().unwrap().double());
+        }
+        let log_size = doublings.len() - 1;
+        if log_size == 0 {
+            return None;
+        }
+
+        let mut coset = Vec::with_capacity(1 << log_size);
+        let mut point = offset.into_group();
+        for _ in 0..1usize << log_size {
+            coset.push(point);
+            point += &generator;
+        }
+        if coset.iter().any(Projective::is_zero) {
+            return None;
+        }
+        let domain: Vec<F> = Projective::normalize_batch(&coset)
+            .into_iter()
+            .map(|p| p.x)
+            .collect();
+        let mut sorted = domain.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        if sorted.len() != domain.len() || domain.iter().step_by(2).any(|x| x.is_zero()) {
+            return None;
+        }
+
+        // The kernel of the `i`-th isogeny is generated by the image of
+        // `2^(n-1-i) * generator`, and maps the `i`-th domain, of size
+        // `2^(n-i)`, two to one onto the next one.
+        let mut kernels: Vec<F> = Projective::normalize_batch(&doublings[1..log_size])
+            .into_iter()
+            .rev()
+            .map(|p| p.x)
+            .collect();
+        let mut domains = vec![domain];
+        let mut a = P::COEFF_A;
+        for i in 0..log_size.saturating_sub(2) {
+            let x0 = kernels[i];
+            let t = x0.square().double() + x0.square() + a;
+            a -= t.double().double() + t;
+            let current = &domains[i];
+            let mut next: Vec<F> = current[..current.len() / 2].to_vec();
+            next.extend_from_slice(&kernels[i + 1..]);
+            let mut denominators: Vec<F> = next.iter().map(|&x| x - x0).collect();
+            batch_inversion(&mut denominators);
+            for (x, d) in next.iter_mut().zip(denominators) {
+                *x += t * d;
+            }
+            kernels.truncate(i + 1);
+            kernels.extend_from_slice(&next[current.len() / 2..]);
+            next.truncate(current.len() / 2);
+            domains.push(next);
+        }
+
+        let sub_domains = (0..log_size)
+            .map(|k| {
+                let steps = domains[..log_size - 1 - k]
+                    .iter()
+                    .zip(&kernels)
+                    .map(|(domain, &x0)| {
+                        let s: Vec<F> = domain.iter().step_by(2 << k).copied().collect();
+                        let s_prime: Vec<F> =
+                            domain[1 << k..].iter().step_by(2 << k).copied().collect();
+                        ExtendStep::new(&s, &s_prime, x0)
+                    })
+                    .collect();
+                SubDomain {
+                    steps,
+                    s_pow: Vec::new(),
+                    a_pow_inv: Vec::new(),
+                    z_inv: Vec::new(),
+                    w: Vec::new(),
+                }
+            })
+            .collect();
+        let mut result = Self {
+            s: domains[0].iter().step_by(2).copied().collect(),
+            s_prime: domains[0].iter().skip(1).step_by(2).copied().collect(),
+            sub_domains,
+        };
+
+        // The values used by `enter` and `exit` on the `k`-th sub-domain rely
+        // on these operations on the next one.
+        for k in (0..log_size - 1).rev() {
+            let h = 1 << (log_size - k - 2);
+            let s_k: Vec<F> = result.s.iter().step_by(1 << k).copied().collect();
+            let s_pow: Vec<F> = s_k.iter().map(|s| s.pow([h as u64])).collect();
+            let mut a_pow_inv: Vec<F> = s_pow.iter().step_by(2).copied().collect();
+            batch_inversion(&mut a_pow_inv);
+
+            // `Z_A = X^h + Y`, where `Y` has degree less than `h` and is
+            // equal to `-X^h` over `A`.
+            let y: Vec<F> = s_pow.iter().step_by(2).map(|&s| -s).collect();
+            let mut z_inv = result.extend_impl(k + 1, &y, false);
+            for (z, s) in z_inv.iter_mut().zip(s_pow.iter().skip(1).step_by(2)) {
+                *z += s;
+            }
+            batch_inversion(&mut z_inv);
+
+            // `Z_A^2 mod X^h = Y^2 mod X^h`.
+            let y = result.exit_impl(k + 1, &y);
+            let mut y_squared = karatsuba(&y, &y);
+            y_squared.truncate(h);
+            let w_a = result.enter_impl(k + 1, &y_squared);
+            let w_a_prime = result.extend_impl(k + 1, &w_a, false);
+
+            let sub_domain = &mut result.sub_domains[k];
+            sub_domain.s_pow = s_pow;
+            sub_domain.a_pow_inv = a_pow_inv;
+            sub_domain.z_inv = z_inv;
+            sub_domain.w = interleave(&w_a, &w_a_prime);
+        }
+        Some(result)
+    }
+
+    /// Returns the number of points of `S`, which bounds the number of
+    /// coefficients of the polynomials handled by the domain.
+    pub fn size(&self) -> usize {
+        self.s.len()
+    }
+
+    /// Returns the base-2 logarithm of [`size`](Self::size).
+    pub fn log_size(&self) -> u32 {
+        self.s.len().trailing_zeros()
+    }
+
+    /// Returns the evaluation set `S`.
+    pub fn s(&self) -> &[F] {
+        &self.s
+    }
+
+    /// Returns the extension set `S'`.
+    pub fn s_prime(&self) -> &[F] {
+        &self.s_prime
+    }
+
+    /// Given the evaluations over `S` of a polynomial of degree less than
+    /// [`size`](Self::size), returns its evaluations over `S'`.
+    ///
+    /// This is a low-degree extension by a factor of two.
+    pub fn extend(&self, evals: &[F]) -> Vec<F> {
+        assert_eq!(evals.len(), self.size(), "invalid number of evaluations");
+        self.extend_impl(0, evals, false)
+    }
+
+    /// Returns the evaluations over `S` of the polynomial with coefficients
+    /// `coeffs`, of which there must be at most [`size`](Self::size).
+    pub fn enter(&self, coeffs: &[F]) -> Vec<F> {
+        assert!(coeffs.len() <= self.size(), "too many coefficients");
+        let mut coeffs = coeffs.to_vec();
+        coeffs.resize(self.size(), F::zero());
+        self.enter_impl(0, &coeffs)
+    }
+
+    /// Returns the [`size`](Self::size) coefficients of the polynomial of
+    /// degree less than [`size`](Self::size) with evaluations `evals` over `S`.
+    pub fn exit(&self, evals: &[F]) -> Vec<F> {
+        assert_eq!(evals.len(), self.size(), "invalid number of evaluations");
+        self.exit_impl(0, evals)
+    }
+
+    /// Multiplies two polynomials whose product has degree less than
+    /// [`size`](Self::size), by multiplying their evaluations over `S`.
+    pub fn mul_polynomials(
+        &self,
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+    ) -> DensePolynomial<F> {
+        if a.is_zero() || b.is_zero() {
+            return DensePolynomial::zero();
+        }
+        assert!(
+            a.coeffs.len() + b.coeffs.len() - 1 <= self.size(),
+            "the product is too large for the domain"
+        );
+        let mut evals = self.enter(&a.coeffs);
+        for (e, b) in evals.iter_mut().zip(self.enter(&b.coeffs)) {
+            *e *= b;
+        }
+        DensePolynomial::from_coefficients_vec(self.exit(&evals))
+    }
+
+    /// Runs [`extend`](Self::extend) on the `k`-th sub-domain, from `S'_k`
+    /// to `S_k` if `reverse` is set.
+    fn extend_impl(&self, k: usize, evals: &[F], reverse: bool) -> Vec<F> {
+        extend_steps(&self.sub_domains[k].steps, evals, reverse)
+    }
+
+    /// Runs [`enter`](Self::enter) on the `k`-th sub-domain, by writing
+    /// `P = Q_0 + X^h Q_1` and evaluating `Q_0` and `Q_1` over
+    /// `S_k = A + A'`.
+    fn enter_impl(&self, k: usize, coeffs: &[F]) -> Vec<F> {
+        if coeffs.len() == 1 {
+            return coeffs.to_vec();
+        }
+        let (q0, q1) = coeffs.split_at(coeffs.len() / 2);
+        let (q0_a, q1_a) = (self.enter_impl(k + 1, q0), self.enter_impl(k + 1, q1));
+        let q0_a_prime = self.extend_impl(k + 1, &q0_a, false);
+        let q1_a_prime = self.extend_impl(k + 1, &q1_a, false);
+        let mut evals = interleave(&q0_a, &q0_a_prime);
+        let q1 = interleave(&q1_a, &q1_a_prime);
+        for ((e, q1), s) in evals.iter_mut().zip(q1).zip(&self.sub_domains[k].s_pow) {
+            *e += q1 * s;
+        }
+        evals
+    }
+
+    /// Runs [`exit`](Self::exit) on the `k`-th sub-domain, by computing the
+    /// evaluations over `A` of `Q_0 = P mod X^h` and `Q_1 = (P - Q_0) / X^h`.
+    fn exit_impl(&self, k: usize, evals: &[F]) -> Vec<F> {
+        if evals.len() == 1 {
+            return evals.to_vec();
+        }
+        let sub_domain = &self.sub_domains[k];
+        // `REDC(REDC(P) * (Z_A^2 mod X^h)) = P mod X^h`.
+        let (p_a, p_a_prime) = deinterleave(evals);
+        let (r_a, r_a_prime) = self.redc(k, &p_a, &p_a_prime);
+        let (mut u_a, mut u_a_prime) = (r_a, r_a_prime);
+        let (w_a, w_a_prime) = deinterleave(&sub_domain.w);
+        u_a.iter_mut().zip(w_a).for_each(|(u, w)| *u *= w);
+        u_a_prime
+            .iter_mut()
+            .zip(w_a_prime)
+            .for_each(|(u, w)| *u *= w);
+        let (q0_a, _) = self.redc(k, &u_a, &u_a_prime);
+
+        let q1_a: Vec<F> = p_a
+            .iter()
+            .zip(&q0_a)
+            .zip(&sub_domain.a_pow_inv)
+            .map(|((&p, &q0), &s)| (p - q0) * s)
+            .collect();
+        let mut coeffs = self.exit_impl(k + 1, &q0_a);
+        coeffs.extend(self.exit_impl(k + 1, &q1_a));
+        coeffs
+    }
+
+    /// Given the evaluations over `A` and `A'` of a polynomial `P` of degree
+    /// less than `2h` on the `k`-th sub-domain, returns the evaluations over
+    /// `A` and `A'` of `P * Z_A^(-1) mod X^h`.
+    ///
+    /// This is Montgomery's reduction: `T = -P / X^h mod Z_A` is known over
+    /// `A`, and `(P + T X^h) / Z_A` has degree less than `h`.
+    fn redc(&self, k: usize, p_a: &[F], p_a_prime: &[F]) -> (Vec<F>, Vec<F>) {
+        let sub_domain = &self.sub_domains[k];
+        let t_a: Vec<F> = p_a
+            .iter()
+            .zip(&sub_domain.a_pow_inv)
+            .map(|(&p, &s)| -p * s)
+            .collect();
+        let t_a_prime = self.extend_impl(k + 1, &t_a, false);
+        let r_a_prime: Vec<F> = p_a_prime
+            .iter()
+            .zip(t_a_prime)
+            .zip(sub_domain.s_pow.iter().skip(1).step_by(2))
+            .zip(&sub_domain.z_inv)
+            .map(|(((&p, t), &s), &z)| (p + t * s) * z)
+            .collect();
+        let r_a = self.extend_impl(k + 1, &r_a_prime, true);
+        (r_a, r_a_prime)
+    }
+}
+
+/// Runs the steps of [`EcfftDomain::extend`] on `evals`, from `S` to `S'`, or
+/// from `S'` to `S` if `reverse` is set.
+fn extend_steps<F: Field>(steps: &[ExtendStep<F>], evals: &[F], reverse: bool) -> Vec<F> {
+    let Some((step, steps)) = steps.split_first() else {
+        return evals.to_vec();
+    };
+    let (from_inv, to) = if reverse {
+        (&step.s_prime_inv, &step.s)
+    } else {
+        (&step.s_inv, &step.s_prime)
+    };
+    let (first, second) = evals.split_at(evals.len() / 2);
+    let (p0, p1): (Vec<F>, Vec<F>) = first
+        .iter()
+        .zip(second)
+        .zip(from_inv)
+        .map(|((&e0, &e1), m)| (m[0] * e0 + m[1] * e1, m[2] * e0 + m[3] * e1))
+        .unzip();
+    let p0 = extend_steps(steps, &p0, reverse);
+    let p1 = extend_steps(steps, &p1, reverse);
+    let (mut first, second): (Vec<F>, Vec<F>) = p0
+        .into_iter()
+        .zip(p1)
+        .zip(to)
+        .map(|((p0, p1), m)| (m[0] * p0 + m[1] * p1, m[2] * p0 + m[3] * p1))
+        .unzip();
+    first.extend(second);
+    first
+}
+
+/// Returns `[a[0], b[0], a[1], b[1], ...]`.
+fn interleave<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    a.iter().zip(b).flat_map(|(&a, &b)| [a, b]).collect()
+}
+
+/// Splits `v` into its elements of even and odd indices.
+fn deinterleave<F: Field>(v: &[F]) -> (Vec<F>, Vec<F>) {
+    v.chunks(2).map(|c| (c[0], c[1])).unzip()
+}
+
+/// Multiplies two polynomials of the same length with Karatsuba's algorithm.
+fn karatsuba<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let n = a.len();
+    if n <= 16 {
+        let mut result = vec![F::zero(); 2 * n - 1];
+        for (i, a) in a.iter().enumerate() {
+            for (r, b) in result[i..].iter_mut().zip(b) {
+                *r += *a * b;
+            }
+        }
+        return result;
+    }
+    let half = n / 2;
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half);
+    let z0 = karatsuba(a0, b0);
+    let z2 = karatsuba(a1, b1);
+    let mut a01 = a1.to_vec();
+    let mut b01 = b1.to_vec();
+    a01.iter_mut().zip(a0).for_each(|(x, y)| *x += y);
+    b01.iter_mut().zip(b0).for_each(|(x, y)| *x += y);
+    let z1 = karatsuba(&a01, &b01);
+    let mut result = vec![F::zero(); 2 * n - 1];
+    for (i, ((z0, z1), z2)) in z0.iter().zip(&z1).zip(&z2).enumerate() {
+        result[i] += z0;
+        result[i + half] += *z1 - z0 - z2;
+        result[i + 2 * half] += z2;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CurveConfig;
+    use ark_ff::MontFp;
+    use ark_poly::Polynomial;
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::secp256k1::{Fq, Fr};
+
+    /// An auxiliary curve over the base field of secp256k1, with a point of
+    /// order `2^10`.
+    ///
+    /// The ECFFT only uses the group law, so the scalar field and cofactor
+    /// are placeholders.
+    #[derive(Clone, Default, PartialEq, Eq)]
+    struct AuxConfig;
+
+    impl CurveConfig for AuxConfig {
+        type BaseField = Fq;
+        type ScalarField = Fr;
+
+        const COFACTOR: &'static [u64] = &[1];
+        const COFACTOR_INV: Fr = Fr::ONE;
+    }
+
+    impl SWCurveConfig for AuxConfig {
+        const COEFF_A: Fq = MontFp!(
+            "91171476818373115222769168694894421606629374331395953767909034242282408490135"
+        );
+        const COEFF_B: Fq = MontFp!(
+            "27878978231692961234577499602380555642306302698754645167922502002935804357950"
+        );
+        const GENERATOR: Affine<Self> = Affine::new_unchecked(
+            MontFp!(
+                "42273454184865133970827353695726935672085420292817878415302104976515824373762"
+            ),
+            MontFp!(
+                "46025020933107208118034888240931214180339172331398182349876455934466261896391"
+            ),
+        );
+    }
+
+    const OFFSET: Affine<AuxConfig> = Affine::new_unchecked(
+        Fq::ONE,
+        MontFp!("76149287877270190751047517489708135944655219648734357696607172668746177157294"),
+    );
+
+    fn domain(log_size: u32) -> EcfftDomain<Fq> {
+        let generator = AuxConfig::GENERATOR * Fr::from(1u64 << (10 - log_size));
+        EcfftDomain::new(generator.into_affine(), OFFSET).unwrap()
+    }
+
+    fn evaluate(coeffs: &[Fq], set: &[Fq]) -> Vec<Fq> {
+        let poly = DensePolynomial::from_coefficients_slice(coeffs);
+        set.iter().map(|x| poly.evaluate(x)).collect()
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut rng = test_rng();
+        for log_size in 1..=10 {
+            let domain = domain(log_size);
+            assert_eq!(domain.size(), 1 << (log_size - 1));
+            let coeffs: Vec<Fq> = (0..domain.size()).map(|_| Fq::rand(&mut rng)).collect();
+            let evals = evaluate(&coeffs, domain.s());
+            assert_eq!(domain.extend(&evals), evaluate(&coeffs, domain.s_prime()));
+        }
+    }
+
+    #[test]
+    fn test_enter_exit() {
+        let mut rng = test_rng();
+        for log_size in 1..=10 {
+            let domain = domain(log_size);
+            let coeffs: Vec<Fq> = (0..domain.size()).map(|_| Fq::rand(&mut rng)).collect();
+            let evals = domain.enter(&coeffs);
+            assert_eq!(evals, evaluate(&coeffs, domain.s()));
+            assert_eq!(domain.exit(&evals), coeffs);
+        }
+    }
+
+    #[test]
+    fn test_mul_polynomials() {
+        let mut rng = test_rng();
+        let domain = domain(8);
+        for (deg_a, deg_b) in [(0, 0), (1, 5), (63, 64), (100, 27)] {
+            let a = DensePolynomial::<Fq>::rand(deg_a, &mut rng);
+            let b = DensePolynomial::<Fq>::rand(deg_b, &mut rng);
+            assert_eq!(domain.mul_polynomials(&a, &b), a.naive_mul(&b));
+        }
+    }
+
+    #[test]
+    fn test_invalid_domains() {
+        let generator = AuxConfig::GENERATOR;
+        assert!(EcfftDomain::new(generator, Affine::identity()).is_none());
+        assert!(EcfftDomain::new(generator, generator).is_none());
+        assert!(EcfftDomain::new(Affine::identity(), OFFSET).is_none());
+        let not_power_of_two = (AuxConfig::GENERATOR + OFFSET).into_affine();
+        assert!(EcfftDomain::new(not_power_of_two, OFFSET).is_none());
+    }
+}
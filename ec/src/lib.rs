@@ -40,6 +40,8 @@ pub mod hashing;
 
 pub mod pairing;
 
+pub mod ecfft;
+
 /// Represents (elements of) a group of prime order `r`.
 pub trait PrimeGroup: AdditiveGroup<Scalar = Self::ScalarField> {
     /// The scalar field `F_r`, where `r` is the order of this group.
@@ -387,6 +387,9 @@ impl<P: SWCurveConfig> CanonicalDeserialize for Affine<P> {
     }
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([P: SWCurveConfig] Affine<P>);
+
 impl<M: SWCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Affine<M>
 where
     M::BaseField: ToConstraintField<ConstraintF>,
@@ -3,6 +3,8 @@ use crate::{
     scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
     AffineRepr, CurveGroup, PrimeGroup,
 };
+#[cfg(feature = "ct")]
+use ark_ff::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
 use ark_serialize::{
     CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
@@ -131,6 +133,36 @@ impl<P: SWCurveConfig> Projective<P> {
     }
 }
 
+#[cfg(feature = "ct")]
+impl<P: SWCurveConfig> Projective<P>
+where
+    P::BaseField: ConditionallySelectable + ConstantTimeEq,
+{
+    /// Computes `scalar * self` in constant time.
+    ///
+    /// Unlike [`Mul`], the sequence of operations and memory accesses does
+    /// not depend on `scalar` or on `self`. On curves with an even cofactor,
+    /// `self` must be in the prime-order subgroup.
+    /// See [`sw_mul_ct`](crate::scalar_mul::constant_time::sw_mul_ct).
+    pub fn mul_ct(&self, scalar: &P::ScalarField) -> Self {
+        crate::scalar_mul::constant_time::sw_mul_ct(self, scalar)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: SWCurveConfig> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: SWCurveConfig> Zeroize for Projective<P> {
     fn zeroize(&mut self) {
         self.x.zeroize();
@@ -623,6 +655,9 @@ impl<P: SWCurveConfig> CanonicalDeserialize for Projective<P> {
     }
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([P: SWCurveConfig] Projective<P>);
+
 impl<M: SWCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Projective<M>
 where
     M::BaseField: ToConstraintField<ConstraintF>,
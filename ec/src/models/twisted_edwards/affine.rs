@@ -342,6 +342,9 @@ impl<P: TECurveConfig> CanonicalDeserialize for Affine<P> {
     }
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([P: TECurveConfig] Affine<P>);
+
 impl<M: TECurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Affine<M>
 where
     M::BaseField: ToConstraintField<ConstraintF>,
@@ -15,6 +15,8 @@ use ark_std::{
     One, Zero,
 };
 
+#[cfg(feature = "ct")]
+use ark_ff::subtle::{Choice, ConditionallySelectable};
 use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
 
 use educe::Educe;
@@ -120,6 +122,37 @@ impl<P: TECurveConfig> Projective<P> {
         p.into()
     }
 }
+
+#[cfg(feature = "ct")]
+impl<P: TECurveConfig> Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    /// Computes `scalar * self` in constant time.
+    ///
+    /// Unlike [`Mul`], the sequence of operations and memory accesses does
+    /// not depend on `scalar` or on `self`.
+    /// See [`te_mul_ct`](crate::scalar_mul::constant_time::te_mul_ct).
+    pub fn mul_ct(&self, scalar: &P::ScalarField) -> Self {
+        crate::scalar_mul::constant_time::te_mul_ct(self, scalar)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: TECurveConfig> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: TECurveConfig> Zeroize for Projective<P> {
     // The phantom data does not contain element-specific data
     // and thus does not need to be zeroized.
@@ -467,6 +500,9 @@ impl<P: TECurveConfig> CanonicalDeserialize for Projective<P> {
     }
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([P: TECurveConfig] Projective<P>);
+
 impl<M: TECurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Projective<M>
 where
     M::BaseField: ToConstraintField<ConstraintF>,
@@ -0,0 +1,218 @@
+//! Constant-time scalar multiplication.
+//!
+//! The routines in this module use a fixed-window method: the scalar is split
+//! into `WINDOW_SIZE`-bit digits, and the multiples `0 * P, ..., 15 * P` are
+//! precomputed. Each digit is looked up by scanning the whole table with
+//! [`ConditionallySelectable`], and the number of doublings and additions
+//! depends only on `MODULUS_BIT_SIZE` of the scalar field. All group
+//! operations use complete formulas, so that no special cases are handled by
+//! branching on secret data.
+//!
+//! These are only available when the `ct` feature is enabled.
+
+use crate::{
+    short_weierstrass::{Projective as SWProjective, SWCurveConfig},
+    twisted_edwards::{Projective as TEProjective, TECurveConfig},
+    CurveGroup,
+};
+use ark_ff::{
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    AdditiveGroup, Field, PrimeField,
+};
+use ark_std::{marker::PhantomData, Zero};
+
+/// Number of scalar bits processed per iteration.
+const WINDOW_SIZE: usize = 4;
+
+/// Number of precomputed multiples of the base.
+const TABLE_SIZE: usize = 1 << WINDOW_SIZE;
+
+/// Computes `scalar * base` with a fixed-window method.
+///
+/// `add` and `double` must not branch on their inputs, and `add` must be
+/// complete, i.e. correct for all inputs including the identity and equal
+/// points.
+#[inline(always)]
+fn fixed_window_mul<G, F>(
+    base: &G,
+    identity: G,
+    scalar: &F,
+    add: impl Fn(&G, &G) -> G,
+    double: impl Fn(&G) -> G,
+) -> G
+where
+    G: ConditionallySelectable,
+    F: PrimeField,
+{
+    let mut table = [identity; TABLE_SIZE];
+    for i in 1..TABLE_SIZE {
+        table[i] = add(&table[i - 1], base);
+    }
+
+    let scalar = scalar.into_bigint();
+    let limbs = scalar.as_ref();
+    let num_windows = (F::MODULUS_BIT_SIZE as usize).div_ceil(WINDOW_SIZE);
+
+    let mut res = identity;
+    for window in (0..num_windows).rev() {
+        for _ in 0..WINDOW_SIZE {
+            res = double(&res);
+        }
+        // `WINDOW_SIZE` divides 64, so a window never straddles two limbs.
+        let bit = window * WINDOW_SIZE;
+        let digit = (limbs[bit / 64] >> (bit % 64)) & (TABLE_SIZE as u64 - 1);
+
+        let mut entry = identity;
+        for (i, multiple) in table.iter().enumerate() {
+            entry.conditional_assign(multiple, (i as u64).ct_eq(&digit));
+        }
+        res = add(&res, &entry);
+    }
+    res
+}
+
+/// A short Weierstrass point in homogeneous projective coordinates, where
+/// `(X : Y : Z)` represents the affine point `(X/Z, Y/Z)`.
+///
+/// [`SWProjective`] uses Jacobian coordinates, for which no efficient complete
+/// addition formulas are known.
+struct Homogeneous<P: SWCurveConfig> {
+    x: P::BaseField,
+    y: P::BaseField,
+    z: P::BaseField,
+    _params: PhantomData<P>,
+}
+
+impl<P: SWCurveConfig> Clone for Homogeneous<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: SWCurveConfig> Copy for Homogeneous<P> {}
+
+impl<P: SWCurveConfig> ConditionallySelectable for Homogeneous<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: P::BaseField::conditional_select(&a.x, &b.x, choice),
+            y: P::BaseField::conditional_select(&a.y, &b.y, choice),
+            z: P::BaseField::conditional_select(&a.z, &b.z, choice),
+            _params: PhantomData,
+        }
+    }
+}
+
+impl<P: SWCurveConfig> Homogeneous<P> {
+    const IDENTITY: Self = Self {
+        x: P::BaseField::ZERO,
+        y: P::BaseField::ONE,
+        z: P::BaseField::ZERO,
+        _params: PhantomData,
+    };
+
+    /// Converts from Jacobian coordinates: `(X, Y, Z) -> (X * Z, Y, Z^3)`.
+    fn from_jacobian(p: &SWProjective<P>) -> Self {
+        let z2 = p.z.square();
+        Self {
+            x: p.x * &p.z,
+            y: p.y,
+            z: z2 * &p.z,
+            _params: PhantomData,
+        }
+    }
+
+    /// Converts to Jacobian coordinates: `(X, Y, Z) -> (X * Z, Y * Z^2, Z)`.
+    fn into_jacobian(self) -> SWProjective<P>
+    where
+        P::BaseField: ConditionallySelectable + ConstantTimeEq,
+    {
+        let z2 = self.z.square();
+        let res = SWProjective::new_unchecked(self.x * &self.z, self.y * &z2, self.z);
+        // The identity maps to `(0, 0, 0)`, which is not a valid Jacobian
+        // representative.
+        let is_zero = self.z.ct_eq(&P::BaseField::ZERO);
+        SWProjective::conditional_select(&res, &SWProjective::zero(), is_zero)
+    }
+
+    /// Complete addition for arbitrary `a`.
+    ///
+    /// Algorithm 1 of "Complete addition formulas for prime order elliptic
+    /// curves" by Renes, Costello and Batina (<https://eprint.iacr.org/2015/1060>).
+    fn add(&self, other: &Self) -> Self {
+        let b3 = P::COEFF_B.double() + &P::COEFF_B;
+
+        let t0 = self.x * &other.x;
+        let t1 = self.y * &other.y;
+        let t2 = self.z * &other.z;
+        let t3 = (self.x + &self.y) * &(other.x + &other.y) - &(t0 + &t1);
+        let t4 = (self.x + &self.z) * &(other.x + &other.z) - &(t0 + &t2);
+        let t5 = (self.y + &self.z) * &(other.y + &other.z) - &(t1 + &t2);
+
+        let z3 = P::mul_by_a(t4) + &(b3 * &t2);
+        let x3 = t1 - &z3;
+        let z3 = t1 + &z3;
+        let y3 = x3 * &z3;
+
+        let t1 = t0.double() + &t0;
+        let t2 = P::mul_by_a(t2);
+        let t4 = b3 * &t4 + &P::mul_by_a(t0 - &t2);
+        let t1 = t1 + &t2;
+
+        Self {
+            x: t3 * &x3 - &(t5 * &t4),
+            y: y3 + &(t1 * &t4),
+            z: t5 * &z3 + &(t3 * &t1),
+            _params: PhantomData,
+        }
+    }
+}
+
+/// Constant-time scalar multiplication for short Weierstrass curves.
+///
+/// The complete addition formulas are only complete on curves of odd order,
+/// which have no point of order two. On curves with an even cofactor, such
+/// as the short Weierstrass form of Bandersnatch, `base` must lie in the
+/// prime-order subgroup, which is checked in debug builds.
+pub fn sw_mul_ct<P: SWCurveConfig>(
+    base: &SWProjective<P>,
+    scalar: &P::ScalarField,
+) -> SWProjective<P>
+where
+    P::BaseField: ConditionallySelectable + ConstantTimeEq,
+{
+    debug_assert!(
+        P::COFACTOR[0] & 1 == 1 || P::is_in_correct_subgroup_assuming_on_curve(&base.into_affine()),
+        "on curves with an even cofactor, the base must be in the prime-order subgroup"
+    );
+    fixed_window_mul(
+        &Homogeneous::from_jacobian(base),
+        Homogeneous::IDENTITY,
+        scalar,
+        Homogeneous::add,
+        |p| p.add(p),
+    )
+    .into_jacobian()
+}
+
+/// Constant-time scalar multiplication for twisted Edwards curves.
+///
+/// The unified addition and doubling formulas in extended coordinates are
+/// complete for points in the prime-order subgroup.
+pub fn te_mul_ct<P: TECurveConfig>(
+    base: &TEProjective<P>,
+    scalar: &P::ScalarField,
+) -> TEProjective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fixed_window_mul(
+        base,
+        TEProjective::zero(),
+        scalar,
+        |a, b| *a + b,
+        |p| p.double(),
+    )
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "ct")]
+pub mod constant_time;
 pub mod glv;
 pub mod wnaf;
 
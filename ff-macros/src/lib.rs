@@ -12,6 +12,8 @@ use proc_macro::TokenStream;
 use syn::{Expr, ExprLit, Item, ItemFn, Lit, Meta};
 
 mod montgomery;
+mod small_fp;
+mod solinas;
 mod unroll;
 
 pub(crate) mod utils;
@@ -44,34 +46,109 @@ pub fn to_sign_and_limbs(input: TokenStream) -> TokenStream {
 pub fn mont_config(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     // Parse the type definition
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let (modulus, generator, small_subgroup_base, small_subgroup_power) =
+        fetch_field_attrs(&ast.attrs);
 
+    montgomery::mont_config_helper(
+        modulus,
+        generator,
+        small_subgroup_base,
+        small_subgroup_power,
+        ast.ident,
+    )
+    .into()
+}
+
+/// Derive the `SolinasConfig` trait.
+///
+/// The modulus must have the form `2^k - c`, where `k` is its bit length
+/// and `c` is a single limb with `(c + 1)^2 <= 2^k`.
+///
+/// The attributes available to this macro are the same as for `MontConfig`:
+/// * `modulus`: Specify the prime modulus underlying this prime field.
+/// * `generator`: Specify the generator of the multiplicative subgroup of this
+///   prime field. This value must be a quadratic non-residue in the field.
+/// * `small_subgroup_base` and `small_subgroup_power` (optional): If the field
+///   has insufficient two-adicity, specify an additional subgroup of size
+///   `small_subgroup_base.pow(small_subgroup_power)`.
+#[proc_macro_derive(
+    SolinasConfig,
+    attributes(modulus, generator, small_subgroup_base, small_subgroup_power)
+)]
+pub fn solinas_config(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    // Parse the type definition
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let (modulus, generator, small_subgroup_base, small_subgroup_power) =
+        fetch_field_attrs(&ast.attrs);
+
+    solinas::solinas_config_helper(
+        modulus,
+        generator,
+        small_subgroup_base,
+        small_subgroup_power,
+        ast.ident,
+    )
+    .into()
+}
+
+/// Derive the `SmallFpConfig` trait.
+///
+/// The modulus must be an odd prime smaller than `2^31`.
+///
+/// The attributes available to this macro are the same as for `MontConfig`:
+/// * `modulus`: Specify the prime modulus underlying this prime field.
+/// * `generator`: Specify the generator of the multiplicative subgroup of this
+///   prime field. This value must be a quadratic non-residue in the field.
+/// * `small_subgroup_base` and `small_subgroup_power` (optional): If the field
+///   has insufficient two-adicity, specify an additional subgroup of size
+///   `small_subgroup_base.pow(small_subgroup_power)`.
+#[proc_macro_derive(
+    SmallFpConfig,
+    attributes(modulus, generator, small_subgroup_base, small_subgroup_power)
+)]
+pub fn small_fp_config(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    // Parse the type definition
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let (modulus, generator, small_subgroup_base, small_subgroup_power) =
+        fetch_field_attrs(&ast.attrs);
+
+    small_fp::small_fp_config_helper(
+        modulus,
+        generator,
+        small_subgroup_base,
+        small_subgroup_power,
+        ast.ident,
+    )
+    .into()
+}
+
+/// Fetch the attributes shared by the field config derive macros.
+fn fetch_field_attrs(attrs: &[syn::Attribute]) -> (BigUint, BigUint, Option<u32>, Option<u32>) {
     // We're given the modulus p of the prime field
-    let modulus: BigUint = fetch_attr("modulus", &ast.attrs)
+    let modulus: BigUint = fetch_attr("modulus", attrs)
         .expect("Please supply a modulus attribute")
         .parse()
         .expect("Modulus should be a number");
 
     // We may be provided with a generator of p - 1 order. It is required that this
     // generator be quadratic nonresidue.
-    let generator: BigUint = fetch_attr("generator", &ast.attrs)
+    let generator: BigUint = fetch_attr("generator", attrs)
         .expect("Please supply a generator attribute")
         .parse()
         .expect("Generator should be a number");
 
-    let small_subgroup_base: Option<u32> = fetch_attr("small_subgroup_base", &ast.attrs)
+    let small_subgroup_base: Option<u32> = fetch_attr("small_subgroup_base", attrs)
         .map(|s| s.parse().expect("small_subgroup_base should be a number"));
 
-    let small_subgroup_power: Option<u32> = fetch_attr("small_subgroup_power", &ast.attrs)
+    let small_subgroup_power: Option<u32> = fetch_attr("small_subgroup_power", attrs)
         .map(|s| s.parse().expect("small_subgroup_power should be a number"));
 
-    montgomery::mont_config_helper(
+    (
         modulus,
         generator,
         small_subgroup_base,
         small_subgroup_power,
-        ast.ident,
     )
-    .into()
 }
 
 const ARG_MSG: &str = "Failed to parse unroll threshold; must be a positive integer";
@@ -25,6 +25,7 @@ pub(super) fn add_with_carry_impl(num_limbs: usize) -> proc_macro2::TokenStream
     }
 }
 
+#[cfg(not(feature = "ct"))]
 pub(super) fn sub_with_borrow_impl(num_limbs: usize) -> proc_macro2::TokenStream {
     let mut body = proc_macro2::TokenStream::new();
     body.extend(quote! {
@@ -50,6 +51,7 @@ pub(super) fn sub_with_borrow_impl(num_limbs: usize) -> proc_macro2::TokenStream
     }
 }
 
+#[cfg(not(feature = "ct"))]
 pub(super) fn subtract_modulus_impl(
     modulus: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
@@ -69,3 +71,21 @@ pub(super) fn subtract_modulus_impl(
         }
     }
 }
+
+#[cfg(feature = "ct")]
+pub(super) fn subtract_modulus_impl(
+    _modulus: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    // Delegate to `Fp`, whose reduction is branch-free in `ct` mode.
+    quote! {
+        #[inline(always)]
+        fn __subtract_modulus(a: &mut F) {
+            a.subtract_modulus();
+        }
+
+        #[inline(always)]
+        fn __subtract_modulus_with_carry(a: &mut F, carry: bool) {
+            a.subtract_modulus_with_carry(carry);
+        }
+    }
+}
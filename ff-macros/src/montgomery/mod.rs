@@ -1,10 +1,9 @@
-use std::str::FromStr;
-
 use num_bigint::BigUint;
-use num_traits::One;
 
 mod biginteger;
-use biginteger::{add_with_carry_impl, sub_with_borrow_impl, subtract_modulus_impl};
+#[cfg(not(feature = "ct"))]
+use biginteger::sub_with_borrow_impl;
+use biginteger::{add_with_carry_impl, subtract_modulus_impl};
 
 mod add;
 use add::add_assign_impl;
@@ -28,34 +27,14 @@ pub(crate) fn mont_config_helper(
     small_subgroup_power: Option<u32>,
     config_name: proc_macro2::Ident,
 ) -> proc_macro2::TokenStream {
-    let mut limbs = 1usize;
-    {
-        let mut cur = BigUint::one() << 64; // always 64-bit limbs for now
-        while cur < modulus {
-            limbs += 1;
-            cur <<= 64;
-        }
-    }
-
-    // modulus - 1 = 2^s * t
-    let mut trace = &modulus - BigUint::from_str("1").unwrap();
-    while !trace.bit(0) {
-        trace >>= 1u8;
-    }
-
-    // Compute 2^s root of unity given the generator
-    let remaining_subgroup_size = match (small_subgroup_base, small_subgroup_power) {
-        (Some(base), Some(power)) => Some(&trace / BigUint::from(base).pow(power)),
-        (None, None) => None,
-        (..) => panic!("Must specify both `small_subgroup_base` and `small_subgroup_power`"),
-    };
-    let two_adic_root_of_unity = generator.modpow(&trace, &modulus);
-    let large_subgroup_generator = remaining_subgroup_size
-        .as_ref()
-        .map(|e| generator.modpow(e, &modulus).to_string());
+    let (limbs, two_adic_root_of_unity, large_subgroup_generator) = utils::fft_params(
+        &modulus,
+        &generator,
+        small_subgroup_base,
+        small_subgroup_power,
+    );
     let modulus = modulus.to_string();
     let generator = generator.to_string();
-    let two_adic_root_of_unity = two_adic_root_of_unity.to_string();
     let modulus_limbs = utils::str_to_limbs_u64(&modulus).1;
     let modulus_has_spare_bit = modulus_limbs.last().unwrap() >> 63 == 0;
     let can_use_no_carry_mul_opt = {
@@ -69,8 +48,35 @@ pub(crate) fn mont_config_helper(
     let modulus = quote::quote! { BigInt([ #( #modulus_limbs ),* ]) };
 
     let add_with_carry = add_with_carry_impl(limbs);
-    let sub_with_borrow = sub_with_borrow_impl(limbs);
     let subtract_modulus = subtract_modulus_impl(&modulus);
+    // In `ct` mode, `sub_assign` and `neg_in_place` fall back to the
+    // branch-free defaults of `MontConfig`.
+    #[cfg(not(feature = "ct"))]
+    let (sub_with_borrow, sub_and_neg) = (
+        sub_with_borrow_impl(limbs),
+        quote::quote! {
+            #[inline(always)]
+            fn sub_assign(a: &mut F, b: &F) {
+                // If `other` is larger than `self`, add the modulus to self first.
+                if b.0 > a.0 {
+                    __add_with_carry(&mut a.0, &#modulus);
+                }
+                __sub_with_borrow(&mut a.0, &b.0);
+            }
+
+            /// Sets `a = -a`.
+            #[inline(always)]
+            fn neg_in_place(a: &mut F) {
+                if *a != F::ZERO {
+                    let mut tmp = #modulus;
+                    __sub_with_borrow(&mut tmp, &a.0);
+                    a.0 = tmp;
+                }
+            }
+        },
+    );
+    #[cfg(feature = "ct")]
+    let (sub_with_borrow, sub_and_neg) = (quote::quote! {}, quote::quote! {});
     let add_assign = add_assign_impl(modulus_has_spare_bit);
     let double_in_place = double_in_place_impl(modulus_has_spare_bit);
     let mul_assign = mul_assign_impl(
@@ -120,30 +126,13 @@ pub(crate) fn mont_config_helper(
                     #add_assign
                 }
 
-                #[inline(always)]
-                fn sub_assign(a: &mut F, b: &F) {
-                    // If `other` is larger than `self`, add the modulus to self first.
-                    if b.0 > a.0 {
-                        __add_with_carry(&mut a.0, &#modulus);
-                    }
-                    __sub_with_borrow(&mut a.0, &b.0);
-                }
+                #sub_and_neg
 
                 #[inline(always)]
                 fn double_in_place(a: &mut F) {
                     #double_in_place
                 }
 
-                /// Sets `a = -a`.
-                #[inline(always)]
-                fn neg_in_place(a: &mut F) {
-                    if *a != F::ZERO {
-                        let mut tmp = #modulus;
-                        __sub_with_borrow(&mut tmp, &a.0);
-                        a.0 = tmp;
-                    }
-                }
-
                 #[inline(always)]
                 fn mul_assign(a: &mut F, b: &F) {
                     #mul_assign
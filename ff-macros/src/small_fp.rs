@@ -0,0 +1,56 @@
+use num_bigint::BigUint;
+
+use crate::utils;
+
+pub(crate) fn small_fp_config_helper(
+    modulus: BigUint,
+    generator: BigUint,
+    small_subgroup_base: Option<u32>,
+    small_subgroup_power: Option<u32>,
+    config_name: proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    assert!(
+        modulus.bits() <= 31,
+        "The modulus must be smaller than 2^31"
+    );
+    assert!(modulus.bit(0), "The modulus must be odd");
+
+    let (_, two_adic_root_of_unity, large_subgroup_generator) = utils::fft_params(
+        &modulus,
+        &generator,
+        small_subgroup_base,
+        small_subgroup_power,
+    );
+    let modulus = u32::try_from(&modulus).unwrap();
+    let generator = generator.to_string();
+
+    let mixed_radix = if let Some(large_subgroup_generator) = large_subgroup_generator {
+        quote::quote! {
+            const SMALL_SUBGROUP_BASE: Option<u32> = Some(#small_subgroup_base);
+
+            const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(#small_subgroup_power);
+
+            const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<F> = Some(ark_ff::SmallFp!(#large_subgroup_generator));
+        }
+    } else {
+        quote::quote! {}
+    };
+
+    quote::quote! {
+        const _: () = {
+            use ark_ff::fields::*;
+            type F = SmallFp<#config_name>;
+
+            #[automatically_derived]
+            impl SmallFpConfig for #config_name {
+                const MODULUS: u32 = #modulus;
+
+                const GENERATOR: F = ark_ff::SmallFp!(#generator);
+
+                const TWO_ADIC_ROOT_OF_UNITY: F = ark_ff::SmallFp!(#two_adic_root_of_unity);
+
+                #mixed_radix
+            }
+        };
+    }
+}
@@ -0,0 +1,67 @@
+use num_bigint::BigUint;
+use num_traits::One;
+
+use crate::utils;
+
+pub(crate) fn solinas_config_helper(
+    modulus: BigUint,
+    generator: BigUint,
+    small_subgroup_base: Option<u32>,
+    small_subgroup_power: Option<u32>,
+    config_name: proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    // Check that the modulus has the form 2^k - c, with c a single limb
+    // satisfying (c + 1)^2 <= 2^k.
+    let k = modulus.bits();
+    let c = (BigUint::one() << k) - &modulus;
+    assert!(
+        c.bits() <= 64,
+        "The modulus must be of the form 2^k - c, with c < 2^64"
+    );
+    assert!(
+        (&c + 1u8).pow(2) <= BigUint::one() << k,
+        "The modulus must be of the form 2^k - c, with (c + 1)^2 <= 2^k"
+    );
+
+    let (limbs, two_adic_root_of_unity, large_subgroup_generator) = utils::fft_params(
+        &modulus,
+        &generator,
+        small_subgroup_base,
+        small_subgroup_power,
+    );
+    let modulus = modulus.to_string();
+    let generator = generator.to_string();
+    let modulus_limbs = utils::str_to_limbs_u64(&modulus).1;
+    let modulus = quote::quote! { BigInt([ #( #modulus_limbs ),* ]) };
+
+    let mixed_radix = if let Some(large_subgroup_generator) = large_subgroup_generator {
+        quote::quote! {
+            const SMALL_SUBGROUP_BASE: Option<u32> = Some(#small_subgroup_base);
+
+            const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(#small_subgroup_power);
+
+            const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<F> = Some(ark_ff::SolinasFp!(#large_subgroup_generator));
+        }
+    } else {
+        quote::quote! {}
+    };
+
+    quote::quote! {
+        const _: () = {
+            use ark_ff::{fields::Fp, BigInt, fields::*};
+            type B = BigInt<#limbs>;
+            type F = Fp<SolinasBackend<#config_name, #limbs>, #limbs>;
+
+            #[automatically_derived]
+            impl SolinasConfig<#limbs> for #config_name {
+                const MODULUS: B = #modulus;
+
+                const GENERATOR: F = ark_ff::SolinasFp!(#generator);
+
+                const TWO_ADIC_ROOT_OF_UNITY: F = ark_ff::SolinasFp!(#two_adic_root_of_unity);
+
+                #mixed_radix
+            }
+        };
+    }
+}
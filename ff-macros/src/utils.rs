@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
-use num_bigint::{BigInt, Sign};
-use num_traits::Num;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{Num, One};
 use proc_macro::TokenStream;
 use syn::{Expr, Lit};
 
@@ -60,3 +60,45 @@ pub(crate) fn str_to_limbs_u64(num: &str) -> (bool, Vec<u64>) {
     let sign_is_positive = sign != Sign::Minus;
     (sign_is_positive, limbs)
 }
+
+/// Computes the number of 64-bit limbs needed to represent `modulus`, the
+/// `2^s`-th root of unity `generator^t` (where `modulus - 1 = 2^s * t`), and,
+/// if a small subgroup is specified, the generator of the large subgroup used
+/// for mixed-radix FFTs.
+pub(crate) fn fft_params(
+    modulus: &BigUint,
+    generator: &BigUint,
+    small_subgroup_base: Option<u32>,
+    small_subgroup_power: Option<u32>,
+) -> (usize, String, Option<String>) {
+    let mut limbs = 1usize;
+    {
+        let mut cur = BigUint::one() << 64; // always 64-bit limbs for now
+        while cur < *modulus {
+            limbs += 1;
+            cur <<= 64;
+        }
+    }
+
+    // modulus - 1 = 2^s * t
+    let mut trace = modulus - BigUint::from_str("1").unwrap();
+    while !trace.bit(0) {
+        trace >>= 1u8;
+    }
+
+    // Compute 2^s root of unity given the generator
+    let remaining_subgroup_size = match (small_subgroup_base, small_subgroup_power) {
+        (Some(base), Some(power)) => Some(&trace / BigUint::from(base).pow(power)),
+        (None, None) => None,
+        (..) => panic!("Must specify both `small_subgroup_base` and `small_subgroup_power`"),
+    };
+    let two_adic_root_of_unity = generator.modpow(&trace, modulus);
+    let large_subgroup_generator = remaining_subgroup_size
+        .as_ref()
+        .map(|e| generator.modpow(e, modulus).to_string());
+    (
+        limbs,
+        two_adic_root_of_unity.to_string(),
+        large_subgroup_generator,
+    )
+}
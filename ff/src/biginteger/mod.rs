@@ -70,6 +70,9 @@ impl<const N: usize> CanonicalDeserialize for BigInt<N> {
     }
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([const N: usize] BigInt<N>);
+
 /// Construct a [`struct@BigInt<N>`] element from a literal string.
 ///
 /// # Panics
@@ -173,7 +176,7 @@ impl<const N: usize> BigInt<N> {
         result
     }
 
-    const fn const_geq(&self, other: &Self) -> bool {
+    pub(crate) const fn const_geq(&self, other: &Self) -> bool {
         const_for!((i in 0..N) {
             let a = self.0[N - i - 1];
             let b = other.0[N - i - 1];
@@ -841,6 +844,27 @@ impl<const N: usize> Not for BigInt<N> {
     }
 }
 
+#[cfg(feature = "ct")]
+impl<const N: usize> subtle::ConstantTimeEq for BigInt<N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.0[..], &other.0[..])
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<const N: usize> subtle::ConditionallySelectable for BigInt<N> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        let mut result = *a;
+        for i in 0..N {
+            result.0[i] =
+                subtle::ConditionallySelectable::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        result
+    }
+}
+
 /// Compute the signed modulo operation on a u64 representation, returning the result.
 /// If n % modulus > modulus / 2, return modulus - n
 /// # Example
@@ -20,6 +20,10 @@ pub mod utils;
 #[macro_use]
 pub mod arithmetic;
 
+#[macro_use]
+mod packed;
+pub use packed::{simd_dispatch, PackedField};
+
 #[macro_use]
 pub mod models;
 pub use self::models::*;
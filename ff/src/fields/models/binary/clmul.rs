@@ -0,0 +1,127 @@
+//! Carry-less multiplication of 64-bit polynomials over GF(2).
+
+/// Returns the carry-less product of `a` and `b`.
+///
+/// Uses PCLMULQDQ on `x86_64` and PMULL on `aarch64` if they are enabled at
+/// compile time or, with the `std` feature, detected at runtime, and
+/// [`clmul64_portable`] otherwise.
+#[inline]
+#[allow(unreachable_code, unsafe_code)]
+pub(super) fn clmul64(a: u64, b: u64) -> u128 {
+    #[cfg(all(target_arch = "x86_64", target_feature = "pclmulqdq"))]
+    // SAFETY: PCLMULQDQ is enabled at compile time.
+    return unsafe { x86::clmul64(a, b) };
+
+    #[cfg(all(
+        target_arch = "x86_64",
+        not(target_feature = "pclmulqdq"),
+        feature = "std"
+    ))]
+    if std::is_x86_feature_detected!("pclmulqdq") {
+        // SAFETY: the CPU supports PCLMULQDQ.
+        return unsafe { x86::clmul64(a, b) };
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "aes"))]
+    // SAFETY: PMULL is enabled at compile time.
+    return unsafe { aarch64::clmul64(a, b) };
+
+    #[cfg(all(target_arch = "aarch64", not(target_feature = "aes"), feature = "std"))]
+    if std::arch::is_aarch64_feature_detected!("aes") {
+        // SAFETY: the CPU supports PMULL.
+        return unsafe { aarch64::clmul64(a, b) };
+    }
+
+    clmul64_portable(a, b)
+}
+
+/// Portable and constant-time version of [`clmul64`], with one level of
+/// Karatsuba over [`clmul32`].
+#[inline]
+pub(super) const fn clmul64_portable(a: u64, b: u64) -> u128 {
+    let (a0, a1) = (a as u32, (a >> 32) as u32);
+    let (b0, b1) = (b as u32, (b >> 32) as u32);
+    let z0 = clmul32(a0, b0);
+    let z2 = clmul32(a1, b1);
+    let z1 = clmul32(a0 ^ a1, b0 ^ b1) ^ z0 ^ z2;
+    (z0 as u128) ^ ((z1 as u128) << 32) ^ ((z2 as u128) << 64)
+}
+
+/// Returns the carry-less product of `a` and `b` using integer
+/// multiplications.
+///
+/// The operands are split into four interleaved parts with three zero bits
+/// between consecutive coefficients, so that the carries of each integer
+/// product, of at most 8 terms, never reach the next coefficient of the same
+/// part.
+#[inline]
+const fn clmul32(a: u32, b: u32) -> u64 {
+    const M0: u64 = 0x1111_1111_1111_1111;
+    const M1: u64 = M0 << 1;
+    const M2: u64 = M0 << 2;
+    const M3: u64 = M0 << 3;
+    let (a, b) = (a as u64, b as u64);
+    let (a0, a1, a2, a3) = (a & M0, a & M1, a & M2, a & M3);
+    let (b0, b1, b2, b3) = (b & M0, b & M1, b & M2, b & M3);
+    let z0 = (a0 * b0) ^ (a1 * b3) ^ (a2 * b2) ^ (a3 * b1);
+    let z1 = (a0 * b1) ^ (a1 * b0) ^ (a2 * b3) ^ (a3 * b2);
+    let z2 = (a0 * b2) ^ (a1 * b1) ^ (a2 * b0) ^ (a3 * b3);
+    let z3 = (a0 * b3) ^ (a1 * b2) ^ (a2 * b1) ^ (a3 * b0);
+    (z0 & M0) | (z1 & M1) | (z2 & M2) | (z3 & M3)
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    any(target_feature = "pclmulqdq", feature = "std")
+))]
+#[allow(unsafe_code)]
+mod x86 {
+    use core::arch::x86_64::*;
+
+    #[inline]
+    #[target_feature(enable = "pclmulqdq")]
+    pub(super) unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        let a = _mm_set_epi64x(0, a as i64);
+        let b = _mm_set_epi64x(0, b as i64);
+        core::mem::transmute::<__m128i, u128>(_mm_clmulepi64_si128(a, b, 0x00))
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", any(target_feature = "aes", feature = "std")))]
+#[allow(unsafe_code)]
+mod aarch64 {
+    use core::arch::aarch64::vmull_p64;
+
+    #[inline]
+    #[target_feature(enable = "neon,aes")]
+    pub(super) unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        vmull_p64(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::{rand::Rng, test_rng};
+
+    fn clmul64_naive(a: u64, b: u64) -> u128 {
+        (0..64)
+            .filter(|i| (b >> i) & 1 == 1)
+            .fold(0, |acc, i| acc ^ ((a as u128) << i))
+    }
+
+    #[test]
+    fn test_clmul64() {
+        let mut rng = test_rng();
+        let edge = [0, 1, u64::MAX, 1 << 63, 0x8888_8888_8888_8888];
+        for (a, b) in edge
+            .iter()
+            .flat_map(|a| edge.iter().map(move |b| (*a, *b)))
+            .chain((0..1000).map(|_| (rng.gen(), rng.gen())))
+        {
+            let expected = clmul64_naive(a, b);
+            assert_eq!(clmul64_portable(a, b), expected);
+            assert_eq!(clmul64(a, b), expected);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! The field with two elements.
+
+use super::impl_binary_field;
+use crate::{BigInt, PrimeField};
+use ark_std::str::FromStr;
+use num_bigint::BigUint;
+
+/// The field with two elements, the base prime field of all binary fields,
+/// where addition is XOR and multiplication is AND.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BinaryField1b(u8);
+
+impl_binary_field!(@field BinaryField1b, u8, 1, 1);
+
+impl BinaryField1b {
+    #[inline]
+    const fn mul_bits(a: u8, b: u8) -> u8 {
+        a & b
+    }
+
+    #[inline]
+    const fn square_bits(a: u8) -> u8 {
+        a
+    }
+
+    /// The only nonzero element is one, its own inverse.
+    #[inline]
+    const fn inverse_bits(a: u8) -> u8 {
+        a
+    }
+}
+
+impl PrimeField for BinaryField1b {
+    type BigInt = BigInt<1>;
+
+    const MODULUS: BigInt<1> = BigInt([2]);
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInt<1> = BigInt([0]);
+    const MODULUS_BIT_SIZE: u32 = 2;
+    // `p - 1 = 2^0 * 1`.
+    const TRACE: BigInt<1> = BigInt([1]);
+    const TRACE_MINUS_ONE_DIV_TWO: BigInt<1> = BigInt([0]);
+
+    #[inline]
+    fn from_bigint(repr: BigInt<1>) -> Option<Self> {
+        (repr.0[0] < 2).then_some(Self(repr.0[0] as u8))
+    }
+
+    #[inline]
+    fn into_bigint(self) -> BigInt<1> {
+        BigInt([self.0 as u64])
+    }
+}
+
+impl From<BigInt<1>> for BinaryField1b {
+    #[inline]
+    fn from(int: BigInt<1>) -> Self {
+        Self::from_bigint(int).unwrap()
+    }
+}
+
+impl From<BinaryField1b> for BigInt<1> {
+    #[inline]
+    fn from(elem: BinaryField1b) -> Self {
+        elem.into_bigint()
+    }
+}
+
+impl From<BigUint> for BinaryField1b {
+    #[inline]
+    fn from(val: BigUint) -> Self {
+        Self(val.bit(0) as u8)
+    }
+}
+
+impl From<BinaryField1b> for BigUint {
+    #[inline]
+    fn from(elem: BinaryField1b) -> Self {
+        Self::from(elem.0)
+    }
+}
+
+impl FromStr for BinaryField1b {
+    type Err = ();
+
+    /// Interprets a string of numbers as the element of the same parity.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let n = num_bigint::BigInt::from_str(s).map_err(|_| ())?;
+        Ok(Self(n.bit(0) as u8))
+    }
+}
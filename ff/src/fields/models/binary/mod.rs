@@ -0,0 +1,555 @@
+//! Binary fields, i.e. finite fields of characteristic two.
+//!
+//! Two families of fields are provided, all of which implement [`Field`]
+//! over [`BinaryField1b`], the field with two elements:
+//!
+//! * the tower fields [`BinaryField2b`], ..., [`BinaryField128b`], which
+//!   follow Wiedemann's construction as used in Binius: `T_0 = GF(2)` and
+//!   `T_{i+1} = T_i[X_i] / (X_i^2 + X_{i-1} X_i + 1)` with `X_{-1} = 1`, so
+//!   that every field is a subfield of the next one;
+//! * the fields [`BinaryPolyField64b`] and [`BinaryPolyField128b`], in
+//!   polynomial basis modulo `x^64 + x^4 + x^3 + x + 1` and
+//!   `x^128 + x^7 + x^2 + x + 1` respectively, whose multiplication uses the
+//!   carry-less multiplication instructions (PCLMULQDQ on `x86_64`, PMULL on
+//!   `aarch64`) when available, and a constant-time portable implementation
+//!   otherwise.
+//!
+//! Elements are built from their coordinates in the canonical basis with
+//! `new`, and `value` returns them. The conversions from integers required
+//! by [`Field`] (e.g. `From<u64>`) go through the characteristic, i.e. they
+//! map an integer to zero or one depending on its parity.
+//!
+//! [`Field`]: crate::Field
+
+mod clmul;
+
+mod gf2;
+pub use gf2::*;
+
+mod polynomial;
+pub use polynomial::*;
+
+mod tower;
+pub use tower::*;
+
+/// Returns `a^(2^k - 2)`, the inverse of `a` if it is nonzero, in a field
+/// with `2^k` elements, using the Itoh-Tsujii addition chain.
+#[inline]
+fn itoh_tsujii<T: Copy>(a: T, k: u32, mul: impl Fn(T, T) -> T, square: impl Fn(T) -> T) -> T {
+    // Invariant: `beta = a^(2^n - 1)`, built along the bits of `k - 1`.
+    let e = k - 1;
+    let mut beta = a;
+    let mut n = 1;
+    for i in (0..(31 - e.leading_zeros())).rev() {
+        let mut t = beta;
+        for _ in 0..n {
+            t = square(t);
+        }
+        beta = mul(t, beta);
+        n *= 2;
+        if (e >> i) & 1 == 1 {
+            beta = mul(square(beta), a);
+            n += 1;
+        }
+    }
+    square(beta)
+}
+
+/// Implements [`Field`], [`FftField`] and the traits they require for a
+/// binary field `$name(repr)` of `2^$bits` elements, whose multiplicative
+/// group is generated by `$name($generator)`, along with the embedding of
+/// [`BinaryField1b`].
+///
+/// The type must provide the associated functions `mul_bits`, `square_bits`
+/// and `inverse_bits` on the raw representation, the latter mapping zero to
+/// zero.
+macro_rules! impl_binary_field {
+    ($name:ident, $repr:ty, $bits:expr, $generator:expr) => {
+        impl_binary_field!(@field $name, $repr, $bits, $generator);
+
+        impl From<$crate::fields::models::binary::BinaryField1b> for $name {
+            #[inline]
+            fn from(elem: $crate::fields::models::binary::BinaryField1b) -> Self {
+                <Self as $crate::Field>::from_base_prime_field(elem)
+            }
+        }
+    };
+
+    (@field $name:ident, $repr:ty, $bits:expr, $generator:expr) => {
+        impl $name {
+            /// The mask of the bits used by the representation.
+            const MASK: $repr = <$repr>::MAX >> (<$repr>::BITS - $bits);
+
+            /// Returns the element whose coordinates in the canonical basis
+            /// are the bits of `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `value` does not fit in
+            #[doc = concat!(stringify!($bits), " bits.")]
+            #[inline]
+            pub const fn new(value: $repr) -> Self {
+                assert!(value <= Self::MASK, "value does not fit in the field");
+                Self(value)
+            }
+
+            /// Returns the coordinates of `self` in the canonical basis, as
+            /// the bits of an integer.
+            #[inline]
+            pub const fn value(self) -> $repr {
+                self.0
+            }
+        }
+
+        impl $crate::Zero for $name {
+            #[inline]
+            fn zero() -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl $crate::One for $name {
+            #[inline]
+            fn one() -> Self {
+                Self(1)
+            }
+
+            #[inline]
+            fn is_one(&self) -> bool {
+                self.0 == 1
+            }
+        }
+
+        impl $crate::AdditiveGroup for $name {
+            type Scalar = Self;
+
+            const ZERO: Self = Self(0);
+
+            #[inline]
+            fn double(&self) -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            fn double_in_place(&mut self) -> &mut Self {
+                self.0 = 0;
+                self
+            }
+
+            #[inline]
+            fn neg_in_place(&mut self) -> &mut Self {
+                self
+            }
+        }
+
+        impl $crate::Field for $name {
+            type BasePrimeField = $crate::fields::models::binary::BinaryField1b;
+
+            // Squaring is a bijection, so the square root of `a` is
+            // `a^(2^(k - 1))`; with a two-adicity of zero the non-residue is
+            // never used.
+            const SQRT_PRECOMP: Option<$crate::SqrtPrecomputation<Self>> = {
+                const TRACE: u128 = (1u128 << ($bits - 1)) - 1;
+                Some($crate::SqrtPrecomputation::TonelliShanks {
+                    two_adicity: 0,
+                    quadratic_nonresidue_to_trace: Self(1),
+                    trace_of_modulus_minus_one_div_two: &[TRACE as u64, (TRACE >> 64) as u64],
+                })
+            };
+
+            const ONE: Self = Self(1);
+
+            fn characteristic() -> &'static [u64] {
+                &[2]
+            }
+
+            fn extension_degree() -> u64 {
+                $bits
+            }
+
+            fn to_base_prime_field_elements(
+                &self,
+            ) -> impl Iterator<Item = Self::BasePrimeField> {
+                let value = self.0;
+                (0..$bits).map(move |i| Self::BasePrimeField::from(((value >> i) & 1) as u64))
+            }
+
+            fn from_base_prime_field_elems(
+                elems: impl IntoIterator<Item = Self::BasePrimeField>,
+            ) -> Option<Self> {
+                let mut value: $repr = 0;
+                let mut len = 0;
+                for elem in elems {
+                    if len == $bits {
+                        return None;
+                    }
+                    value |= ($crate::PrimeField::into_bigint(elem).0[0] as $repr) << len;
+                    len += 1;
+                }
+                (len == $bits).then_some(Self(value))
+            }
+
+            fn from_base_prime_field(elem: Self::BasePrimeField) -> Self {
+                Self($crate::PrimeField::into_bigint(elem).0[0] as $repr)
+            }
+
+            fn from_random_bytes_with_flags<F: ark_serialize::Flags>(
+                bytes: &[u8],
+            ) -> Option<(Self, F)> {
+                if F::BIT_SIZE > 8 {
+                    return None;
+                }
+                let size = ark_serialize::buffer_byte_size($bits + F::BIT_SIZE);
+                let mut buf = [0u8; 17];
+                let len = bytes.len().min(size);
+                buf[..len].copy_from_slice(&bytes[..len]);
+                let flags_mask = u8::MAX.checked_shl(8 - F::BIT_SIZE as u32).unwrap_or(0);
+                let flags = F::from_u8(buf[size - 1] & flags_mask)?;
+                let mut repr = [0u8; core::mem::size_of::<$repr>()];
+                repr.copy_from_slice(&buf[..core::mem::size_of::<$repr>()]);
+                Some((Self(<$repr>::from_le_bytes(repr) & Self::MASK), flags))
+            }
+
+            #[inline]
+            fn legendre(&self) -> $crate::LegendreSymbol {
+                if self.0 == 0 {
+                    $crate::LegendreSymbol::Zero
+                } else {
+                    $crate::LegendreSymbol::QuadraticResidue
+                }
+            }
+
+            #[inline]
+            fn sqrt(&self) -> Option<Self> {
+                Some($crate::Field::frobenius_map(self, $bits - 1))
+            }
+
+            #[inline]
+            fn square(&self) -> Self {
+                Self(Self::square_bits(self.0))
+            }
+
+            #[inline]
+            fn square_in_place(&mut self) -> &mut Self {
+                self.0 = Self::square_bits(self.0);
+                self
+            }
+
+            #[inline]
+            fn inverse(&self) -> Option<Self> {
+                (self.0 != 0).then(|| Self(Self::inverse_bits(self.0)))
+            }
+
+            fn inverse_in_place(&mut self) -> Option<&mut Self> {
+                if self.0 == 0 {
+                    None
+                } else {
+                    self.0 = Self::inverse_bits(self.0);
+                    Some(self)
+                }
+            }
+
+            // The Frobenius map is the identity on `BinaryField1b`.
+            #[allow(clippy::modulo_one)]
+            fn frobenius_map_in_place(&mut self, power: usize) {
+                for _ in 0..power % $bits {
+                    $crate::Field::square_in_place(self);
+                }
+            }
+
+            #[inline]
+            fn mul_by_base_prime_field(&self, elem: &Self::BasePrimeField) -> Self {
+                let mask = ($crate::PrimeField::into_bigint(*elem).0[0] as $repr).wrapping_neg();
+                Self(self.0 & mask)
+            }
+        }
+
+        // The multiplicative group has odd order `2^k - 1`.
+        impl $crate::FftField for $name {
+            const GENERATOR: Self = Self($generator);
+            const TWO_ADICITY: u32 = 0;
+            const TWO_ADIC_ROOT_OF_UNITY: Self = Self(1);
+        }
+
+        impl ark_std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
+                write!(f, "0x{:01$x}", self.0, ($bits as usize).div_ceil(4))
+            }
+        }
+
+        impl ark_std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
+                ark_std::fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                zeroize::Zeroize::zeroize(&mut self.0);
+            }
+        }
+
+        impl ark_std::rand::distributions::Distribution<$name>
+            for ark_std::rand::distributions::Standard
+        {
+            #[inline]
+            fn sample<R: ark_std::rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name(rng.gen::<$repr>() & $name::MASK)
+            }
+        }
+
+        impl ark_serialize::CanonicalSerializeWithFlags for $name {
+            fn serialize_with_flags<W: ark_std::io::Write, F: ark_serialize::Flags>(
+                &self,
+                mut writer: W,
+                flags: F,
+            ) -> Result<(), ark_serialize::SerializationError> {
+                if F::BIT_SIZE > 8 {
+                    return Err(ark_serialize::SerializationError::NotEnoughSpace);
+                }
+                let size = ark_serialize::buffer_byte_size($bits + F::BIT_SIZE);
+                let mut buf = [0u8; 17];
+                buf[..core::mem::size_of::<$repr>()].copy_from_slice(&self.0.to_le_bytes());
+                buf[size - 1] |= flags.u8_bitmask();
+                writer.write_all(&buf[..size])?;
+                Ok(())
+            }
+
+            fn serialized_size_with_flags<F: ark_serialize::Flags>(&self) -> usize {
+                ark_serialize::buffer_byte_size($bits + F::BIT_SIZE)
+            }
+        }
+
+        impl ark_serialize::CanonicalSerialize for $name {
+            #[inline]
+            fn serialize_with_mode<W: ark_std::io::Write>(
+                &self,
+                writer: W,
+                _compress: ark_serialize::Compress,
+            ) -> Result<(), ark_serialize::SerializationError> {
+                ark_serialize::CanonicalSerializeWithFlags::serialize_with_flags(
+                    self,
+                    writer,
+                    ark_serialize::EmptyFlags,
+                )
+            }
+
+            #[inline]
+            fn serialized_size(&self, _compress: ark_serialize::Compress) -> usize {
+                ark_serialize::buffer_byte_size($bits)
+            }
+        }
+
+        impl ark_serialize::CanonicalDeserializeWithFlags for $name {
+            fn deserialize_with_flags<R: ark_std::io::Read, F: ark_serialize::Flags>(
+                mut reader: R,
+            ) -> Result<(Self, F), ark_serialize::SerializationError> {
+                if F::BIT_SIZE > 8 {
+                    return Err(ark_serialize::SerializationError::NotEnoughSpace);
+                }
+                let size = ark_serialize::buffer_byte_size($bits + F::BIT_SIZE);
+                let mut buf = [0u8; 17];
+                reader.read_exact(&mut buf[..size])?;
+                let flags = F::from_u8_remove_flags(&mut buf[size - 1])
+                    .ok_or(ark_serialize::SerializationError::UnexpectedFlags)?;
+                let mut repr = [0u8; core::mem::size_of::<$repr>()];
+                repr.copy_from_slice(&buf[..core::mem::size_of::<$repr>()]);
+                let value = <$repr>::from_le_bytes(repr);
+                // Reject non-canonical encodings.
+                if value > Self::MASK || buf[core::mem::size_of::<$repr>()..size].iter().any(|b| *b != 0) {
+                    return Err(ark_serialize::SerializationError::InvalidData);
+                }
+                Ok((Self(value), flags))
+            }
+        }
+
+        impl ark_serialize::Valid for $name {
+            #[inline]
+            fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+                Ok(())
+            }
+        }
+
+        impl ark_serialize::CanonicalDeserialize for $name {
+            fn deserialize_with_mode<R: ark_std::io::Read>(
+                reader: R,
+                _compress: ark_serialize::Compress,
+                _validate: ark_serialize::Validate,
+            ) -> Result<Self, ark_serialize::SerializationError> {
+                <Self as ark_serialize::CanonicalDeserializeWithFlags>::deserialize_with_flags::<
+                    R,
+                    ark_serialize::EmptyFlags,
+                >(reader)
+                    .map(|(r, _)| r)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        ark_serialize::impl_serde_via_canonical!([] $name);
+
+        #[cfg(feature = "ct")]
+        impl subtle::ConstantTimeEq for $name {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                subtle::ConstantTimeEq::ct_eq(&self.0, &other.0)
+            }
+        }
+
+        #[cfg(feature = "ct")]
+        impl subtle::ConditionallySelectable for $name {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+                Self(subtle::ConditionallySelectable::conditional_select(
+                    &a.0, &b.0, choice,
+                ))
+            }
+        }
+
+        impl From<bool> for $name {
+            #[inline]
+            fn from(other: bool) -> Self {
+                Self(other as $repr)
+            }
+        }
+
+        impl_binary_field!(@from_ints $name, $repr, u8, u16, u32, u64, u128);
+        impl_binary_field!(@from_ints $name, $repr, i8, i16, i32, i64, i128);
+
+        impl ark_std::ops::Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                self
+            }
+        }
+
+        impl<'a> ark_std::ops::Add<&'a $name> for $name {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn add(self, other: &Self) -> Self {
+                Self(self.0 ^ other.0)
+            }
+        }
+
+        impl<'a> ark_std::ops::Sub<&'a $name> for $name {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn sub(self, other: &Self) -> Self {
+                Self(self.0 ^ other.0)
+            }
+        }
+
+        impl<'a> ark_std::ops::Mul<&'a $name> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: &Self) -> Self {
+                Self(Self::mul_bits(self.0, other.0))
+            }
+        }
+
+        impl<'a> ark_std::ops::Div<&'a $name> for $name {
+            type Output = Self;
+
+            /// Returns `self * other.inverse()` if `other.inverse()` is
+            /// `Some`, and panics otherwise.
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, other: &Self) -> Self {
+                self * &$crate::Field::inverse(other).unwrap()
+            }
+        }
+
+        impl_binary_field!(@ops $name, Add, add, AddAssign, add_assign);
+        impl_binary_field!(@ops $name, Sub, sub, SubAssign, sub_assign);
+        impl_binary_field!(@ops $name, Mul, mul, MulAssign, mul_assign);
+        impl_binary_field!(@ops $name, Div, div, DivAssign, div_assign);
+
+        impl ark_std::iter::Sum<Self> for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self(0), |acc, x| acc + &x)
+            }
+        }
+
+        impl<'a> ark_std::iter::Sum<&'a Self> for $name {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self(0), |acc, x| acc + x)
+            }
+        }
+
+        impl ark_std::iter::Product<Self> for $name {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self(1), |acc, x| acc * &x)
+            }
+        }
+
+        impl<'a> ark_std::iter::Product<&'a Self> for $name {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self(1), |acc, x| acc * x)
+            }
+        }
+    };
+
+    (@from_ints $name:ident, $repr:ty, $($int:ty),*) => {
+        $(
+            impl From<$int> for $name {
+                #[inline]
+                fn from(other: $int) -> Self {
+                    Self((other & 1) as $repr)
+                }
+            }
+        )*
+    };
+
+    (@ops $name:ident, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl ark_std::ops::$trait<Self> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, other: Self) -> Self {
+                ark_std::ops::$trait::$fn(self, &other)
+            }
+        }
+
+        impl<'a> ark_std::ops::$trait<&'a mut $name> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, other: &'a mut Self) -> Self {
+                ark_std::ops::$trait::$fn(self, &*other)
+            }
+        }
+
+        impl ark_std::ops::$assign_trait<Self> for $name {
+            #[inline]
+            fn $assign_fn(&mut self, other: Self) {
+                *self = ark_std::ops::$trait::$fn(*self, &other);
+            }
+        }
+
+        impl<'a> ark_std::ops::$assign_trait<&'a $name> for $name {
+            #[inline]
+            fn $assign_fn(&mut self, other: &Self) {
+                *self = ark_std::ops::$trait::$fn(*self, other);
+            }
+        }
+
+        impl<'a> ark_std::ops::$assign_trait<&'a mut $name> for $name {
+            #[inline]
+            fn $assign_fn(&mut self, other: &'a mut Self) {
+                *self = ark_std::ops::$trait::$fn(*self, &*other);
+            }
+        }
+    };
+}
+use impl_binary_field;
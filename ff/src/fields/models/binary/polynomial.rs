@@ -0,0 +1,82 @@
+//! Binary fields in polynomial basis, multiplied with carry-less
+//! multiplication.
+
+use super::{clmul::clmul64, impl_binary_field, itoh_tsujii};
+
+/// The field with `2^64` elements, `GF(2)[x] / (x^64 + x^4 + x^3 + x + 1)`.
+///
+/// The bit `i` of the representation is the coefficient of `x^i`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BinaryPolyField64b(u64);
+
+impl_binary_field!(BinaryPolyField64b, u64, 64, 0b10);
+
+impl BinaryPolyField64b {
+    /// Reduces `lo + x^64 hi` using `x^64 = x^4 + x^3 + x + 1`.
+    #[inline(always)]
+    const fn reduce(product: u128) -> u64 {
+        let (lo, hi) = (product as u64, (product >> 64) as u64);
+        let t = hi ^ (hi << 1) ^ (hi << 3) ^ (hi << 4);
+        // The bits of `hi * (x^4 + x^3 + x + 1)` above `x^63`.
+        let c = (hi >> 63) ^ (hi >> 61) ^ (hi >> 60);
+        lo ^ t ^ c ^ (c << 1) ^ (c << 3) ^ (c << 4)
+    }
+
+    #[inline]
+    fn mul_bits(a: u64, b: u64) -> u64 {
+        Self::reduce(clmul64(a, b))
+    }
+
+    #[inline]
+    fn square_bits(a: u64) -> u64 {
+        Self::reduce(clmul64(a, a))
+    }
+
+    #[inline]
+    fn inverse_bits(a: u64) -> u64 {
+        itoh_tsujii(a, 64, Self::mul_bits, Self::square_bits)
+    }
+}
+
+/// The field with `2^128` elements, `GF(2)[x] / (x^128 + x^7 + x^2 + x + 1)`,
+/// i.e. the field of GHASH, in its natural (non bit-reflected) ordering.
+///
+/// The bit `i` of the representation is the coefficient of `x^i`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BinaryPolyField128b(u128);
+
+impl_binary_field!(BinaryPolyField128b, u128, 128, 0b10);
+
+impl BinaryPolyField128b {
+    /// Reduces `lo + x^128 hi` using `x^128 = x^7 + x^2 + x + 1`.
+    #[inline(always)]
+    const fn reduce(lo: u128, hi: u128) -> u128 {
+        let t = hi ^ (hi << 1) ^ (hi << 2) ^ (hi << 7);
+        // The bits of `hi * (x^7 + x^2 + x + 1)` above `x^127`.
+        let c = (hi >> 127) ^ (hi >> 126) ^ (hi >> 121);
+        lo ^ t ^ c ^ (c << 1) ^ (c << 2) ^ (c << 7)
+    }
+
+    /// Multiplies with Karatsuba over 64-bit carry-less products.
+    #[inline]
+    fn mul_bits(a: u128, b: u128) -> u128 {
+        let (a0, a1) = (a as u64, (a >> 64) as u64);
+        let (b0, b1) = (b as u64, (b >> 64) as u64);
+        let z0 = clmul64(a0, b0);
+        let z2 = clmul64(a1, b1);
+        let z1 = clmul64(a0 ^ a1, b0 ^ b1) ^ z0 ^ z2;
+        Self::reduce(z0 ^ (z1 << 64), z2 ^ (z1 >> 64))
+    }
+
+    /// The cross terms of the square vanish in characteristic two.
+    #[inline]
+    fn square_bits(a: u128) -> u128 {
+        let (a0, a1) = (a as u64, (a >> 64) as u64);
+        Self::reduce(clmul64(a0, a0), clmul64(a1, a1))
+    }
+
+    #[inline]
+    fn inverse_bits(a: u128) -> u128 {
+        itoh_tsujii(a, 128, Self::mul_bits, Self::square_bits)
+    }
+}
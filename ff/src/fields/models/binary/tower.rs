@@ -0,0 +1,278 @@
+//! The binary tower fields `T_1, ..., T_7` of Wiedemann's construction.
+//!
+//! An element of `T_{i+1} = T_i[X_i] / (X_i^2 + X_{i-1} X_i + 1)` is stored
+//! as its coordinates `(a0, a1)` in the basis `(1, X_i)`, in the low and high
+//! halves of the representation respectively, so that the elements of every
+//! subfield are the integers with zero high bits.
+
+use super::impl_binary_field;
+
+/// The field with 4 elements, `T_1 = GF(2)[X_0] / (X_0^2 + X_0 + 1)`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BinaryField2b(u8);
+
+impl_binary_field!(BinaryField2b, u8, 2, 0b10);
+
+impl BinaryField2b {
+    #[inline]
+    const fn mul_recursive(a: u8, b: u8) -> u8 {
+        let (a0, a1, b0, b1) = (a & 1, a >> 1, b & 1, b >> 1);
+        let z0 = a0 & b0;
+        let z2 = a1 & b1;
+        let z1 = ((a0 ^ a1) & (b0 ^ b1)) ^ z0 ^ z2;
+        (z0 ^ z2) | ((z1 ^ z2) << 1)
+    }
+
+    #[inline]
+    const fn mul_bits(a: u8, b: u8) -> u8 {
+        Self::mul_recursive(a, b)
+    }
+
+    /// Returns `a * X_0`.
+    #[inline]
+    const fn mul_by_generator_bits(a: u8) -> u8 {
+        (a >> 1) | ((a ^ (a >> 1)) & 1) << 1
+    }
+
+    #[inline]
+    const fn square_bits(a: u8) -> u8 {
+        ((a ^ (a >> 1)) & 1) | (a & 2)
+    }
+
+    /// The multiplicative group has order 3, so `a^-1 = a^2`.
+    #[inline]
+    const fn inverse_bits(a: u8) -> u8 {
+        Self::square_bits(a)
+    }
+}
+
+/// Defines the tower field `$name`, a quadratic extension of `$sub`.
+macro_rules! binary_tower_field {
+    (@common $(#[$doc:meta])* $name:ident($repr:ty), $bits:expr, $sub:ident($sub_repr:ty), $generator:expr) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($repr);
+
+        impl_binary_field!($name, $repr, $bits, $generator);
+
+        // Not every level uses every helper: e.g. the recursive
+        // multiplication is only needed up to the lookup tables of
+        // `BinaryField8b`.
+        #[allow(dead_code)]
+        impl $name {
+            /// Returns the coordinates of `a` over the subfield.
+            #[inline(always)]
+            const fn split(a: $repr) -> ($sub_repr, $sub_repr) {
+                ((a as $sub_repr) & $sub::MASK, (a >> ($bits / 2)) as $sub_repr)
+            }
+
+            /// Returns the element with coordinates `(lo, hi)` over the
+            /// subfield.
+            #[inline(always)]
+            const fn join(lo: $sub_repr, hi: $sub_repr) -> $repr {
+                (lo as $repr) | ((hi as $repr) << ($bits / 2))
+            }
+
+            /// Multiplies with Karatsuba over the subfield, using
+            /// `X^2 = X_{i-1} X + 1`.
+            #[inline]
+            const fn mul_recursive(a: $repr, b: $repr) -> $repr {
+                binary_tower_field!(@mul $sub::mul_recursive, a, b)
+            }
+
+            /// Returns `a * X_i`.
+            #[inline]
+            const fn mul_by_generator_bits(a: $repr) -> $repr {
+                let (a0, a1) = Self::split(a);
+                Self::join(a1, a0 ^ $sub::mul_by_generator_bits(a1))
+            }
+
+            #[inline]
+            fn square_bits(a: $repr) -> $repr {
+                let (a0, a1) = Self::split(a);
+                let (s0, s1) = ($sub::square_bits(a0), $sub::square_bits(a1));
+                Self::join(s0 ^ s1, $sub::mul_by_generator_bits(s1))
+            }
+
+            /// Multiplies by the conjugate `(a0 + X_{i-1} a1) + a1 X` and
+            /// divides by the norm, which lies in the subfield.
+            #[inline]
+            fn inverse_recursive(a: $repr) -> $repr {
+                let (a0, a1) = Self::split(a);
+                let c0 = a0 ^ $sub::mul_by_generator_bits(a1);
+                let norm = $sub::mul_bits(a0, c0) ^ $sub::square_bits(a1);
+                let norm_inv = $sub::inverse_bits(norm);
+                Self::join($sub::mul_bits(c0, norm_inv), $sub::mul_bits(a1, norm_inv))
+            }
+        }
+
+        impl From<$sub> for $name {
+            #[inline]
+            fn from(other: $sub) -> Self {
+                Self(other.0 as $repr)
+            }
+        }
+    };
+
+    (@mul $sub:ident :: $mul:ident, $a:expr, $b:expr) => {{
+        let (a0, a1) = Self::split($a);
+        let (b0, b1) = Self::split($b);
+        let z0 = $sub::$mul(a0, b0);
+        let z2 = $sub::$mul(a1, b1);
+        let z1 = $sub::$mul(a0 ^ a1, b0 ^ b1) ^ z0 ^ z2;
+        Self::join(z0 ^ z2, z1 ^ $sub::mul_by_generator_bits(z2))
+    }};
+
+    ($(#[$doc:meta])* $name:ident($repr:ty), $bits:expr, $sub:ident($sub_repr:ty), $generator:expr) => {
+        binary_tower_field!(@common $(#[$doc])* $name($repr), $bits, $sub($sub_repr), $generator);
+
+        impl $name {
+            #[inline]
+            fn mul_bits(a: $repr, b: $repr) -> $repr {
+                binary_tower_field!(@mul $sub::mul_bits, a, b)
+            }
+
+            #[inline]
+            fn inverse_bits(a: $repr) -> $repr {
+                Self::inverse_recursive(a)
+            }
+        }
+    };
+}
+
+binary_tower_field!(
+    /// The field with 16 elements, `T_2 = T_1[X_1] / (X_1^2 + X_0 X_1 + 1)`.
+    BinaryField4b(u8), 4, BinaryField2b(u8), 0x5
+);
+
+binary_tower_field!(
+    @common
+    /// The field with 256 elements, `T_3 = T_2[X_2] / (X_2^2 + X_1 X_2 + 1)`.
+    ///
+    /// Without the `ct` feature, multiplications and inversions use
+    /// logarithm tables, and are thus not constant-time.
+    BinaryField8b(u8), 8, BinaryField4b(u8), 0x13
+);
+
+impl BinaryField8b {
+    #[cfg(not(feature = "ct"))]
+    #[inline]
+    fn mul_bits(a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            return 0;
+        }
+        let (log, exp) = &LOG_EXP_8B;
+        exp[log[a as usize] as usize + log[b as usize] as usize]
+    }
+
+    #[cfg(not(feature = "ct"))]
+    #[inline]
+    fn inverse_bits(a: u8) -> u8 {
+        if a == 0 {
+            return 0;
+        }
+        let (log, exp) = &LOG_EXP_8B;
+        exp[255 - log[a as usize] as usize]
+    }
+
+    #[cfg(feature = "ct")]
+    #[inline]
+    fn mul_bits(a: u8, b: u8) -> u8 {
+        Self::mul_recursive(a, b)
+    }
+
+    #[cfg(feature = "ct")]
+    #[inline]
+    fn inverse_bits(a: u8) -> u8 {
+        Self::inverse_recursive(a)
+    }
+}
+
+/// The logarithms of the nonzero elements of [`BinaryField8b`] in base a
+/// generator `g` of its multiplicative group, and the powers `g^0, ...,
+/// g^509`, so that the sum of two logarithms can be looked up directly.
+#[cfg(not(feature = "ct"))]
+static LOG_EXP_8B: ([u8; 256], [u8; 510]) = {
+    const fn pow(a: u8, mut e: u32) -> u8 {
+        let (mut res, mut base) = (1, a);
+        while e > 0 {
+            if e & 1 == 1 {
+                res = BinaryField8b::mul_recursive(res, base);
+            }
+            base = BinaryField8b::mul_recursive(base, base);
+            e >>= 1;
+        }
+        res
+    }
+    // `g` generates the group of order 255 = 3 * 5 * 17 iff none of
+    // `g^85`, `g^51` and `g^15` is one.
+    let mut g = 2;
+    while pow(g, 85) == 1 || pow(g, 51) == 1 || pow(g, 15) == 1 {
+        g += 1;
+    }
+    let mut log = [0u8; 256];
+    let mut exp = [0u8; 510];
+    let mut x = 1;
+    let mut i = 0;
+    while i < 510 {
+        exp[i] = x;
+        if i < 255 {
+            log[x as usize] = i as u8;
+        }
+        x = BinaryField8b::mul_recursive(x, g);
+        i += 1;
+    }
+    (log, exp)
+};
+
+binary_tower_field!(
+    /// The field with `2^16` elements, `T_4 = T_3[X_3] / (X_3^2 + X_2 X_3 + 1)`.
+    BinaryField16b(u16), 16, BinaryField8b(u8), 0x102
+);
+
+binary_tower_field!(
+    /// The field with `2^32` elements, `T_5 = T_4[X_4] / (X_4^2 + X_3 X_4 + 1)`.
+    BinaryField32b(u32), 32, BinaryField16b(u16), 0x1_0005
+);
+
+binary_tower_field!(
+    /// The field with `2^64` elements, `T_6 = T_5[X_5] / (X_5^2 + X_4 X_5 + 1)`.
+    BinaryField64b(u64), 64, BinaryField32b(u32), 0x1_0000_0004
+);
+
+binary_tower_field!(
+    /// The field with `2^128` elements, `T_7 = T_6[X_6] / (X_6^2 + X_5 X_6 + 1)`.
+    BinaryField128b(u128), 128, BinaryField64b(u64), 0x1_0000_0000_0000_0005
+);
+
+/// Implements the embeddings of the subfields of `$name` other than the
+/// largest one, which is handled by [`binary_tower_field`].
+macro_rules! impl_subfield_embeddings {
+    ($name:ident($repr:ty): $($sub:ident),+) => {
+        $(
+            impl From<$sub> for $name {
+                #[inline]
+                fn from(other: $sub) -> Self {
+                    Self(other.0 as $repr)
+                }
+            }
+        )+
+    };
+}
+
+impl_subfield_embeddings!(BinaryField8b(u8): BinaryField2b);
+impl_subfield_embeddings!(BinaryField16b(u16): BinaryField2b, BinaryField4b);
+impl_subfield_embeddings!(BinaryField32b(u32): BinaryField2b, BinaryField4b, BinaryField8b);
+impl_subfield_embeddings!(
+    BinaryField64b(u64): BinaryField2b,
+    BinaryField4b,
+    BinaryField8b,
+    BinaryField16b
+);
+impl_subfield_embeddings!(
+    BinaryField128b(u128): BinaryField2b,
+    BinaryField4b,
+    BinaryField8b,
+    BinaryField16b,
+    BinaryField32b
+);
@@ -659,6 +659,9 @@ impl<P: CubicExtConfig> CanonicalDeserialize for CubicExtField<P> {
     }
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([P: CubicExtConfig] CubicExtField<P>);
+
 impl<P: CubicExtConfig> ToConstraintField<P::BasePrimeField> for CubicExtField<P>
 where
     P::BaseField: ToConstraintField<P::BasePrimeField>,
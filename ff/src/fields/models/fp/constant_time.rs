@@ -0,0 +1,181 @@
+//! Constant-time operations on prime field elements.
+//!
+//! These are only available when the `ct` feature is enabled. In that mode,
+//! the conditional reductions of [`MontBackend`](super::MontBackend) are made
+//! branch-free, and [`Field::inverse`], [`Field::sqrt`] and
+//! [`Field::legendre`] for [`Fp`] are routed through the methods below.
+
+use super::{Fp, FpConfig};
+use crate::{BigInt, BigInteger, Field, LegendreSymbol, PrimeField, SqrtPrecomputation};
+use ark_std::marker::PhantomData;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+impl<P: FpConfig<N>, const N: usize> ConstantTimeEq for Fp<P, N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> ConditionallySelectable for Fp<P, N> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fp(BigInt::conditional_select(&a.0, &b.0, choice), PhantomData)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Fp<P, N> {
+    /// Returns `1` if `self` is zero, and `0` otherwise, in constant time.
+    #[inline]
+    pub fn ct_is_zero(&self) -> Choice {
+        self.ct_eq(&P::ZERO)
+    }
+
+    /// Computes the multiplicative inverse of `self` in constant time.
+    ///
+    /// The inverse is computed via Fermat's little theorem as
+    /// `self^(MODULUS - 2)`. Since the exponent is public, the sequence of
+    /// squarings and multiplications does not depend on `self`.
+    /// The result is `None` if `self` is zero.
+    pub fn ct_inverse(&self) -> CtOption<Self> {
+        let mut exponent = Self::MODULUS;
+        exponent.sub_with_borrow(&BigInt::from(2u64));
+        let inverse = self.pow(exponent);
+        CtOption::new(inverse, !self.ct_is_zero())
+    }
+
+    /// Computes a square root of `self` in constant time, if one exists.
+    ///
+    /// If `P::SQRT_PRECOMP` is `None`, Tonelli-Shanks is run with the
+    /// multiplicative generator as the quadratic non-residue.
+    pub fn ct_sqrt(&self) -> CtOption<Self> {
+        match P::SQRT_PRECOMP {
+            Some(precomp) => precomp.ct_sqrt(self),
+            None => ct_tonelli_shanks(
+                self,
+                P::TWO_ADICITY,
+                P::GENERATOR.pow(Self::TRACE),
+                Self::TRACE_MINUS_ONE_DIV_TWO.as_ref(),
+            ),
+        }
+    }
+
+    /// Computes the Legendre symbol of `self`.
+    ///
+    /// The exponentiation and comparisons are performed in constant time;
+    /// only the returned symbol depends on `self`.
+    pub fn ct_legendre(&self) -> LegendreSymbol {
+        // s = self^((MODULUS - 1) // 2)
+        let s = self.pow(Self::MODULUS_MINUS_ONE_DIV_TWO);
+        let is_zero = s.ct_is_zero();
+        let is_one = s.ct_eq(&P::ONE);
+        // Encode the symbol as 0 (zero), 1 (residue) or 2 (non-residue)
+        // without branching on `s`.
+        let mut symbol = 2u8;
+        symbol.conditional_assign(&1, is_one);
+        symbol.conditional_assign(&0, is_zero);
+        match symbol {
+            0 => LegendreSymbol::Zero,
+            1 => LegendreSymbol::QuadraticResidue,
+            _ => LegendreSymbol::QuadraticNonResidue,
+        }
+    }
+}
+
+impl<F: Field + ConditionallySelectable + ConstantTimeEq> SqrtPrecomputation<F> {
+    /// Constant-time counterpart of [`SqrtPrecomputation::sqrt`].
+    pub fn ct_sqrt(&self, elem: &F) -> CtOption<F> {
+        match self {
+            Self::TonelliShanks {
+                two_adicity,
+                quadratic_nonresidue_to_trace,
+                trace_of_modulus_minus_one_div_two,
+            } => ct_tonelli_shanks(
+                elem,
+                *two_adicity,
+                *quadratic_nonresidue_to_trace,
+                trace_of_modulus_minus_one_div_two,
+            ),
+            Self::Case3Mod4 {
+                modulus_plus_one_div_four,
+            } => {
+                let result = elem.pow(modulus_plus_one_div_four);
+                CtOption::new(result, result.square().ct_eq(elem))
+            },
+        }
+    }
+}
+
+/// Constant-time Tonelli-Shanks.
+///
+/// The number of iterations depends only on `two_adicity`, and intermediate
+/// values are selected with [`ConditionallySelectable`] instead of exiting
+/// early.
+fn ct_tonelli_shanks<F: Field + ConditionallySelectable + ConstantTimeEq>(
+    elem: &F,
+    two_adicity: u32,
+    quadratic_nonresidue_to_trace: F,
+    trace_of_modulus_minus_one_div_two: &[u64],
+) -> CtOption<F> {
+    // Constant-time variant of Algorithm 5 of https://eprint.iacr.org/2012/685.pdf,
+    // adapted from the `ff` crate (https://github.com/zkcrypto/ff).
+    let w = elem.pow(trace_of_modulus_minus_one_div_two);
+
+    let mut v = two_adicity;
+    let mut x = *elem * w;
+    let mut b = x * w;
+    let mut z = quadratic_nonresidue_to_trace;
+
+    for max_v in (1..=two_adicity).rev() {
+        let mut k = 1u32;
+        let mut tmp = b.square();
+        let mut j_less_than_v = Choice::from(1);
+
+        for j in 2..max_v {
+            let tmp_is_one = tmp.ct_eq(&F::ONE);
+            let squared = F::conditional_select(&tmp, &z, tmp_is_one).square();
+            tmp = F::conditional_select(&squared, &tmp, tmp_is_one);
+            let new_z = F::conditional_select(&z, &squared, tmp_is_one);
+            j_less_than_v &= !j.ct_eq(&v);
+            k = u32::conditional_select(&j, &k, tmp_is_one);
+            z = F::conditional_select(&z, &new_z, j_less_than_v);
+        }
+
+        let result = x * z;
+        x = F::conditional_select(&result, &x, b.ct_eq(&F::ONE));
+        z = z.square();
+        b *= z;
+        v = k;
+    }
+
+    CtOption::new(x, x.square().ct_eq(elem))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BigInt, Field, Fp64, MontBackend, MontConfig, SqrtPrecomputation};
+    use ark_std::vec::*;
+
+    /// `GF(97)`, without precomputation for square roots.
+    struct F97Config;
+
+    impl MontConfig<1> for F97Config {
+        const MODULUS: BigInt<1> = BigInt([97]);
+        const GENERATOR: F97 = F97::new(BigInt([5]));
+        const TWO_ADIC_ROOT_OF_UNITY: F97 = F97::new(BigInt([28]));
+        const SQRT_PRECOMP: Option<SqrtPrecomputation<F97>> = None;
+    }
+
+    type F97 = Fp64<MontBackend<F97Config, 1>>;
+
+    #[test]
+    fn ct_sqrt_without_precomputation() {
+        let squares: Vec<F97> = (0..97u64).map(|a| F97::from(a).square()).collect();
+        for a in 0..97u64 {
+            let a = F97::from(a);
+            let root: Option<F97> = a.ct_sqrt().into();
+            assert_eq!(root.is_some(), squares.contains(&a));
+            assert!(root.map_or(true, |root| root.square() == a));
+        }
+    }
+}
@@ -20,6 +20,18 @@ use core::iter;
 mod montgomery_backend;
 pub use montgomery_backend::*;
 
+mod solinas_backend;
+pub use solinas_backend::*;
+
+mod small_backend;
+pub use small_backend::*;
+
+mod packed;
+pub use packed::*;
+
+#[cfg(feature = "ct")]
+mod constant_time;
+
 /// A trait that specifies the configuration of a prime field.
 /// Also specifies how to perform arithmetic on field elements.
 pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
@@ -133,19 +145,44 @@ impl<P: FpConfig<N>, const N: usize> Fp<P, N> {
     }
 
     #[inline]
-    fn subtract_modulus(&mut self) {
+    #[cfg(not(feature = "ct"))]
+    pub(crate) fn subtract_modulus(&mut self) {
         if self.is_geq_modulus() {
             self.0.sub_with_borrow(&Self::MODULUS);
         }
     }
 
     #[inline]
-    fn subtract_modulus_with_carry(&mut self, carry: bool) {
+    #[cfg(not(feature = "ct"))]
+    pub(crate) fn subtract_modulus_with_carry(&mut self, carry: bool) {
         if carry || self.is_geq_modulus() {
             self.0.sub_with_borrow(&Self::MODULUS);
         }
     }
 
+    // Public only so that the code generated by `ark-ff-macros` for `ct`
+    // builds can reach the branch-free reduction from downstream crates.
+    #[doc(hidden)]
+    #[inline]
+    #[cfg(feature = "ct")]
+    pub fn subtract_modulus(&mut self) {
+        self.subtract_modulus_with_carry(false)
+    }
+
+    /// Branch-free variant of the final conditional subtraction: the modulus
+    /// is always subtracted, and the result is kept only if no borrow occurred
+    /// (or if the input overflowed the backing limbs).
+    #[doc(hidden)]
+    #[inline]
+    #[cfg(feature = "ct")]
+    pub fn subtract_modulus_with_carry(&mut self, carry: bool) {
+        use subtle::ConditionallySelectable;
+        let mut reduced = self.0;
+        let borrow = reduced.sub_with_borrow(&Self::MODULUS);
+        let choice = subtle::Choice::from(u8::from(carry) | u8::from(!borrow));
+        self.0.conditional_assign(&reduced, choice);
+    }
+
     fn num_bits_to_shave() -> usize {
         64 * N - (Self::MODULUS_BIT_SIZE as usize)
     }
@@ -319,6 +356,7 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
     fn frobenius_map_in_place(&mut self, _: usize) {}
 
     #[inline]
+    #[cfg(not(feature = "ct"))]
     fn legendre(&self) -> LegendreSymbol {
         // s = self^((MODULUS - 1) // 2)
         let s = self.pow(Self::MODULUS_MINUS_ONE_DIV_TWO);
@@ -331,6 +369,18 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
         }
     }
 
+    #[inline]
+    #[cfg(feature = "ct")]
+    fn legendre(&self) -> LegendreSymbol {
+        self.ct_legendre()
+    }
+
+    #[inline]
+    #[cfg(feature = "ct")]
+    fn sqrt(&self) -> Option<Self> {
+        self.ct_sqrt().into()
+    }
+
     /// Fp is already a "BasePrimeField", so it's just mul by self
     #[inline]
     fn mul_by_base_prime_field(&self, elem: &Self::BasePrimeField) -> Self {
@@ -639,6 +689,9 @@ impl<P: FpConfig<N>, const N: usize> CanonicalDeserialize for Fp<P, N> {
     }
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([P: FpConfig<N>, const N: usize] Fp<P, N>);
+
 impl<P: FpConfig<N>, const N: usize> FromStr for Fp<P, N> {
     type Err = ();
 
@@ -4,6 +4,8 @@ use crate::{
 };
 use ark_ff_macros::unroll_for_loops;
 use ark_std::marker::PhantomData;
+#[cfg(feature = "ct")]
+use subtle::{ConditionallySelectable, ConstantTimeEq};
 
 /// A trait that specifies the constants and arithmetic procedures
 /// for Montgomery arithmetic over the prime field defined by `MODULUS`.
@@ -109,11 +111,23 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     /// Sets `a = a - b`.
     #[inline(always)]
     fn sub_assign(a: &mut Fp<MontBackend<Self, N>, N>, b: &Fp<MontBackend<Self, N>, N>) {
-        // If `other` is larger than `self`, add the modulus to self first.
-        if b.0 > a.0 {
-            a.0.add_with_carry(&Self::MODULUS);
+        #[cfg(not(feature = "ct"))]
+        {
+            // If `other` is larger than `self`, add the modulus to self first.
+            if b.0 > a.0 {
+                a.0.add_with_carry(&Self::MODULUS);
+            }
+            a.0.sub_with_borrow(&b.0);
+        }
+        #[cfg(feature = "ct")]
+        {
+            // Subtract unconditionally, and add the modulus back if the
+            // subtraction borrowed.
+            let borrow = a.0.sub_with_borrow(&b.0);
+            let mut corrected = a.0;
+            corrected.add_with_carry(&Self::MODULUS);
+            a.0.conditional_assign(&corrected, subtle::Choice::from(u8::from(borrow)));
         }
-        a.0.sub_with_borrow(&b.0);
     }
 
     /// Sets `a = 2 * a`.
@@ -132,11 +146,19 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     /// Sets `a = -a`.
     #[inline(always)]
     fn neg_in_place(a: &mut Fp<MontBackend<Self, N>, N>) {
+        #[cfg(not(feature = "ct"))]
         if !a.is_zero() {
             let mut tmp = Self::MODULUS;
             tmp.sub_with_borrow(&a.0);
             a.0 = tmp;
         }
+        #[cfg(feature = "ct")]
+        {
+            let mut tmp = Self::MODULUS;
+            tmp.sub_with_borrow(&a.0);
+            let is_zero = a.0.ct_eq(&BigInt::zero());
+            a.0 = BigInt::conditional_select(&tmp, &a.0, is_zero);
+        }
     }
 
     /// This modular multiplication algorithm uses Montgomery
@@ -293,64 +315,72 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     }
 
     fn inverse(a: &Fp<MontBackend<Self, N>, N>) -> Option<Fp<MontBackend<Self, N>, N>> {
-        if a.is_zero() {
-            return None;
+        #[cfg(feature = "ct")]
+        {
+            a.ct_inverse().into()
         }
-        // Guajardo Kumar Paar Pelzl
-        // Efficient Software-Implementation of Finite Fields with Applications to
-        // Cryptography
-        // Algorithm 16 (BEA for Inversion in Fp)
 
-        let one = BigInt::from(1u64);
-
-        let mut u = a.0;
-        let mut v = Self::MODULUS;
-        let mut b = Fp::new_unchecked(Self::R2); // Avoids unnecessary reduction step.
-        let mut c = Fp::zero();
+        #[cfg(not(feature = "ct"))]
+        {
+            if a.is_zero() {
+                return None;
+            }
+            // Guajardo Kumar Paar Pelzl
+            // Efficient Software-Implementation of Finite Fields with Applications to
+            // Cryptography
+            // Algorithm 16 (BEA for Inversion in Fp)
+
+            let one = BigInt::from(1u64);
+
+            let mut u = a.0;
+            let mut v = Self::MODULUS;
+            let mut b = Fp::new_unchecked(Self::R2); // Avoids unnecessary reduction step.
+            let mut c = Fp::zero();
+
+            while u != one && v != one {
+                while u.is_even() {
+                    u.div2();
+
+                    if b.0.is_even() {
+                        b.0.div2();
+                    } else {
+                        let carry = b.0.add_with_carry(&Self::MODULUS);
+                        b.0.div2();
+                        if !Self::MODULUS_HAS_SPARE_BIT && carry {
+                            (b.0).0[N - 1] |= 1 << 63;
+                        }
+                    }
+                }
 
-        while u != one && v != one {
-            while u.is_even() {
-                u.div2();
+                while v.is_even() {
+                    v.div2();
 
-                if b.0.is_even() {
-                    b.0.div2();
-                } else {
-                    let carry = b.0.add_with_carry(&Self::MODULUS);
-                    b.0.div2();
-                    if !Self::MODULUS_HAS_SPARE_BIT && carry {
-                        (b.0).0[N - 1] |= 1 << 63;
+                    if c.0.is_even() {
+                        c.0.div2();
+                    } else {
+                        let carry = c.0.add_with_carry(&Self::MODULUS);
+                        c.0.div2();
+                        if !Self::MODULUS_HAS_SPARE_BIT && carry {
+                            (c.0).0[N - 1] |= 1 << 63;
+                        }
                     }
                 }
-            }
 
-            while v.is_even() {
-                v.div2();
-
-                if c.0.is_even() {
-                    c.0.div2();
+                if v < u {
+                    u.sub_with_borrow(&v);
+                    b -= &c;
                 } else {
-                    let carry = c.0.add_with_carry(&Self::MODULUS);
-                    c.0.div2();
-                    if !Self::MODULUS_HAS_SPARE_BIT && carry {
-                        (c.0).0[N - 1] |= 1 << 63;
-                    }
+                    v.sub_with_borrow(&u);
+                    c -= &b;
                 }
             }
 
-            if v < u {
-                u.sub_with_borrow(&v);
-                b -= &c;
+            if u == one {
+                Some(b)
             } else {
-                v.sub_with_borrow(&u);
-                c -= &b;
+                Some(c)
             }
         }
-
-        if u == one {
-            Some(b)
-        } else {
-            Some(c)
-        }
     }
 
     fn from_bigint(r: BigInt<N>) -> Option<Fp<MontBackend<Self, N>, N>> {
@@ -0,0 +1,105 @@
+use super::{Fp64, FpConfig};
+use crate::PackedField;
+use ark_std::{
+    array, fmt,
+    ops::{Add, Mul, Neg, Sub},
+    Zero,
+};
+
+/// Number of lanes of [`PackedFp64`].
+const WIDTH: usize = 8;
+
+/// A vector of elements of a prime field with a single 64-bit limb, such as
+/// Goldilocks.
+///
+/// This is a portable fallback: it has no SIMD kernel on any target,
+/// including `x86_64` with AVX2 or AVX-512, and performs all operations lane
+/// by lane with scalar [`Fp64`] arithmetic. Neither AVX2 nor AVX-512 has an
+/// instruction for the full 64x64-bit product that the field multiplication
+/// needs. The compiler may still vectorize additions and subtractions for the
+/// extensions enabled where they are called, e.g. inside
+/// [`simd_dispatch`][`crate::simd_dispatch`], but no speedup over [`Fp64`]
+/// should be expected. Only [`PackedSmallFp`][`crate::PackedSmallFp`] has
+/// dedicated kernels.
+#[derive(Educe)]
+#[educe(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedFp64<P: FpConfig<1>>([Fp64<P>; WIDTH]);
+
+impl<P: FpConfig<1>> PackedFp64<P> {
+    #[inline(always)]
+    fn map2(self, rhs: &Self, f: impl Fn(Fp64<P>, &Fp64<P>) -> Fp64<P> + Copy) -> Self {
+        Self(array::from_fn(|i| f(self.0[i], &rhs.0[i])))
+    }
+}
+
+impl<P: FpConfig<1>> Default for PackedFp64<P> {
+    fn default() -> Self {
+        Self([Fp64::zero(); WIDTH])
+    }
+}
+
+impl<P: FpConfig<1>> fmt::Debug for PackedFp64<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+impl<P: FpConfig<1>> PackedField for PackedFp64<P> {
+    type Scalar = Fp64<P>;
+
+    const WIDTH: usize = WIDTH;
+
+    fn broadcast(value: Self::Scalar) -> Self {
+        Self([value; WIDTH])
+    }
+
+    fn from_fn(mut f: impl FnMut(usize) -> Self::Scalar) -> Self {
+        let mut res = [Fp64::zero(); WIDTH];
+        for (i, res) in res.iter_mut().enumerate() {
+            *res = f(i);
+        }
+        Self(res)
+    }
+
+    fn extract(&self, i: usize) -> Self::Scalar {
+        self.0[i]
+    }
+}
+
+impl<P: FpConfig<1>> Add<&PackedFp64<P>> for PackedFp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.map2(rhs, |a, b| a + b)
+    }
+}
+
+impl<P: FpConfig<1>> Sub<&PackedFp64<P>> for PackedFp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.map2(rhs, |a, b| a - b)
+    }
+}
+
+impl<P: FpConfig<1>> Mul<&PackedFp64<P>> for PackedFp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.map2(rhs, |a, b| a * b)
+    }
+}
+
+impl<P: FpConfig<1>> Neg for PackedFp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::default() - self
+    }
+}
+
+impl_packed_ops_from_ref!(PackedFp64, FpConfig<1>);
@@ -0,0 +1,681 @@
+use super::{Fp, Fp64, FpConfig};
+use crate::{BigInt, PackedField, PrimeField, SqrtPrecomputation};
+use ark_std::{
+    array, fmt,
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// A prime field of at most 31 bits, such as BabyBear (`15 * 2^27 + 1`),
+/// KoalaBear (`2^31 - 2^24 + 1`) or Mersenne-31 (`2^31 - 1`).
+///
+/// Elements are stored in a single limb, and [`PackedSmallFp`] provides
+/// vectors of elements with SIMD arithmetic.
+pub type SmallFp<P> = Fp64<SmallFpBackend<P>>;
+
+/// A trait that specifies the constants for Montgomery arithmetic over a
+/// prime field with modulus smaller than `2^31`, using `R = 2^32`.
+///
+/// Products of two elements fit in a `u64`, so multiplication is a single
+/// widening multiplication followed by a 32-bit Montgomery reduction, and
+/// all operations are branch-free.
+///
+/// # Note
+/// Manual implementation of this trait is not recommended. Instead, the
+/// [`SmallFpConfig`][`ark_ff_macros::SmallFpConfig`] derive macro should be
+/// used.
+pub trait SmallFpConfig: 'static + Sync + Send + Sized {
+    /// The modulus of the field.
+    const MODULUS: u32;
+
+    /// `R = 2^32 % Self::MODULUS`
+    #[doc(hidden)]
+    const R: u32 = ((1u64 << 32) % Self::MODULUS as u64) as u32;
+
+    /// `R2 = R^2 % Self::MODULUS`
+    #[doc(hidden)]
+    const R2: u32 = ((Self::R as u64 * Self::R as u64) % Self::MODULUS as u64) as u32;
+
+    /// `MODULUS^{-1} mod 2^32`
+    #[doc(hidden)]
+    const MODULUS_INV: u32 = modulus_inv::<Self>();
+
+    /// A multiplicative generator of the field.
+    /// `Self::GENERATOR` is an element having multiplicative order
+    /// `Self::MODULUS - 1`.
+    const GENERATOR: SmallFp<Self>;
+
+    /// 2^s root of unity computed by GENERATOR^t
+    const TWO_ADIC_ROOT_OF_UNITY: SmallFp<Self>;
+
+    /// An integer `b` such that there exists a multiplicative subgroup
+    /// of size `b^k` for some integer `k`.
+    const SMALL_SUBGROUP_BASE: Option<u32> = None;
+
+    /// The integer `k` such that there exists a multiplicative subgroup
+    /// of size `Self::SMALL_SUBGROUP_BASE^k`.
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = None;
+
+    /// GENERATOR^((MODULUS-1) / (2^s *
+    /// SMALL_SUBGROUP_BASE^SMALL_SUBGROUP_BASE_ADICITY)).
+    /// Used for mixed-radix FFT.
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<SmallFp<Self>> = None;
+
+    /// Precomputed material for use when computing square roots.
+    /// The default is to use the standard Tonelli-Shanks algorithm.
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<SmallFp<Self>>> = sqrt_precomputation::<Self>();
+
+    /// (MODULUS + 1) / 4 when MODULUS % 4 == 3. Used for square root precomputations.
+    #[doc(hidden)]
+    const MODULUS_PLUS_ONE_DIV_FOUR: Option<BigInt<1>> = match Self::MODULUS % 4 == 3 {
+        true => Some(BigInt([(Self::MODULUS as u64 + 1) / 4])),
+        false => None,
+    };
+}
+
+/// Compute `MODULUS^{-1} mod 2^32`, checking that the modulus is supported.
+const fn modulus_inv<T: SmallFpConfig>() -> u32 {
+    let p = T::MODULUS;
+    assert!(p % 2 == 1, "the modulus must be odd");
+    assert!(p < 1 << 31, "the modulus must be smaller than 2^31");
+    // For odd `p`, `p * p = 1 mod 8`, and each Newton iteration doubles the
+    // number of correct bits.
+    let mut inv = p;
+    crate::const_for!((_i in 0..4) {
+        inv = inv.wrapping_mul(2u32.wrapping_sub(p.wrapping_mul(inv)));
+    });
+    inv
+}
+
+const fn sqrt_precomputation<T: SmallFpConfig>() -> Option<SqrtPrecomputation<SmallFp<T>>> {
+    match T::MODULUS % 4 {
+        3 => match T::MODULUS_PLUS_ONE_DIV_FOUR.as_ref() {
+            Some(BigInt(modulus_plus_one_div_four)) => Some(SqrtPrecomputation::Case3Mod4 {
+                modulus_plus_one_div_four,
+            }),
+            None => None,
+        },
+        _ => Some(SqrtPrecomputation::TonelliShanks {
+            two_adicity: <SmallFpBackend<T>>::TWO_ADICITY,
+            quadratic_nonresidue_to_trace: T::TWO_ADIC_ROOT_OF_UNITY,
+            trace_of_modulus_minus_one_div_two: &<SmallFp<T>>::TRACE_MINUS_ONE_DIV_TWO.0,
+        }),
+    }
+}
+
+/// Construct a [`SmallFp<T>`] element from a literal string.
+///
+/// This is the analogue of [`MontFp!`](crate::MontFp) for fields using
+/// [`SmallFpBackend`].
+///
+/// # Usage
+///
+/// ```rust
+/// # use ark_ff::fields::{SmallFp, SmallFpConfig};
+/// # use ark_std::One;
+/// #[derive(SmallFpConfig)]
+/// #[modulus = "2013265921"]
+/// #[generator = "31"]
+/// pub struct BabyBearConfig;
+/// pub type BabyBear = SmallFp<BabyBearConfig>;
+///
+/// const NEG_ONE: BabyBear = ark_ff::SmallFp!("-1");
+/// assert_eq!(NEG_ONE, -BabyBear::one());
+/// ```
+#[macro_export]
+macro_rules! SmallFp {
+    ($c0:expr) => {{
+        let (is_positive, limbs) = $crate::ark_ff_macros::to_sign_and_limbs!($c0);
+        $crate::SmallFpBackend::from_sign_and_limbs(is_positive, &limbs)
+    }};
+}
+
+pub use ark_ff_macros::SmallFpConfig;
+
+pub struct SmallFpBackend<T: SmallFpConfig>(PhantomData<T>);
+
+impl<T: SmallFpConfig> FpConfig<1> for SmallFpBackend<T> {
+    /// The modulus of the field.
+    const MODULUS: crate::BigInt<1> = BigInt([T::MODULUS as u64]);
+
+    /// A multiplicative generator of the field.
+    /// `Self::GENERATOR` is an element having multiplicative order
+    /// `Self::MODULUS - 1`.
+    const GENERATOR: Fp<Self, 1> = T::GENERATOR;
+
+    /// Additive identity of the field, i.e. the element `e`
+    /// such that, for all elements `f` of the field, `e + f = f`.
+    const ZERO: Fp<Self, 1> = Fp(BigInt([0]), PhantomData);
+
+    /// Multiplicative identity of the field, i.e. the element `e`
+    /// such that, for all elements `f` of the field, `e * f = f`.
+    const ONE: Fp<Self, 1> = Fp(BigInt([T::R as u64]), PhantomData);
+
+    const TWO_ADICITY: u32 = Self::MODULUS.two_adic_valuation();
+    const TWO_ADIC_ROOT_OF_UNITY: Fp<Self, 1> = T::TWO_ADIC_ROOT_OF_UNITY;
+    const SMALL_SUBGROUP_BASE: Option<u32> = T::SMALL_SUBGROUP_BASE;
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = T::SMALL_SUBGROUP_BASE_ADICITY;
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Fp<Self, 1>> = T::LARGE_SUBGROUP_ROOT_OF_UNITY;
+    const SQRT_PRECOMP: Option<crate::SqrtPrecomputation<Fp<Self, 1>>> = T::SQRT_PRECOMP;
+
+    #[inline(always)]
+    fn add_assign(a: &mut Fp<Self, 1>, b: &Fp<Self, 1>) {
+        *a = Self::from_u32(Self::add(Self::to_u32(a), Self::to_u32(b)));
+    }
+
+    #[inline(always)]
+    fn sub_assign(a: &mut Fp<Self, 1>, b: &Fp<Self, 1>) {
+        *a = Self::from_u32(Self::sub(Self::to_u32(a), Self::to_u32(b)));
+    }
+
+    #[inline(always)]
+    fn double_in_place(a: &mut Fp<Self, 1>) {
+        let a_u32 = Self::to_u32(a);
+        *a = Self::from_u32(Self::add(a_u32, a_u32));
+    }
+
+    #[inline(always)]
+    fn neg_in_place(a: &mut Fp<Self, 1>) {
+        *a = Self::from_u32(Self::sub(0, Self::to_u32(a)));
+    }
+
+    #[inline(always)]
+    fn mul_assign(a: &mut Fp<Self, 1>, b: &Fp<Self, 1>) {
+        *a = Self::from_u32(Self::mul(Self::to_u32(a), Self::to_u32(b)));
+    }
+
+    fn sum_of_products<const M: usize>(a: &[Fp<Self, 1>; M], b: &[Fp<Self, 1>; M]) -> Fp<Self, 1> {
+        // Each product is smaller than 2^62, so we can accumulate four of
+        // them before reducing.
+        let mut sum = 0u32;
+        for (a, b) in a.chunks(4).zip(b.chunks(4)) {
+            let t = a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| Self::to_u32(a) as u64 * Self::to_u32(b) as u64)
+                .sum::<u64>();
+            // `t < 4 * p^2 < 2 * p * 2^32`, so a single subtraction brings it
+            // below `p * 2^32`, as required by the Montgomery reduction.
+            let t = Self::reduce_below(t, (T::MODULUS as u64) << 32);
+            sum = Self::add(sum, Self::mont_reduce(t));
+        }
+        Self::from_u32(sum)
+    }
+
+    #[inline(always)]
+    fn square_in_place(a: &mut Fp<Self, 1>) {
+        let a_u32 = Self::to_u32(a);
+        *a = Self::from_u32(Self::mul(a_u32, a_u32));
+    }
+
+    fn inverse(a: &Fp<Self, 1>) -> Option<Fp<Self, 1>> {
+        #[cfg(feature = "ct")]
+        {
+            a.ct_inverse().into()
+        }
+
+        #[cfg(not(feature = "ct"))]
+        {
+            use crate::Field;
+            if Self::to_u32(a) == 0 {
+                None
+            } else {
+                Some(a.pow([T::MODULUS as u64 - 2]))
+            }
+        }
+    }
+
+    fn from_bigint(r: BigInt<1>) -> Option<Fp<Self, 1>> {
+        if r.0[0] >= T::MODULUS as u64 {
+            None
+        } else {
+            Some(Self::from_u32(Self::mul(r.0[0] as u32, T::R2)))
+        }
+    }
+
+    #[inline]
+    fn into_bigint(a: Fp<Self, 1>) -> BigInt<1> {
+        BigInt([Self::mont_reduce(Self::to_u32(&a) as u64) as u64])
+    }
+}
+
+impl<T: SmallFpConfig> SmallFpBackend<T> {
+    /// Interpret a set of limbs (along with a sign) as a field element.
+    /// For *internal* use only; please use the `ark_ff::SmallFp` macro
+    /// instead of this method
+    #[doc(hidden)]
+    pub const fn from_sign_and_limbs(is_positive: bool, limbs: &[u64]) -> SmallFp<T> {
+        assert!(limbs.len() <= 1);
+        let p = T::MODULUS as u64;
+        let mut value = if limbs.is_empty() { 0 } else { limbs[0] % p };
+        if !is_positive && value != 0 {
+            value = p - value;
+        }
+        Fp(BigInt([(value << 32) % p]), PhantomData)
+    }
+
+    #[inline(always)]
+    const fn to_u32(a: &SmallFp<T>) -> u32 {
+        a.0 .0[0] as u32
+    }
+
+    #[inline(always)]
+    const fn from_u32(a: u32) -> SmallFp<T> {
+        Fp(BigInt([a as u64]), PhantomData)
+    }
+
+    /// Returns `a + b mod p`, for `a, b < p`.
+    #[inline(always)]
+    const fn add(a: u32, b: u32) -> u32 {
+        // `a + b < 2^32` since `p < 2^31`.
+        let (r, borrow) = (a + b).overflowing_sub(T::MODULUS);
+        r.wrapping_add(T::MODULUS & (borrow as u32).wrapping_neg())
+    }
+
+    /// Returns `a - b mod p`, for `a, b < p`.
+    #[inline(always)]
+    const fn sub(a: u32, b: u32) -> u32 {
+        let (r, borrow) = a.overflowing_sub(b);
+        r.wrapping_add(T::MODULUS & (borrow as u32).wrapping_neg())
+    }
+
+    /// Returns `a * b / R mod p`, for `a, b < p`.
+    #[inline(always)]
+    const fn mul(a: u32, b: u32) -> u32 {
+        Self::mont_reduce(a as u64 * b as u64)
+    }
+
+    /// Returns `t / R mod p`, for `t < p * 2^32`.
+    ///
+    /// With `m = t * p^{-1} mod 2^32`, the low halves of `t` and `m * p`
+    /// agree, so `(t - m * p) / 2^32` is the difference of their high
+    /// halves, which lies in `(-p, p)`.
+    #[inline(always)]
+    const fn mont_reduce(t: u64) -> u32 {
+        let m = (t as u32).wrapping_mul(T::MODULUS_INV);
+        let mp = m as u64 * T::MODULUS as u64;
+        Self::sub((t >> 32) as u32, (mp >> 32) as u32)
+    }
+
+    /// Returns `t - bound` if `t >= bound`, and `t` otherwise.
+    #[inline(always)]
+    const fn reduce_below(t: u64, bound: u64) -> u64 {
+        let (r, borrow) = t.overflowing_sub(bound);
+        r.wrapping_add(bound & (borrow as u64).wrapping_neg())
+    }
+}
+
+/// Number of lanes of [`PackedSmallFp`]: a single AVX-512 register, or two
+/// AVX2 registers.
+const WIDTH: usize = 16;
+
+/// A vector of [`SmallFp<T>`] elements, stored in Montgomery form as `u32`
+/// lanes.
+#[derive(Educe)]
+#[educe(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedSmallFp<T: SmallFpConfig>([u32; WIDTH], PhantomData<T>);
+
+/// A lane-wise operation on [`PackedSmallFp`].
+#[derive(Clone, Copy)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl<T: SmallFpConfig> PackedSmallFp<T> {
+    #[inline(always)]
+    fn apply(self, rhs: &Self, op: Op) -> Self {
+        let res = self
+            .apply_simd(rhs, op)
+            .unwrap_or_else(|| self.apply_scalar(rhs, op));
+        Self(res, PhantomData)
+    }
+
+    /// Returns `op(self, rhs)` computed with the widest available SIMD
+    /// extension, or `None` if there is none.
+    #[inline(always)]
+    #[allow(unreachable_code, unused_variables)]
+    fn apply_simd(&self, rhs: &Self, op: Op) -> Option<[u32; WIDTH]> {
+        #[cfg(target_arch = "x86_64")]
+        return x86::apply(&self.0, &rhs.0, op, T::MODULUS, T::MODULUS_INV);
+        #[cfg(target_arch = "aarch64")]
+        return aarch64::apply(&self.0, &rhs.0, op, T::MODULUS, T::MODULUS_INV);
+        None
+    }
+
+    #[inline(always)]
+    fn apply_scalar(&self, rhs: &Self, op: Op) -> [u32; WIDTH] {
+        let f = match op {
+            Op::Add => SmallFpBackend::<T>::add,
+            Op::Sub => SmallFpBackend::<T>::sub,
+            Op::Mul => SmallFpBackend::<T>::mul,
+        };
+        array::from_fn(|i| f(self.0[i], rhs.0[i]))
+    }
+}
+
+impl<T: SmallFpConfig> Default for PackedSmallFp<T> {
+    fn default() -> Self {
+        Self([0; WIDTH], PhantomData)
+    }
+}
+
+impl<T: SmallFpConfig> fmt::Debug for PackedSmallFp<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..WIDTH).map(|i| self.extract(i)))
+            .finish()
+    }
+}
+
+impl<T: SmallFpConfig> PackedField for PackedSmallFp<T> {
+    type Scalar = SmallFp<T>;
+
+    const WIDTH: usize = WIDTH;
+
+    fn broadcast(value: Self::Scalar) -> Self {
+        Self([SmallFpBackend::to_u32(&value); WIDTH], PhantomData)
+    }
+
+    fn from_fn(mut f: impl FnMut(usize) -> Self::Scalar) -> Self {
+        let mut res = [0u32; WIDTH];
+        for (i, res) in res.iter_mut().enumerate() {
+            *res = SmallFpBackend::to_u32(&f(i));
+        }
+        Self(res, PhantomData)
+    }
+
+    fn extract(&self, i: usize) -> Self::Scalar {
+        SmallFpBackend::from_u32(self.0[i])
+    }
+}
+
+impl<T: SmallFpConfig> Add<&PackedSmallFp<T>> for PackedSmallFp<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.apply(rhs, Op::Add)
+    }
+}
+
+impl<T: SmallFpConfig> Sub<&PackedSmallFp<T>> for PackedSmallFp<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.apply(rhs, Op::Sub)
+    }
+}
+
+impl<T: SmallFpConfig> Mul<&PackedSmallFp<T>> for PackedSmallFp<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.apply(rhs, Op::Mul)
+    }
+}
+
+impl<T: SmallFpConfig> Neg for PackedSmallFp<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::default() - self
+    }
+}
+
+impl_packed_ops_from_ref!(PackedSmallFp, SmallFpConfig);
+
+/// AVX2 and AVX-512 implementations of the lane-wise operations.
+///
+/// All of them are branch-free: the conditional corrections of
+/// [`SmallFpBackend`] become an unsigned minimum, since for `p < 2^31`
+/// exactly one of `t` and `t ± p` (wrapping) is in `[0, p)`, and it is the
+/// smaller one.
+#[cfg(target_arch = "x86_64")]
+#[allow(unsafe_code)]
+mod x86 {
+    use super::{Op, WIDTH};
+    #[cfg(feature = "std")]
+    use crate::fields::packed::SimdLevel;
+    #[allow(unused_imports)]
+    use core::arch::x86_64::*;
+
+    /// Returns `op(a, b)` lane-wise using the widest available extension, or
+    /// `None` if neither AVX2 nor AVX-512 is available.
+    #[inline(always)]
+    #[allow(unreachable_code, unused_variables)]
+    pub(super) fn apply(
+        a: &[u32; WIDTH],
+        b: &[u32; WIDTH],
+        op: Op,
+        p: u32,
+        p_inv: u32,
+    ) -> Option<[u32; WIDTH]> {
+        #[cfg(all(feature = "avx512", target_feature = "avx512f"))]
+        // SAFETY: AVX-512F is enabled at compile time.
+        return Some(unsafe { avx512(a, b, op, p, p_inv) });
+        #[cfg(feature = "std")]
+        {
+            let level = SimdLevel::detect();
+            #[cfg(feature = "avx512")]
+            if level == SimdLevel::Avx512 {
+                // SAFETY: the CPU supports AVX-512F.
+                return Some(unsafe { avx512(a, b, op, p, p_inv) });
+            }
+            if level >= SimdLevel::Avx2 {
+                // SAFETY: the CPU supports AVX2.
+                return Some(unsafe { avx2(a, b, op, p, p_inv) });
+            }
+        }
+        #[cfg(target_feature = "avx2")]
+        // SAFETY: AVX2 is enabled at compile time.
+        return Some(unsafe { avx2(a, b, op, p, p_inv) });
+        None
+    }
+
+    #[cfg(any(feature = "std", target_feature = "avx2"))]
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn avx2(a: &[u32; WIDTH], b: &[u32; WIDTH], op: Op, p: u32, p_inv: u32) -> [u32; WIDTH] {
+        let p = _mm256_set1_epi32(p as i32);
+        let p_inv = _mm256_set1_epi32(p_inv as i32);
+        let mut res = [0u32; WIDTH];
+        for i in (0..WIDTH).step_by(8) {
+            let x = _mm256_loadu_si256(a[i..].as_ptr().cast());
+            let y = _mm256_loadu_si256(b[i..].as_ptr().cast());
+            let z = match op {
+                Op::Add => {
+                    let t = _mm256_add_epi32(x, y);
+                    _mm256_min_epu32(t, _mm256_sub_epi32(t, p))
+                },
+                Op::Sub => {
+                    let t = _mm256_sub_epi32(x, y);
+                    _mm256_min_epu32(t, _mm256_add_epi32(t, p))
+                },
+                Op::Mul => {
+                    // Multiply the even and odd lanes separately into 64-bit
+                    // products `t`, and compute `m * p` for
+                    // `m = t * p^{-1} mod 2^32`. The low halves of `t` and
+                    // `m * p` agree, so the high half of `t - m * p` is the
+                    // difference of their high halves, in `(-p, p)`.
+                    let x_odd = _mm256_srli_epi64::<32>(x);
+                    let y_odd = _mm256_srli_epi64::<32>(y);
+                    let t_even = _mm256_mul_epu32(x, y);
+                    let t_odd = _mm256_mul_epu32(x_odd, y_odd);
+                    let mp_even = _mm256_mul_epu32(_mm256_mul_epu32(t_even, p_inv), p);
+                    let mp_odd = _mm256_mul_epu32(_mm256_mul_epu32(t_odd, p_inv), p);
+                    let d_even = _mm256_srli_epi64::<32>(_mm256_sub_epi64(t_even, mp_even));
+                    let d_odd = _mm256_sub_epi64(t_odd, mp_odd);
+                    let d = _mm256_blend_epi32::<0b10101010>(d_even, d_odd);
+                    _mm256_min_epu32(d, _mm256_add_epi32(d, p))
+                },
+            };
+            _mm256_storeu_si256(res[i..].as_mut_ptr().cast(), z);
+        }
+        res
+    }
+
+    // The `avx512` feature documents that it requires Rust 1.89.
+    #[cfg(feature = "avx512")]
+    #[allow(clippy::incompatible_msrv)]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn avx512(
+        a: &[u32; WIDTH],
+        b: &[u32; WIDTH],
+        op: Op,
+        p: u32,
+        p_inv: u32,
+    ) -> [u32; WIDTH] {
+        let p = _mm512_set1_epi32(p as i32);
+        let p_inv = _mm512_set1_epi32(p_inv as i32);
+        let x = _mm512_loadu_epi32(a.as_ptr().cast());
+        let y = _mm512_loadu_epi32(b.as_ptr().cast());
+        let z = match op {
+            Op::Add => {
+                let t = _mm512_add_epi32(x, y);
+                _mm512_min_epu32(t, _mm512_sub_epi32(t, p))
+            },
+            Op::Sub => {
+                let t = _mm512_sub_epi32(x, y);
+                _mm512_min_epu32(t, _mm512_add_epi32(t, p))
+            },
+            Op::Mul => {
+                // See `avx2`.
+                let x_odd = _mm512_srli_epi64::<32>(x);
+                let y_odd = _mm512_srli_epi64::<32>(y);
+                let t_even = _mm512_mul_epu32(x, y);
+                let t_odd = _mm512_mul_epu32(x_odd, y_odd);
+                let mp_even = _mm512_mul_epu32(_mm512_mul_epu32(t_even, p_inv), p);
+                let mp_odd = _mm512_mul_epu32(_mm512_mul_epu32(t_odd, p_inv), p);
+                let d_even = _mm512_srli_epi64::<32>(_mm512_sub_epi64(t_even, mp_even));
+                let d_odd = _mm512_sub_epi64(t_odd, mp_odd);
+                let d = _mm512_mask_blend_epi32(0b1010101010101010, d_even, d_odd);
+                _mm512_min_epu32(d, _mm512_add_epi32(d, p))
+            },
+        };
+        let mut res = [0u32; WIDTH];
+        _mm512_storeu_epi32(res.as_mut_ptr().cast(), z);
+        res
+    }
+}
+
+/// NEON implementation of the lane-wise operations, with the same
+/// branch-free corrections as the `x86` kernels.
+#[cfg(target_arch = "aarch64")]
+#[allow(unsafe_code)]
+mod aarch64 {
+    use super::{Op, WIDTH};
+    #[cfg(feature = "std")]
+    use crate::fields::packed::SimdLevel;
+    #[allow(unused_imports)]
+    use core::arch::aarch64::*;
+
+    /// Returns `op(a, b)` lane-wise using NEON, or `None` if it is not
+    /// available.
+    #[inline(always)]
+    #[allow(unreachable_code, unused_variables)]
+    pub(super) fn apply(
+        a: &[u32; WIDTH],
+        b: &[u32; WIDTH],
+        op: Op,
+        p: u32,
+        p_inv: u32,
+    ) -> Option<[u32; WIDTH]> {
+        #[cfg(feature = "std")]
+        if SimdLevel::detect() == SimdLevel::Neon {
+            // SAFETY: the CPU supports NEON.
+            return Some(unsafe { neon(a, b, op, p, p_inv) });
+        }
+        #[cfg(target_feature = "neon")]
+        // SAFETY: NEON is enabled at compile time.
+        return Some(unsafe { neon(a, b, op, p, p_inv) });
+        None
+    }
+
+    #[cfg(any(feature = "std", target_feature = "neon"))]
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn neon(a: &[u32; WIDTH], b: &[u32; WIDTH], op: Op, p: u32, p_inv: u32) -> [u32; WIDTH] {
+        let p = vdupq_n_u32(p);
+        let p_inv = vdupq_n_u32(p_inv);
+        let mut res = [0u32; WIDTH];
+        for i in (0..WIDTH).step_by(4) {
+            let x = vld1q_u32(a[i..].as_ptr());
+            let y = vld1q_u32(b[i..].as_ptr());
+            let z = match op {
+                Op::Add => {
+                    let t = vaddq_u32(x, y);
+                    vminq_u32(t, vsubq_u32(t, p))
+                },
+                Op::Sub => {
+                    let t = vsubq_u32(x, y);
+                    vminq_u32(t, vaddq_u32(t, p))
+                },
+                Op::Mul => {
+                    // As in the `x86` kernels, with `m = t * p^{-1} mod 2^32`
+                    // computed from the low halves of the products `t`. The
+                    // high halves of the 64-bit products `t` and `m * p` are
+                    // their odd 32-bit lanes, gathered with an unzip.
+                    let m = vmulq_u32(vmulq_u32(x, y), p_inv);
+                    let t_hi = vuzp2q_u32(
+                        vreinterpretq_u32_u64(vmull_u32(vget_low_u32(x), vget_low_u32(y))),
+                        vreinterpretq_u32_u64(vmull_high_u32(x, y)),
+                    );
+                    let mp_hi = vuzp2q_u32(
+                        vreinterpretq_u32_u64(vmull_u32(vget_low_u32(m), vget_low_u32(p))),
+                        vreinterpretq_u32_u64(vmull_high_u32(m, p)),
+                    );
+                    let d = vsubq_u32(t_hi, mp_hi);
+                    vminq_u32(d, vaddq_u32(d, p))
+                },
+            };
+            vst1q_u32(res[i..].as_mut_ptr(), z);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::{rand::Rng, test_rng};
+
+    /// BabyBear: `p = 15 * 2^27 + 1`.
+    struct BabyBearConfig;
+
+    impl SmallFpConfig for BabyBearConfig {
+        const MODULUS: u32 = 2013265921;
+        const GENERATOR: SmallFp<Self> = SmallFpBackend::from_sign_and_limbs(true, &[31]);
+        const TWO_ADIC_ROOT_OF_UNITY: SmallFp<Self> =
+            SmallFpBackend::from_sign_and_limbs(true, &[440564289]);
+    }
+
+    /// Checks that the SIMD kernels of the current target, if any, agree with
+    /// the scalar path.
+    #[test]
+    fn test_simd_matches_scalar() {
+        let p = BabyBearConfig::MODULUS;
+        let mut rng = test_rng();
+        for _ in 0..1000 {
+            let mut a: [u32; WIDTH] = array::from_fn(|_| rng.gen_range(0..p));
+            let mut b: [u32; WIDTH] = array::from_fn(|_| rng.gen_range(0..p));
+            // Exercise the extreme values too.
+            (a[0], b[0]) = (0, 0);
+            (a[1], b[1]) = (p - 1, p - 1);
+            (a[2], b[2]) = (0, p - 1);
+            (a[3], b[3]) = (p - 1, 0);
+            let a = PackedSmallFp::<BabyBearConfig>(a, PhantomData);
+            let b = PackedSmallFp::<BabyBearConfig>(b, PhantomData);
+            for op in [Op::Add, Op::Sub, Op::Mul] {
+                if let Some(res) = a.apply_simd(&b, op) {
+                    assert_eq!(res, a.apply_scalar(&b, op));
+                }
+            }
+        }
+    }
+}
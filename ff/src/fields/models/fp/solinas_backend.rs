@@ -0,0 +1,496 @@
+use super::{Fp, FpConfig};
+use crate::{BigInt, BigInteger, PrimeField, SqrtPrecomputation};
+use ark_std::marker::PhantomData;
+#[cfg(not(feature = "ct"))]
+use ark_std::Zero;
+
+/// A trait that specifies the constants for arithmetic over a prime field
+/// whose modulus has the pseudo-Mersenne (or Solinas) form `2^K - C`, where
+/// `K` is the bit length of the modulus and `C` fits in a single limb.
+///
+/// Elements are stored in canonical form, and products are reduced by
+/// repeatedly folding the bits above position `K` back in, using
+/// `2^K = C mod MODULUS`. Mersenne primes such as `2^31 - 1` are the special
+/// case `C = 1`; other examples are Goldilocks (`2^64 - 2^32 + 1`),
+/// `2^255 - 19` and the base field of secp256k1 (`2^256 - 2^32 - 977`).
+///
+/// # Note
+/// Manual implementation of this trait is not recommended. Instead, the
+/// [`SolinasConfig`][`ark_ff_macros::SolinasConfig`] derive macro should be
+/// used.
+pub trait SolinasConfig<const N: usize>: 'static + Sync + Send + Sized {
+    /// The modulus of the field.
+    const MODULUS: BigInt<N>;
+
+    /// The bit length `K` of `Self::MODULUS`.
+    #[doc(hidden)]
+    const K: u32 = Self::MODULUS.const_num_bits();
+
+    /// `C = 2^K - Self::MODULUS`.
+    ///
+    /// Evaluating this constant panics if `Self::MODULUS` is not of the form
+    /// `2^K - C` with `(C + 1)^2 <= 2^K`, which is required for the reduction
+    /// to terminate after a fixed number of folds.
+    #[doc(hidden)]
+    const C: u64 = solinas_c::<Self, N>();
+
+    /// Does the modulus have a spare unused bit
+    ///
+    /// This condition applies if
+    /// (a) `Self::MODULUS[N-1] >> 63 == 0`
+    #[doc(hidden)]
+    const MODULUS_HAS_SPARE_BIT: bool = Self::MODULUS.0[N - 1] >> 63 == 0;
+
+    /// A multiplicative generator of the field.
+    /// `Self::GENERATOR` is an element having multiplicative order
+    /// `Self::MODULUS - 1`.
+    const GENERATOR: Fp<SolinasBackend<Self, N>, N>;
+
+    /// 2^s root of unity computed by GENERATOR^t
+    const TWO_ADIC_ROOT_OF_UNITY: Fp<SolinasBackend<Self, N>, N>;
+
+    /// An integer `b` such that there exists a multiplicative subgroup
+    /// of size `b^k` for some integer `k`.
+    const SMALL_SUBGROUP_BASE: Option<u32> = None;
+
+    /// The integer `k` such that there exists a multiplicative subgroup
+    /// of size `Self::SMALL_SUBGROUP_BASE^k`.
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = None;
+
+    /// GENERATOR^((MODULUS-1) / (2^s *
+    /// SMALL_SUBGROUP_BASE^SMALL_SUBGROUP_BASE_ADICITY)).
+    /// Used for mixed-radix FFT.
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Fp<SolinasBackend<Self, N>, N>> = None;
+
+    /// Precomputed material for use when computing square roots.
+    /// The default is to use the standard Tonelli-Shanks algorithm.
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Fp<SolinasBackend<Self, N>, N>>> =
+        sqrt_precomputation();
+
+    /// (MODULUS + 1) / 4 when MODULUS % 4 == 3. Used for square root precomputations.
+    #[doc(hidden)]
+    const MODULUS_PLUS_ONE_DIV_FOUR: Option<BigInt<N>> = {
+        match Self::MODULUS.mod_4() == 3 {
+            true => {
+                let (modulus_plus_one, carry) = Self::MODULUS.const_add_with_carry(&BigInt::one());
+                let mut result = modulus_plus_one.divide_by_2_round_down();
+                // Since modulus_plus_one is even, dividing by 2 results in a MSB of 0.
+                // Thus we can set MSB to `carry` to get the correct result of (MODULUS + 1) // 2:
+                result.0[N - 1] |= (carry as u64) << 63;
+                Some(result.divide_by_2_round_down())
+            },
+            false => None,
+        }
+    };
+}
+
+/// Compute `2^K - MODULUS`, checking that the modulus has the required form.
+const fn solinas_c<T: SolinasConfig<N>, const N: usize>() -> u64 {
+    let k = T::MODULUS.const_num_bits();
+    assert!(
+        k > 64 * (N as u32 - 1),
+        "the modulus must use the most significant limb"
+    );
+    // 2^K - MODULUS, computed modulo 2^(64 * N) so that K = 64 * N is handled too.
+    let mut two_to_k = BigInt([0u64; N]);
+    if k < 64 * N as u32 {
+        two_to_k.0[N - 1] = 1 << (k % 64);
+    }
+    let (c, _) = two_to_k.const_sub_with_borrow(&T::MODULUS);
+    crate::const_for!((i in 1..N) {
+        assert!(c.0[i] == 0, "2^K - MODULUS must fit in a single limb");
+    });
+    let c = c.0[0];
+    // The reduction needs c^2 + 2c < 2^K, i.e. (c + 1)^2 <= 2^K.
+    if k < 128 {
+        let c_plus_one = c as u128 + 1;
+        match c_plus_one.checked_mul(c_plus_one) {
+            Some(sq) => assert!(sq <= 1 << k, "2^K - MODULUS is too large"),
+            None => panic!("2^K - MODULUS is too large"),
+        }
+    }
+    c
+}
+
+const fn sqrt_precomputation<const N: usize, T: SolinasConfig<N>>(
+) -> Option<SqrtPrecomputation<Fp<SolinasBackend<T, N>, N>>> {
+    match T::MODULUS.mod_4() {
+        3 => match T::MODULUS_PLUS_ONE_DIV_FOUR.as_ref() {
+            Some(BigInt(modulus_plus_one_div_four)) => Some(SqrtPrecomputation::Case3Mod4 {
+                modulus_plus_one_div_four,
+            }),
+            None => None,
+        },
+        _ => Some(SqrtPrecomputation::TonelliShanks {
+            two_adicity: <SolinasBackend<T, N>>::TWO_ADICITY,
+            quadratic_nonresidue_to_trace: T::TWO_ADIC_ROOT_OF_UNITY,
+            trace_of_modulus_minus_one_div_two:
+                &<Fp<SolinasBackend<T, N>, N>>::TRACE_MINUS_ONE_DIV_TWO.0,
+        }),
+    }
+}
+
+/// Construct a [`Fp<SolinasBackend<T, N>, N>`] element from a literal string.
+///
+/// This is the analogue of [`MontFp!`](crate::MontFp) for fields using
+/// [`SolinasBackend`].
+///
+/// # Panics
+///
+/// If the integer represented by the string cannot fit in the number
+/// of limbs of the `Fp`, this macro results in a
+/// * compile-time error if used in a const context
+/// * run-time error otherwise.
+///
+/// # Usage
+///
+/// ```rust
+/// # use ark_ff::{fields::{Fp64, SolinasBackend, SolinasConfig}, SolinasFp};
+/// # use ark_std::One;
+/// #[derive(SolinasConfig)]
+/// #[modulus = "2147483647"]
+/// #[generator = "7"]
+/// pub struct FConfig;
+/// pub type F = Fp64<SolinasBackend<FConfig, 1>>;
+///
+/// const NEG_ONE: F = SolinasFp!("-1");
+/// assert_eq!(NEG_ONE, -F::one());
+/// ```
+#[macro_export]
+macro_rules! SolinasFp {
+    ($c0:expr) => {{
+        let (is_positive, limbs) = $crate::ark_ff_macros::to_sign_and_limbs!($c0);
+        $crate::SolinasBackend::from_sign_and_limbs(is_positive, &limbs)
+    }};
+}
+
+pub use ark_ff_macros::SolinasConfig;
+
+pub use SolinasFp;
+
+pub struct SolinasBackend<T: SolinasConfig<N>, const N: usize>(PhantomData<T>);
+
+impl<T: SolinasConfig<N>, const N: usize> FpConfig<N> for SolinasBackend<T, N> {
+    /// The modulus of the field.
+    const MODULUS: crate::BigInt<N> = T::MODULUS;
+
+    /// A multiplicative generator of the field.
+    /// `Self::GENERATOR` is an element having multiplicative order
+    /// `Self::MODULUS - 1`.
+    const GENERATOR: Fp<Self, N> = T::GENERATOR;
+
+    /// Additive identity of the field, i.e. the element `e`
+    /// such that, for all elements `f` of the field, `e + f = f`.
+    const ZERO: Fp<Self, N> = Fp(BigInt([0u64; N]), PhantomData);
+
+    /// Multiplicative identity of the field, i.e. the element `e`
+    /// such that, for all elements `f` of the field, `e * f = f`.
+    const ONE: Fp<Self, N> = Fp(BigInt::one(), PhantomData);
+
+    const TWO_ADICITY: u32 = Self::MODULUS.two_adic_valuation();
+    const TWO_ADIC_ROOT_OF_UNITY: Fp<Self, N> = T::TWO_ADIC_ROOT_OF_UNITY;
+    const SMALL_SUBGROUP_BASE: Option<u32> = T::SMALL_SUBGROUP_BASE;
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = T::SMALL_SUBGROUP_BASE_ADICITY;
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Fp<Self, N>> = T::LARGE_SUBGROUP_ROOT_OF_UNITY;
+    const SQRT_PRECOMP: Option<crate::SqrtPrecomputation<Fp<Self, N>>> = T::SQRT_PRECOMP;
+
+    #[inline(always)]
+    fn add_assign(a: &mut Fp<Self, N>, b: &Fp<Self, N>) {
+        // This cannot exceed the backing capacity.
+        let c = a.0.add_with_carry(&b.0);
+        // However, it may need to be reduced
+        if T::MODULUS_HAS_SPARE_BIT {
+            a.subtract_modulus()
+        } else {
+            a.subtract_modulus_with_carry(c)
+        }
+    }
+
+    #[inline(always)]
+    fn sub_assign(a: &mut Fp<Self, N>, b: &Fp<Self, N>) {
+        #[cfg(not(feature = "ct"))]
+        {
+            // If `other` is larger than `self`, add the modulus to self first.
+            if b.0 > a.0 {
+                a.0.add_with_carry(&T::MODULUS);
+            }
+            a.0.sub_with_borrow(&b.0);
+        }
+        #[cfg(feature = "ct")]
+        {
+            use subtle::ConditionallySelectable;
+            // Subtract unconditionally, and add the modulus back if the
+            // subtraction borrowed.
+            let borrow = a.0.sub_with_borrow(&b.0);
+            let mut corrected = a.0;
+            corrected.add_with_carry(&T::MODULUS);
+            a.0.conditional_assign(&corrected, subtle::Choice::from(u8::from(borrow)));
+        }
+    }
+
+    #[inline(always)]
+    fn double_in_place(a: &mut Fp<Self, N>) {
+        // This cannot exceed the backing capacity.
+        let c = a.0.mul2();
+        // However, it may need to be reduced.
+        if T::MODULUS_HAS_SPARE_BIT {
+            a.subtract_modulus()
+        } else {
+            a.subtract_modulus_with_carry(c)
+        }
+    }
+
+    #[inline(always)]
+    fn neg_in_place(a: &mut Fp<Self, N>) {
+        #[cfg(not(feature = "ct"))]
+        if !a.is_zero() {
+            let mut tmp = T::MODULUS;
+            tmp.sub_with_borrow(&a.0);
+            a.0 = tmp;
+        }
+        #[cfg(feature = "ct")]
+        {
+            use subtle::{ConditionallySelectable, ConstantTimeEq};
+            let mut tmp = T::MODULUS;
+            tmp.sub_with_borrow(&a.0);
+            let is_zero = a.0.ct_eq(&BigInt::zero());
+            a.0 = BigInt::conditional_select(&tmp, &a.0, is_zero);
+        }
+    }
+
+    /// Computes the double-width product and reduces it with
+    /// `Self::reduce`, followed by a single conditional subtraction.
+    #[inline]
+    fn mul_assign(a: &mut Fp<Self, N>, b: &Fp<Self, N>) {
+        let (lo, hi) = Self::mul_wide(&a.0, &b.0);
+        a.0 = Self::reduce(lo, hi);
+        a.subtract_modulus();
+    }
+
+    fn sum_of_products<const M: usize>(a: &[Fp<Self, N>; M], b: &[Fp<Self, N>; M]) -> Fp<Self, N> {
+        a.iter().zip(b).map(|(a, b)| *a * b).sum()
+    }
+
+    #[inline]
+    fn square_in_place(a: &mut Fp<Self, N>) {
+        let (lo, hi) = Self::mul_wide(&a.0, &a.0);
+        a.0 = Self::reduce(lo, hi);
+        a.subtract_modulus();
+    }
+
+    fn inverse(a: &Fp<Self, N>) -> Option<Fp<Self, N>> {
+        #[cfg(feature = "ct")]
+        {
+            a.ct_inverse().into()
+        }
+
+        #[cfg(not(feature = "ct"))]
+        {
+            if a.is_zero() {
+                return None;
+            }
+            // Guajardo Kumar Paar Pelzl
+            // Efficient Software-Implementation of Finite Fields with Applications to
+            // Cryptography
+            // Algorithm 16 (BEA for Inversion in Fp)
+
+            let one = BigInt::from(1u64);
+
+            let mut u = a.0;
+            let mut v = T::MODULUS;
+            let mut b = Self::ONE;
+            let mut c = Self::ZERO;
+
+            while u != one && v != one {
+                while u.is_even() {
+                    u.div2();
+
+                    if b.0.is_even() {
+                        b.0.div2();
+                    } else {
+                        let carry = b.0.add_with_carry(&T::MODULUS);
+                        b.0.div2();
+                        if !T::MODULUS_HAS_SPARE_BIT && carry {
+                            (b.0).0[N - 1] |= 1 << 63;
+                        }
+                    }
+                }
+
+                while v.is_even() {
+                    v.div2();
+
+                    if c.0.is_even() {
+                        c.0.div2();
+                    } else {
+                        let carry = c.0.add_with_carry(&T::MODULUS);
+                        c.0.div2();
+                        if !T::MODULUS_HAS_SPARE_BIT && carry {
+                            (c.0).0[N - 1] |= 1 << 63;
+                        }
+                    }
+                }
+
+                if v < u {
+                    u.sub_with_borrow(&v);
+                    b -= &c;
+                } else {
+                    v.sub_with_borrow(&u);
+                    c -= &b;
+                }
+            }
+
+            if u == one {
+                Some(b)
+            } else {
+                Some(c)
+            }
+        }
+    }
+
+    fn from_bigint(r: BigInt<N>) -> Option<Fp<Self, N>> {
+        if r >= T::MODULUS {
+            None
+        } else {
+            Some(Fp(r, PhantomData))
+        }
+    }
+
+    #[inline]
+    fn into_bigint(a: Fp<Self, N>) -> BigInt<N> {
+        a.0
+    }
+}
+
+impl<T: SolinasConfig<N>, const N: usize> SolinasBackend<T, N> {
+    /// Index of the limb containing bit `K`.
+    const K_LIMB: usize = (T::K / 64) as usize;
+
+    /// Offset of bit `K` within [`Self::K_LIMB`].
+    const K_SHIFT: u32 = T::K % 64;
+
+    /// Interpret a set of limbs (along with a sign) as a field element.
+    /// For *internal* use only; please use the `ark_ff::SolinasFp` macro
+    /// instead of this method
+    #[doc(hidden)]
+    pub const fn from_sign_and_limbs(is_positive: bool, limbs: &[u64]) -> Fp<Self, N> {
+        let mut repr = [0u64; N];
+        assert!(limbs.len() <= N);
+        crate::const_for!((i in 0..(limbs.len())) {
+            repr[i] = limbs[i];
+        });
+        // The input can be as large as 2^(64 * N), so fold until it is below
+        // 2^K, and then subtract the modulus at most once.
+        let (mut t, mut top) = (repr, 0);
+        while top != 0 || Self::high_bits(&t, top) != 0 {
+            (t, top) = Self::fold(t, top);
+        }
+        let mut res = BigInt(t);
+        if res.const_geq(&T::MODULUS) {
+            res = res.const_sub_with_borrow(&T::MODULUS).0;
+        }
+        if is_positive || res.const_is_zero() {
+            Fp(res, PhantomData)
+        } else {
+            Fp(T::MODULUS.const_sub_with_borrow(&res).0, PhantomData)
+        }
+    }
+
+    /// Computes the `2 * N`-limb product `a * b`, returned as `(lo, hi)`.
+    #[inline(always)]
+    const fn mul_wide(a: &BigInt<N>, b: &BigInt<N>) -> ([u64; N], [u64; N]) {
+        let (mut lo, mut hi) = ([0u64; N], [0u64; N]);
+        crate::const_for!((i in 0..N) {
+            let mut carry = 0;
+            crate::const_for!((j in 0..N) {
+                let k = i + j;
+                if k >= N {
+                    hi[k - N] = mac_with_carry!(hi[k - N], a.0[i], b.0[j], &mut carry);
+                } else {
+                    lo[k] = mac_with_carry!(lo[k], a.0[i], b.0[j], &mut carry);
+                }
+            });
+            hi[i] = carry;
+        });
+        (lo, hi)
+    }
+
+    /// Reduces `x = hi * 2^(64 * N) + lo`, where `x < MODULUS^2`, to a value
+    /// below `2^K` that is congruent to `x`.
+    ///
+    /// Writing `x = h * 2^K + l`, we have `x = l + C * h mod MODULUS`, and
+    /// `l + C * h < (C + 1) * 2^K`. Two further folds bring the value below
+    /// `2^K`: the first leaves at most `2^K + C^2`, and the second at most
+    /// `C^2 + C < 2^K`.
+    #[inline(always)]
+    const fn reduce(lo: [u64; N], hi: [u64; N]) -> BigInt<N> {
+        // Split x at bit K.
+        let mut l = lo;
+        let mut h = [0u64; N];
+        crate::const_for!((i in 0..N) {
+            let limb = Self::limb(&lo, &hi, Self::K_LIMB + i);
+            h[i] = if Self::K_SHIFT == 0 {
+                limb
+            } else {
+                (limb >> Self::K_SHIFT) | (Self::limb(&lo, &hi, Self::K_LIMB + i + 1) << (64 - Self::K_SHIFT))
+            };
+        });
+        if Self::K_SHIFT != 0 {
+            l[N - 1] &= (1 << Self::K_SHIFT) - 1;
+        }
+
+        // t = l + C * h
+        let mut carry = 0;
+        crate::const_for!((i in 0..N) {
+            l[i] = mac_with_carry!(l[i], h[i], T::C, &mut carry);
+        });
+
+        let (t, top) = Self::fold(l, carry);
+        let (t, _) = Self::fold(t, top);
+        BigInt(t)
+    }
+
+    /// Returns the `i`-th limb of `hi * 2^(64 * N) + lo`.
+    #[inline(always)]
+    const fn limb(lo: &[u64; N], hi: &[u64; N], i: usize) -> u64 {
+        if i < N {
+            lo[i]
+        } else if i < 2 * N {
+            hi[i - N]
+        } else {
+            0
+        }
+    }
+
+    /// Returns `(t + top * 2^(64 * N)) >> K`, which must fit in a `u64`.
+    #[inline(always)]
+    const fn high_bits(t: &[u64; N], top: u64) -> u64 {
+        if Self::K_SHIFT == 0 {
+            top
+        } else {
+            (t[N - 1] >> Self::K_SHIFT) | (top << (64 - Self::K_SHIFT))
+        }
+    }
+
+    /// Given `x = t + top * 2^(64 * N)` with `x >> K < 2^64`, computes
+    /// `(x mod 2^K) + C * (x >> K)`.
+    #[inline(always)]
+    const fn fold(mut t: [u64; N], top: u64) -> ([u64; N], u64) {
+        let h = Self::high_bits(&t, top);
+        if Self::K_SHIFT != 0 {
+            t[N - 1] &= (1 << Self::K_SHIFT) - 1;
+        }
+        let prod = (T::C as u128) * (h as u128);
+        let (prod_lo, prod_hi) = (prod as u64, (prod >> 64) as u64);
+        let mut carry = 0;
+        t[0] = adc!(t[0], prod_lo, &mut carry);
+        if N == 1 {
+            // The sum is at most 2^K + C * h < 2^128, so this cannot overflow.
+            return (t, carry + prod_hi);
+        }
+        t[1] = adc!(t[1], prod_hi, &mut carry);
+        crate::const_for!((i in 2..N) {
+            t[i] = adc!(t[i], 0, &mut carry);
+        });
+        (t, carry)
+    }
+}
@@ -12,6 +12,8 @@ pub use self::fp4::*;
 
 pub mod fp6_2over3;
 
+pub mod binary;
+
 pub mod fp6_3over2;
 pub use self::fp6_3over2::*;
 
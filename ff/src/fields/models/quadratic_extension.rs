@@ -752,6 +752,9 @@ impl<P: QuadExtConfig> CanonicalDeserialize for QuadExtField<P> {
     }
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([P: QuadExtConfig] QuadExtField<P>);
+
 impl<P: QuadExtConfig> ToConstraintField<P::BasePrimeField> for QuadExtField<P>
 where
     P::BaseField: ToConstraintField<P::BasePrimeField>,
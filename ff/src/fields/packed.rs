@@ -0,0 +1,272 @@
+use crate::Field;
+use ark_std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// A vector of [`Self::WIDTH`] field elements, supporting lane-wise
+/// arithmetic.
+///
+/// Implementations use SIMD instructions where available. With the `std`
+/// feature, AVX2 (and AVX-512, if the `avx512` feature is enabled) on
+/// `x86_64` and NEON on `aarch64` are detected once at runtime and the result
+/// is cached. Otherwise, the instructions enabled at compile time are used,
+/// and other targets fall back to lane-by-lane arithmetic.
+///
+/// The dispatch on the detected extension is still made on every
+/// operation, so hot loops should be run through [`simd_dispatch`], or
+/// compiled with the extensions enabled (e.g. with `-C target-cpu=native`).
+pub trait PackedField:
+    'static
+    + Copy
+    + Send
+    + Sync
+    + Debug
+    + Default
+    + PartialEq
+    + Eq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+{
+    /// The type of each lane.
+    type Scalar: Field;
+
+    /// The number of lanes.
+    const WIDTH: usize;
+
+    /// Returns a vector with every lane set to `value`.
+    fn broadcast(value: Self::Scalar) -> Self;
+
+    /// Returns a vector whose `i`-th lane is `f(i)`.
+    fn from_fn(f: impl FnMut(usize) -> Self::Scalar) -> Self;
+
+    /// Returns the `i`-th lane.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= Self::WIDTH`.
+    fn extract(&self, i: usize) -> Self::Scalar;
+
+    /// Returns a vector whose lanes are the elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice.len() != Self::WIDTH`.
+    fn from_slice(slice: &[Self::Scalar]) -> Self {
+        assert_eq!(slice.len(), Self::WIDTH);
+        Self::from_fn(|i| slice[i])
+    }
+
+    /// Writes the lanes of `self` to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out.len() != Self::WIDTH`.
+    fn write_to_slice(&self, out: &mut [Self::Scalar]) {
+        assert_eq!(out.len(), Self::WIDTH);
+        for (i, out) in out.iter_mut().enumerate() {
+            *out = self.extract(i);
+        }
+    }
+
+    /// Returns `self * self`.
+    fn square(&self) -> Self {
+        *self * self
+    }
+
+    /// Returns `self + self`.
+    fn double(&self) -> Self {
+        *self + self
+    }
+}
+
+/// Implements the by-value and assigning arithmetic operators of a packed
+/// type in terms of the by-reference ones.
+macro_rules! impl_packed_ops_from_ref {
+    ($type:ident, $($bound:tt)+) => {
+        impl<T: $($bound)+> ark_std::ops::Add for $type<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                self + &rhs
+            }
+        }
+
+        impl<T: $($bound)+> ark_std::ops::Sub for $type<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                self - &rhs
+            }
+        }
+
+        impl<T: $($bound)+> ark_std::ops::Mul for $type<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self * &rhs
+            }
+        }
+
+        impl<T: $($bound)+> ark_std::ops::AddAssign for $type<T> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + &rhs;
+            }
+        }
+
+        impl<T: $($bound)+> ark_std::ops::SubAssign for $type<T> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - &rhs;
+            }
+        }
+
+        impl<T: $($bound)+> ark_std::ops::MulAssign for $type<T> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * &rhs;
+            }
+        }
+    };
+}
+
+/// Calls `f` from a function compiled for the widest SIMD extension
+/// supported by the CPU.
+///
+/// Code inlined into `f`, including the operations of [`PackedField`]
+/// implementations, is then compiled for that extension, so runtime
+/// detection is done once rather than on every operation. If the extension
+/// is already enabled at compile time, `f` is called directly.
+///
+/// ```
+/// use ark_ff::{simd_dispatch, PackedField};
+///
+/// fn mul_assign<P: PackedField>(a: &mut [P], b: &[P]) {
+///     simd_dispatch(|| {
+///         for (a, b) in a.iter_mut().zip(b) {
+///             *a *= *b;
+///         }
+///     })
+/// }
+/// ```
+#[inline(always)]
+pub fn simd_dispatch<R>(f: impl FnOnce() -> R) -> R {
+    #[cfg(all(
+        feature = "std",
+        target_arch = "x86_64",
+        not(target_feature = "avx512f")
+    ))]
+    {
+        #[allow(unused_variables)]
+        let level = SimdLevel::detect();
+        #[cfg(feature = "avx512")]
+        if level == SimdLevel::Avx512 {
+            // SAFETY: the CPU supports AVX-512F.
+            #[allow(unsafe_code)]
+            return unsafe { x86::avx512(f) };
+        }
+        #[cfg(not(target_feature = "avx2"))]
+        if level >= SimdLevel::Avx2 {
+            // SAFETY: the CPU supports AVX2.
+            #[allow(unsafe_code)]
+            return unsafe { x86::avx2(f) };
+        }
+    }
+    #[cfg(all(feature = "std", target_arch = "aarch64", not(target_feature = "neon")))]
+    if SimdLevel::detect() == SimdLevel::Neon {
+        // SAFETY: the CPU supports NEON.
+        #[allow(unsafe_code)]
+        return unsafe { aarch64::neon(f) };
+    }
+    f()
+}
+
+/// The widest SIMD extension supported by the CPU.
+#[cfg(all(feature = "std", any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum SimdLevel {
+    Scalar,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    #[cfg(target_arch = "x86_64")]
+    Avx512,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+impl SimdLevel {
+    /// Returns the widest extension supported by the CPU, among those that
+    /// are compiled in. It is detected on the first call only.
+    #[inline]
+    pub(crate) fn detect() -> Self {
+        static LEVEL: std::sync::OnceLock<SimdLevel> = std::sync::OnceLock::new();
+        *LEVEL.get_or_init(|| {
+            if cfg!(feature = "avx512") && std::is_x86_feature_detected!("avx512f") {
+                Self::Avx512
+            } else if std::is_x86_feature_detected!("avx2") {
+                Self::Avx2
+            } else {
+                Self::Scalar
+            }
+        })
+    }
+}
+
+#[cfg(all(feature = "std", target_arch = "aarch64"))]
+impl SimdLevel {
+    /// Returns [`SimdLevel::Neon`] if the CPU supports NEON. It is detected
+    /// on the first call only.
+    #[inline]
+    pub(crate) fn detect() -> Self {
+        static LEVEL: std::sync::OnceLock<SimdLevel> = std::sync::OnceLock::new();
+        *LEVEL.get_or_init(|| {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                Self::Neon
+            } else {
+                Self::Scalar
+            }
+        })
+    }
+}
+
+#[cfg(all(
+    feature = "std",
+    target_arch = "x86_64",
+    not(target_feature = "avx512f")
+))]
+#[allow(unsafe_code)]
+mod x86 {
+    #[cfg(not(target_feature = "avx2"))]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn avx2<R>(f: impl FnOnce() -> R) -> R {
+        f()
+    }
+
+    #[cfg(feature = "avx512")]
+    #[target_feature(enable = "avx512f")]
+    pub(super) unsafe fn avx512<R>(f: impl FnOnce() -> R) -> R {
+        f()
+    }
+}
+
+#[cfg(all(feature = "std", target_arch = "aarch64", not(target_feature = "neon")))]
+#[allow(unsafe_code)]
+mod aarch64 {
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn neon<R>(f: impl FnOnce() -> R) -> R {
+        f()
+    }
+}
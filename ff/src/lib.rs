@@ -34,6 +34,9 @@ pub(crate) mod const_helpers;
 
 pub use ark_std::UniformRand;
 
+#[cfg(feature = "ct")]
+pub use subtle;
+
 mod to_field_vec;
 pub use to_field_vec::ToConstraintField;
 
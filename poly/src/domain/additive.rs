@@ -0,0 +1,859 @@
+//! This module defines `AdditiveEvaluationDomain`, an `EvaluationDomain`
+//! for fields of characteristic two, whose elements form an affine subspace
+//! over `GF(2)` instead of a coset of a multiplicative subgroup.
+//!
+//! FFTs use the novel polynomial basis of Lin, Chung and Han
+//! ([LCH14](https://arxiv.org/abs/1404.3458)), and run in `O(n log n)` field
+//! operations in that basis and `O(n log^2 n)` in the monomial basis.
+//!
+//! `AdditiveEvaluationDomain` supports FFTs of size at most `2^k` over
+//! `GF(2^k)`.
+
+use crate::{
+    domain::{utils::reduce_by_monic_sparse, DomainCoeff, EvaluationDomain},
+    univariate::{DenseOrSparsePolynomial, DensePolynomial, SparsePolynomial},
+};
+use ark_ff::{batch_inversion, FftField, Field, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::{fmt, hash, vec::*};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Defines a domain over which finite field (I)FFTs can be performed in
+/// fields of characteristic two.
+///
+/// Let `β_0, ..., β_{k-1}` be the canonical basis of `F` over `GF(2)`. The
+/// domain of size `2^l` is the affine subspace `offset + V_l`, where
+/// `V_l = span(β_0, ..., β_{l-1})`, and its `i`-th element is
+/// `offset + sum_j i_j β_j`, where `i_j` is the `j`-th bit of `i`.
+///
+/// The subspace polynomial `W_i(X) = prod_{v in V_i} (X - v)` is `GF(2)`-linear,
+/// so the vanishing polynomial of the domain is `W_l(X) - W_l(offset)`.
+///
+/// The domain has no multiplicative generator or coset offset, so
+/// [`EvaluationDomain::group_gen`], [`EvaluationDomain::group_gen_inv`],
+/// [`EvaluationDomain::coset_offset_inv`] and
+/// [`EvaluationDomain::coset_offset_pow_size`] panic, as does
+/// [`EvaluationDomain::size_inv`] for domains larger than one. The domain
+/// overrides every provided method of the trait that would call them.
+///
+/// The coefficients of `W_l` are stored in the domain, and the other subspace
+/// polynomials, which only depend on its size, are recomputed for each
+/// transform in `O(l^2)` operations.
+#[derive(Copy, Clone)]
+pub struct AdditiveEvaluationDomain<F: FftField> {
+    /// The size of the domain.
+    pub size: u64,
+    /// `log_2(self.size)`, i.e. the dimension of the subspace.
+    pub log_size_of_group: u32,
+    /// Offset that specifies the coset.
+    pub offset: F,
+    /// The coefficients of `W_l` in `X, X^2, ..., X^{2^l}`, followed by
+    /// zeros.
+    vanishing_coeffs: [F; MAX_VANISHING_COEFFS],
+}
+
+/// The maximal number of coefficients of `W_l`, since the size `2^l` of a
+/// domain fits in a `u64`.
+const MAX_VANISHING_COEFFS: usize = 64;
+
+impl<F: FftField> hash::Hash for AdditiveEvaluationDomain<F> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        self.log_size_of_group.hash(state);
+        self.offset.hash(state);
+    }
+}
+
+impl<F: FftField> PartialEq for AdditiveEvaluationDomain<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.log_size_of_group == other.log_size_of_group
+            && self.offset == other.offset
+    }
+}
+
+impl<F: FftField> Eq for AdditiveEvaluationDomain<F> {}
+
+/// Only the size and the offset are serialized, and the subspace polynomials
+/// are recomputed on deserialization.
+impl<F: FftField> CanonicalSerialize for AdditiveEvaluationDomain<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.size.serialize_with_mode(&mut writer, compress)?;
+        self.log_size_of_group
+            .serialize_with_mode(&mut writer, compress)?;
+        self.offset.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.size.serialized_size(compress)
+            + self.log_size_of_group.serialized_size(compress)
+            + self.offset.serialized_size(compress)
+    }
+}
+
+impl<F: FftField> Valid for AdditiveEvaluationDomain<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if 1u64.checked_shl(self.log_size_of_group) != Some(self.size) {
+            return Err(SerializationError::InvalidData);
+        }
+        self.offset.check()
+    }
+}
+
+impl<F: FftField> CanonicalDeserialize for AdditiveEvaluationDomain<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let size = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+        let log_size_of_group = u32::deserialize_with_mode(&mut reader, compress, validate)?;
+        let offset = F::deserialize_with_mode(&mut reader, compress, validate)?;
+        // The subspace polynomials are only defined for domains that fit in
+        // the field, whatever the validation mode.
+        let domain = usize::try_from(size)
+            .ok()
+            .and_then(Self::new)
+            .filter(|d| d.size == size && d.log_size_of_group == log_size_of_group)
+            .ok_or(SerializationError::InvalidData)?;
+        Ok(domain.with_offset(offset))
+    }
+}
+
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: FftField] AdditiveEvaluationDomain<F>);
+
+impl<F: FftField> fmt::Debug for AdditiveEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Additive subspace of size {}", self.size)
+    }
+}
+
+/// Returns `β_j`, the `j`-th element of the canonical basis of `F` over
+/// `GF(2)`.
+fn basis_element<F: Field>(j: usize) -> F {
+    let degree = F::extension_degree() as usize;
+    F::from_base_prime_field_elems((0..degree).map(|i| F::BasePrimeField::from(i == j))).unwrap()
+}
+
+/// Evaluates at `x` the linearized polynomial `sum_j coeffs[j] X^{2^j}`.
+fn evaluate_linearized<F: Field>(coeffs: &[F], mut x: F) -> F {
+    let mut result = F::zero();
+    for c in coeffs {
+        result += *c * x;
+        x.square_in_place();
+    }
+    result
+}
+
+/// The subspace polynomials `W_0, ..., W_l` of a domain of size `2^l`.
+#[derive(Debug)]
+struct SubspacePolynomials<F: Field> {
+    /// The coefficients of `W_i` in `X, X^2, X^4, ...`.
+    coeffs: Vec<Vec<F>>,
+    /// `W_i(β_i)^{-1}` for `i < l`, so that `W_i(X) / W_i(β_i)` is one at
+    /// `β_i`.
+    norm_invs: Vec<F>,
+}
+
+impl<F: Field> SubspacePolynomials<F> {
+    /// Computes `W_0, ..., W_l` with `W_0(X) = X` and
+    /// `W_{i+1}(X) = W_i(X)^2 + W_i(β_i) W_i(X)`.
+    fn new(log_size: u32) -> Self {
+        let mut coeffs = Vec::with_capacity(log_size as usize + 1);
+        let mut norms = Vec::with_capacity(log_size as usize);
+        let mut w = vec![F::one()];
+        for i in 0..log_size as usize {
+            let norm = evaluate_linearized(&w, basis_element(i));
+            let mut next = Vec::with_capacity(i + 2);
+            next.push(norm * w[0]);
+            for j in 1..=i {
+                next.push(w[j - 1].square() + norm * w[j]);
+            }
+            next.push(w[i].square());
+            norms.push(norm);
+            coeffs.push(ark_std::mem::replace(&mut w, next));
+        }
+        coeffs.push(w);
+        batch_inversion(&mut norms);
+        Self {
+            coeffs,
+            norm_invs: norms,
+        }
+    }
+
+    /// Returns the coefficients of the normalized subspace polynomial
+    /// `W_i(X) / W_i(β_i)`.
+    fn normalized_coeffs(&self, i: usize) -> Vec<F> {
+        self.coeffs[i]
+            .iter()
+            .map(|c| *c * self.norm_invs[i])
+            .collect()
+    }
+
+    /// Evaluates the normalized subspace polynomial `W_i(X) / W_i(β_i)` at
+    /// `x`.
+    fn evaluate_normalized(&self, i: usize, x: F) -> F {
+        evaluate_linearized(&self.coeffs[i], x) * self.norm_invs[i]
+    }
+}
+
+impl<F: FftField> AdditiveEvaluationDomain<F> {
+    /// Returns the subspace `V_l` of size `2^l`.
+    fn from_log_size(log_size_of_group: u32) -> Self {
+        let w = SubspacePolynomials::<F>::new(log_size_of_group)
+            .coeffs
+            .pop()
+            .unwrap();
+        let mut vanishing_coeffs = [F::ZERO; MAX_VANISHING_COEFFS];
+        vanishing_coeffs[..w.len()].copy_from_slice(&w);
+        Self {
+            size: 1 << log_size_of_group,
+            log_size_of_group,
+            offset: F::ZERO,
+            vanishing_coeffs,
+        }
+    }
+
+    /// Returns the coset `offset + V_l` of the subspace of `self`.
+    const fn with_offset(&self, offset: F) -> Self {
+        Self { offset, ..*self }
+    }
+
+    /// Returns the subspace polynomials `W_0, ..., W_l`.
+    fn subspace_polynomials(&self) -> SubspacePolynomials<F> {
+        SubspacePolynomials::new(self.log_size_of_group)
+    }
+
+    /// Returns the coefficients of `W_l` in `X, X^2, ..., X^{2^l}`.
+    fn vanishing_coeffs(&self) -> &[F] {
+        &self.vanishing_coeffs[..=self.log_size_of_group as usize]
+    }
+
+    /// Returns the twiddles of the `i`-th layer of the transform, namely the
+    /// values of `W_i(X) / W_i(β_i)` at `offset + sum_j u_j β_{i+1+j}` for
+    /// every block `u` of `2^{i+1}` consecutive elements.
+    fn twiddles(&self, polys: &SubspacePolynomials<F>, i: usize) -> Vec<F> {
+        let mut twiddles = Vec::with_capacity(1 << (self.log_size_of_group as usize - i - 1));
+        twiddles.push(polys.evaluate_normalized(i, self.offset));
+        for j in i + 1..self.log_size_of_group as usize {
+            let shift = polys.evaluate_normalized(i, basis_element(j));
+            twiddles.extend_from_within(..);
+            let half = twiddles.len() / 2;
+            twiddles[half..].iter_mut().for_each(|t| *t += shift);
+        }
+        twiddles
+    }
+
+    /// Evaluates over the domain the polynomial `sum_j coeffs[j] X_j`, where
+    /// `X_j` is the product of the normalized subspace polynomials
+    /// `W_i(X) / W_i(β_i)` over the bits `i` of `j`, i.e. the novel
+    /// polynomial basis of LCH14.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `coeffs` differs from the size of the domain.
+    pub fn novel_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        assert_eq!(coeffs.len(), self.size());
+        self.novel_fft_with(coeffs);
+    }
+
+    /// Inverse of [`Self::novel_fft_in_place`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `evals` differs from the size of the domain.
+    pub fn novel_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        assert_eq!(evals.len(), self.size());
+        self.novel_ifft_with(evals);
+    }
+
+    /// Splits each block `D = D_0 + W_i(X) / W_i(β_i) D_1` into the values
+    /// `D_0 + t D_1` and `D_0 + (t + 1) D_1` it takes on the two halves of the
+    /// block, where `t` is the value of the normalized `W_i` on the first half.
+    fn novel_fft_with<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        let polys = self.subspace_polynomials();
+        for i in (0..self.log_size_of_group as usize).rev() {
+            let half = 1 << i;
+            let twiddles = self.twiddles(&polys, i);
+            ark_std::cfg_chunks_mut!(coeffs, 2 * half)
+                .zip(twiddles)
+                .for_each(|(block, t)| {
+                    let (lo, hi) = block.split_at_mut(half);
+                    for (a, b) in lo.iter_mut().zip(hi) {
+                        let mut tb = *b;
+                        tb *= t;
+                        *a += tb;
+                        *b += *a;
+                    }
+                });
+        }
+    }
+
+    fn novel_ifft_with<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        let polys = self.subspace_polynomials();
+        for i in 0..self.log_size_of_group as usize {
+            let half = 1 << i;
+            let twiddles = self.twiddles(&polys, i);
+            ark_std::cfg_chunks_mut!(evals, 2 * half)
+                .zip(twiddles)
+                .for_each(|(block, t)| {
+                    let (lo, hi) = block.split_at_mut(half);
+                    for (a, b) in lo.iter_mut().zip(hi) {
+                        *b -= *a;
+                        let mut tb = *b;
+                        tb *= t;
+                        *a -= tb;
+                    }
+                });
+        }
+    }
+
+    /// Converts `coeffs` from the monomial basis to the novel basis, by
+    /// dividing each block of size `2^{m+1}` by the normalized `W_m`, for `m`
+    /// from `l - 1` down to `0`, and storing the quotient in its upper half.
+    fn monomial_to_novel<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        let polys = self.subspace_polynomials();
+        for m in (0..polys.norm_invs.len()).rev() {
+            let half = 1 << m;
+            let w = polys.normalized_coeffs(m);
+            let (leading, lower) = w.split_last().unwrap();
+            let leading_inv = leading.inverse().unwrap();
+            ark_std::cfg_chunks_mut!(coeffs, 2 * half).for_each(|block| {
+                for d in (half..2 * half).rev() {
+                    block[d] *= leading_inv;
+                    let q = block[d];
+                    for (j, c) in lower.iter().enumerate() {
+                        let mut t = q;
+                        t *= *c;
+                        block[d - half + (1 << j)] -= t;
+                    }
+                }
+            });
+        }
+    }
+
+    /// Inverse of [`Self::monomial_to_novel`].
+    fn novel_to_monomial<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        let polys = self.subspace_polynomials();
+        for m in 0..polys.norm_invs.len() {
+            let half = 1 << m;
+            let w = polys.normalized_coeffs(m);
+            let (leading, lower) = w.split_last().unwrap();
+            ark_std::cfg_chunks_mut!(coeffs, 2 * half).for_each(|block| {
+                for d in half..2 * half {
+                    let q = block[d];
+                    for (j, c) in lower.iter().enumerate() {
+                        let mut t = q;
+                        t *= *c;
+                        block[d - half + (1 << j)] += t;
+                    }
+                    block[d] *= *leading;
+                }
+            });
+        }
+    }
+
+    /// Returns the coefficient of `X` in the vanishing polynomial, which is
+    /// also its derivative since the polynomial is linearized.
+    const fn vanishing_polynomial_derivative(&self) -> F {
+        self.vanishing_coeffs[0]
+    }
+}
+
+impl<F: FftField> EvaluationDomain<F> for AdditiveEvaluationDomain<F> {
+    type Elements = AdditiveElements<F>;
+
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    ///
+    /// Returns `None` if `F` does not have characteristic two, or if it has
+    /// fewer than `num_coeffs` elements.
+    fn new(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_domain(num_coeffs)?;
+        Some(Self::from_log_size(size.trailing_zeros()))
+    }
+
+    /// Construct the domain `offset + V_l`.
+    fn get_coset(&self, offset: F) -> Option<Self> {
+        Some(self.with_offset(offset))
+    }
+
+    fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        if F::characteristic() != [2] {
+            return None;
+        }
+        let size = num_coeffs.checked_next_power_of_two()?;
+        (u64::from(size.trailing_zeros()) <= F::extension_degree()).then_some(size)
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.size.try_into().unwrap()
+    }
+
+    #[inline]
+    fn log_size_of_group(&self) -> u64 {
+        self.log_size_of_group as u64
+    }
+
+    /// Return the inverse of the size of the domain in `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless the domain has size one, since larger domains have a
+    /// size of zero in `F`. The methods of the domain never divide by its
+    /// size.
+    fn size_inv(&self) -> F {
+        self.size_as_field_element()
+            .inverse()
+            .expect("the size of an additive domain larger than one is zero in the field")
+    }
+
+    /// # Panics
+    ///
+    /// Always panics, since the domain is an additive coset and has no
+    /// multiplicative generator. Its elements are instead enumerated by
+    /// [`Self::element`].
+    fn group_gen(&self) -> F {
+        panic!("an additive domain has no multiplicative generator")
+    }
+
+    /// # Panics
+    ///
+    /// Always panics, see [`Self::group_gen`].
+    fn group_gen_inv(&self) -> F {
+        panic!("an additive domain has no multiplicative generator")
+    }
+
+    #[inline]
+    fn coset_offset(&self) -> F {
+        self.offset
+    }
+
+    /// # Panics
+    ///
+    /// Always panics, since the domain is the additive shift
+    /// `self.coset_offset() + V` of a subspace `V`, and not a multiplicative
+    /// coset.
+    fn coset_offset_inv(&self) -> F {
+        panic!("an additive domain is not a multiplicative coset")
+    }
+
+    /// # Panics
+    ///
+    /// Always panics, see [`Self::coset_offset_inv`]. The vanishing
+    /// polynomial is instead given by [`Self::vanishing_polynomial`].
+    fn coset_offset_pow_size(&self) -> F {
+        panic!("an additive domain is not a multiplicative coset")
+    }
+
+    /// Compute a FFT, modifying the vector in place.
+    ///
+    /// Coefficients beyond the size of the domain are first reduced modulo
+    /// the vanishing polynomial.
+    fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        if coeffs.len() > self.size() {
+            reduce_by_monic_sparse(coeffs, &self.vanishing_polynomial());
+        }
+        coeffs.resize(self.size(), T::zero());
+        self.fft_slice_in_place(coeffs);
+    }
+
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.size(), T::zero());
+        self.ifft_slice_in_place(evals);
+    }
+
+    fn fft_slice_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        assert_eq!(
+            coeffs.len(),
+            self.size(),
+            "the slice must have the domain size"
+        );
+        self.monomial_to_novel(coeffs);
+        self.novel_fft_with(coeffs);
+    }
+
+    fn ifft_slice_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        assert_eq!(
+            evals.len(),
+            self.size(),
+            "the slice must have the domain size"
+        );
+        self.novel_ifft_with(evals);
+        self.novel_to_monomial(evals);
+    }
+
+    /// Evaluate all the lagrange polynomials defined by this domain at the
+    /// point `tau`, using that the derivative of the vanishing polynomial
+    /// `Z` is a constant `c`, so that `L_i(tau) = Z(tau) / (c (tau - x_i))`.
+    fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        let z_at_tau = self.evaluate_vanishing_polynomial(tau);
+        if z_at_tau.is_zero() {
+            // `tau` is in the domain, and the only nonzero coefficient is the
+            // one of its index.
+            let mut u = vec![F::zero(); self.size()];
+            if let Some(i) = self.elements().position(|x| x == tau) {
+                u[i] = F::one();
+            }
+            u
+        } else {
+            let c_over_z = self.vanishing_polynomial_derivative() / z_at_tau;
+            let mut lagrange_coefficients_inverse: Vec<F> =
+                self.elements().map(|x| c_over_z * (tau - x)).collect();
+            batch_inversion(&mut lagrange_coefficients_inverse);
+            lagrange_coefficients_inverse
+        }
+    }
+
+    /// Return the sparse vanishing polynomial `W_l(X) - W_l(offset)`.
+    fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        let w = self.vanishing_coeffs();
+        let constant_coeff = evaluate_linearized(w, self.offset);
+        let coeffs = ark_std::iter::once((0, -constant_coeff))
+            .chain(w.iter().enumerate().map(|(j, c)| (1 << j, *c)))
+            .filter(|(_, c)| !c.is_zero())
+            .collect();
+        SparsePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    /// This evaluates the vanishing polynomial for this domain at tau, as
+    /// `W_l(tau - offset)` by linearity.
+    fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        evaluate_linearized(self.vanishing_coeffs(), tau - self.offset)
+    }
+
+    /// Reduce `coeffs` modulo the vanishing polynomial `W_l(X) - W_l(offset)`.
+    fn reduce_mod_vanishing<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        if coeffs.len() > self.size() {
+            reduce_by_monic_sparse(coeffs, &self.vanishing_polynomial());
+        }
+    }
+
+    /// Return the filter polynomial of `self` with respect to the subdomain
+    /// `subdomain`, i.e. the quotient of their vanishing polynomials,
+    /// normalized to be one on `subdomain`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `subdomain` is not contained within `self`.
+    fn filter_polynomial(&self, subdomain: &Self) -> DensePolynomial<F> {
+        let self_vanishing_poly = DenseOrSparsePolynomial::from(
+            &self.vanishing_polynomial() * subdomain.vanishing_polynomial_derivative(),
+        );
+        let subdomain_vanishing_poly = DenseOrSparsePolynomial::from(
+            &subdomain.vanishing_polynomial() * self.vanishing_polynomial_derivative(),
+        );
+        let (quotient, remainder) = self_vanishing_poly
+            .divide_with_q_and_r(&subdomain_vanishing_poly)
+            .unwrap();
+        assert!(remainder.is_zero());
+        quotient
+    }
+
+    /// This evaluates at `tau` the filter polynomial for `self` with respect
+    /// to the subdomain `subdomain`.
+    fn evaluate_filter_polynomial(&self, subdomain: &Self, tau: F) -> F {
+        let v_subdomain_of_tau = subdomain.evaluate_vanishing_polynomial(tau);
+        if v_subdomain_of_tau.is_zero() {
+            F::one()
+        } else {
+            subdomain.vanishing_polynomial_derivative() * self.evaluate_vanishing_polynomial(tau)
+                / (self.vanishing_polynomial_derivative() * v_subdomain_of_tau)
+        }
+    }
+
+    /// Returns `offset + sum_j i_j β_j`.
+    fn element(&self, i: usize) -> F {
+        (0..self.log_size_of_group as usize)
+            .filter(|j| (i >> j) & 1 == 1)
+            .fold(self.offset, |acc, j| acc + basis_element::<F>(j))
+    }
+
+    /// Return an iterator over the elements of the domain.
+    fn elements(&self) -> AdditiveElements<F> {
+        AdditiveElements {
+            cur_elem: self.offset,
+            cur_index: 0,
+            size: self.size,
+        }
+    }
+
+    /// The elements of a subdomain with the same offset are the first
+    /// elements of `self`, so indices are unchanged.
+    fn reindex_by_subdomain(&self, other: Self, index: usize) -> usize {
+        assert!(self.size() >= other.size());
+        assert_eq!(self.offset, other.offset);
+        index
+    }
+}
+
+/// An iterator over the elements of an [`AdditiveEvaluationDomain`].
+pub struct AdditiveElements<F: FftField> {
+    cur_elem: F,
+    cur_index: u64,
+    size: u64,
+}
+
+impl<F: FftField> Iterator for AdditiveElements<F> {
+    type Item = F;
+    fn next(&mut self) -> Option<F> {
+        if self.cur_index == self.size {
+            None
+        } else {
+            let cur_elem = self.cur_elem;
+            // Going from `i` to `i + 1` flips the trailing ones of `i` and the
+            // zero above them.
+            for j in 0..=self.cur_index.trailing_ones() as usize {
+                self.cur_elem += basis_element::<F>(j);
+            }
+            self.cur_index += 1;
+            Some(cur_elem)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        polynomial::{univariate::*, DenseUVPolynomial, Polynomial},
+        AdditiveEvaluationDomain, EvaluationDomain, Evaluations,
+    };
+    use ark_ff::{
+        binary::{BinaryField128b, BinaryField8b, BinaryPolyField64b},
+        FftField, One, Zero,
+    };
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::Rng, test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    fn check_fft<F: FftField>() {
+        let rng = &mut test_rng();
+        for log_size in 0..8 {
+            let domain = AdditiveEvaluationDomain::<F>::new(1 << log_size).unwrap();
+            for offset in [F::zero(), F::rand(rng)] {
+                let coset = domain.get_coset(offset).unwrap();
+                for degree in [0, (1 << log_size) - 1, (3 << log_size) + 1] {
+                    let poly = DensePolynomial::<F>::rand(degree, rng);
+                    let evals = coset.fft(&poly.coeffs);
+                    let naive: Vec<_> = coset.elements().map(|x| poly.evaluate(&x)).collect();
+                    assert_eq!(evals, naive, "log_size {log_size}, degree {degree}");
+                    if degree < coset.size() {
+                        let mut coeffs = coset.ifft(&evals);
+                        coeffs.truncate(poly.coeffs.len());
+                        assert_eq!(coeffs, poly.coeffs);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fft_correctness() {
+        check_fft::<BinaryField8b>();
+        check_fft::<BinaryField128b>();
+        check_fft::<BinaryPolyField64b>();
+    }
+
+    #[test]
+    fn novel_fft_roundtrip() {
+        let rng = &mut test_rng();
+        let domain = AdditiveEvaluationDomain::<BinaryField128b>::new(1 << 6)
+            .unwrap()
+            .get_coset(rng.gen())
+            .unwrap();
+        let coeffs: Vec<BinaryField128b> = (0..domain.size()).map(|_| rng.gen()).collect();
+        let mut evals = coeffs.clone();
+        domain.novel_fft_in_place(&mut evals);
+        let mut monomial = evals.clone();
+        domain.ifft_in_place(&mut monomial);
+        assert_eq!(domain.fft(&monomial), evals);
+        domain.novel_ifft_in_place(&mut evals);
+        assert_eq!(evals, coeffs);
+    }
+
+    #[test]
+    fn elements_and_domain_size() {
+        for log_size in 0..=8 {
+            let domain = AdditiveEvaluationDomain::<BinaryField8b>::new(1 << log_size).unwrap();
+            let elements: Vec<_> = domain.elements().collect();
+            assert_eq!(elements.len(), domain.size());
+            for (i, x) in elements.iter().enumerate() {
+                assert_eq!(*x, domain.element(i));
+                assert!(!elements[..i].contains(x));
+            }
+        }
+        assert!(AdditiveEvaluationDomain::<BinaryField8b>::new(257).is_none());
+        assert!(AdditiveEvaluationDomain::<Fr>::new(4).is_none());
+    }
+
+    #[test]
+    fn vanishing_polynomial_evaluation() {
+        let rng = &mut test_rng();
+        for log_size in 0..8 {
+            let domain = AdditiveEvaluationDomain::<BinaryField128b>::new(1 << log_size)
+                .unwrap()
+                .get_coset(rng.gen())
+                .unwrap();
+            let z = domain.vanishing_polynomial();
+            assert_eq!(z.degree(), domain.size());
+            for x in domain.elements() {
+                assert!(z.evaluate(&x).is_zero());
+                assert!(domain.evaluate_vanishing_polynomial(x).is_zero());
+            }
+            for _ in 0..10 {
+                let point: BinaryField128b = rng.gen();
+                assert_eq!(
+                    z.evaluate(&point),
+                    domain.evaluate_vanishing_polynomial(point)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lagrange_coefficients() {
+        let rng = &mut test_rng();
+        let domain = AdditiveEvaluationDomain::<BinaryField128b>::new(1 << 5)
+            .unwrap()
+            .get_coset(rng.gen())
+            .unwrap();
+        let poly = DensePolynomial::<BinaryField128b>::rand(domain.size() - 1, rng);
+        let evals = domain.fft(&poly.coeffs);
+        for tau in [rng.gen(), domain.element(7)] {
+            let lagrange = domain.evaluate_all_lagrange_coefficients(tau);
+            let value: BinaryField128b = lagrange.iter().zip(&evals).map(|(l, e)| *l * e).sum();
+            assert_eq!(value, poly.evaluate(&tau));
+        }
+    }
+
+    #[test]
+    fn filter_polynomial() {
+        let rng = &mut test_rng();
+        let offset: BinaryField128b = rng.gen();
+        let domain = AdditiveEvaluationDomain::new(1 << 5)
+            .unwrap()
+            .get_coset(offset)
+            .unwrap();
+        let subdomain = AdditiveEvaluationDomain::new(1 << 2)
+            .unwrap()
+            .get_coset(offset)
+            .unwrap();
+        let filter = domain.filter_polynomial(&subdomain);
+        for (i, x) in domain.elements().enumerate() {
+            let expected = if i < subdomain.size() {
+                BinaryField128b::one()
+            } else {
+                BinaryField128b::zero()
+            };
+            assert_eq!(filter.evaluate(&x), expected);
+            assert_eq!(domain.evaluate_filter_polynomial(&subdomain, x), expected);
+        }
+        let tau = rng.gen();
+        assert_eq!(
+            filter.evaluate(&tau),
+            domain.evaluate_filter_polynomial(&subdomain, tau)
+        );
+        assert_eq!(domain.reindex_by_subdomain(subdomain, 3), 3);
+    }
+
+    #[test]
+    fn evaluations_interop() {
+        let rng = &mut test_rng();
+        let domain = AdditiveEvaluationDomain::<BinaryField128b>::new(1 << 4)
+            .unwrap()
+            .get_coset(rng.gen())
+            .unwrap();
+        for degree in [5, 15, 40] {
+            let poly = DensePolynomial::rand(degree, rng);
+            let naive: Vec<_> = domain.elements().map(|x| poly.evaluate(&x)).collect();
+            let evals = poly.evaluate_over_domain_by_ref(domain);
+            assert_eq!(evals.evals, naive);
+            let sparse = SparsePolynomial::from(poly.clone());
+            assert_eq!(sparse.evaluate_over_domain(domain).evals, naive);
+            if degree < domain.size() {
+                assert_eq!(evals.interpolate(), poly);
+            }
+        }
+        let a = Evaluations::from_vec_and_domain(vec![BinaryField128b::one(); 16], domain);
+        let b = &a + &a;
+        assert!(b.evals.iter().all(Zero::is_zero));
+    }
+
+    #[test]
+    fn size_inv() {
+        let domain = AdditiveEvaluationDomain::<BinaryField8b>::new(1).unwrap();
+        assert_eq!(domain.size_inv(), BinaryField8b::one());
+    }
+
+    #[test]
+    #[should_panic(expected = "is zero in the field")]
+    fn size_inv_of_larger_domains() {
+        AdditiveEvaluationDomain::<BinaryField8b>::new(1 << 4)
+            .unwrap()
+            .size_inv();
+    }
+
+    #[test]
+    #[should_panic(expected = "no multiplicative generator")]
+    fn group_gen() {
+        AdditiveEvaluationDomain::<BinaryField8b>::new(1 << 4)
+            .unwrap()
+            .group_gen();
+    }
+
+    #[test]
+    #[should_panic(expected = "not a multiplicative coset")]
+    fn coset_offset_inv() {
+        AdditiveEvaluationDomain::<BinaryField8b>::new(1 << 4)
+            .unwrap()
+            .coset_offset_inv();
+    }
+
+    #[test]
+    #[should_panic(expected = "odd characteristic")]
+    fn fold_rejects_binary_fields() {
+        let domain = AdditiveEvaluationDomain::<BinaryField8b>::new(1 << 4).unwrap();
+        let evals = Evaluations::from_vec_and_domain(vec![BinaryField8b::one(); 16], domain);
+        evals.fold(BinaryField8b::one());
+    }
+
+    #[test]
+    fn serialization() {
+        let rng = &mut test_rng();
+        let domain = AdditiveEvaluationDomain::<BinaryField128b>::new(1 << 5)
+            .unwrap()
+            .get_coset(rng.gen())
+            .unwrap();
+        let mut bytes = Vec::new();
+        domain.serialize_compressed(&mut bytes).unwrap();
+        let deserialized =
+            AdditiveEvaluationDomain::<BinaryField128b>::deserialize_compressed(&bytes[..])
+                .unwrap();
+        assert_eq!(deserialized, domain);
+        let poly = DensePolynomial::<BinaryField128b>::rand(domain.size() - 1, rng);
+        assert_eq!(deserialized.fft(&poly.coeffs), domain.fft(&poly.coeffs));
+
+        // A domain larger than the field is rejected.
+        let mut bytes = Vec::new();
+        (1u64 << 9, 9u32, BinaryField8b::zero())
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        assert!(
+            AdditiveEvaluationDomain::<BinaryField8b>::deserialize_uncompressed_unchecked(
+                &bytes[..]
+            )
+            .is_err()
+        );
+    }
+}
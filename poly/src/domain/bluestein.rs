@@ -0,0 +1,346 @@
+//! This module provides a `BluesteinEvaluationDomain`, an `EvaluationDomain`
+//! over multiplicative subgroups of any size `n` dividing `p - 1`, whose
+//! FFTs are computed with Bluestein's chirp-z transform.
+//!
+//! The chirp-z transform evaluates a polynomial over any geometric
+//! progression `a, a * r, ..., a * r^(m - 1)` with a single convolution, which
+//! is computed with an FFT over a large enough [`GeneralEvaluationDomain`], or
+//! naively if the field has none.
+
+pub use crate::domain::utils::Elements;
+use crate::domain::{DomainCoeff, EvaluationDomain, GeneralEvaluationDomain};
+use ark_ff::{BigInteger, FftField, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{fmt, vec, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Defines a domain over which finite field (I)FFTs can be performed.
+///
+/// Works for any size `n` that divides `p - 1`, at the cost of a convolution
+/// of size `2n - 1` per FFT.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BluesteinEvaluationDomain<F: FftField> {
+    /// The size of the domain.
+    pub size: u64,
+    /// `ceil(log_2(self.size))`, since `self.size` need not be a power of two.
+    pub log_size_of_group: u32,
+    /// Size of the domain as a field element.
+    pub size_as_field_element: F,
+    /// Inverse of the size in the field.
+    pub size_inv: F,
+    /// A generator of the subgroup.
+    pub group_gen: F,
+    /// Inverse of the generator of the subgroup.
+    pub group_gen_inv: F,
+    /// Offset that specifies the coset.
+    pub offset: F,
+    /// Inverse of the offset that specifies the coset.
+    pub offset_inv: F,
+    /// Constant coefficient for the vanishing polynomial.
+    /// Equals `self.offset^self.size`.
+    pub offset_pow_size: F,
+}
+
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: FftField] BluesteinEvaluationDomain<F>);
+
+impl<F: FftField> fmt::Debug for BluesteinEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bluestein multiplicative subgroup of size {}", self.size)
+    }
+}
+
+impl<F: PrimeField> EvaluationDomain<F> for BluesteinEvaluationDomain<F> {
+    type Elements = Elements<F>;
+
+    /// Construct the smallest domain that is large enough for evaluations of
+    /// a polynomial having `num_coeffs` coefficients.
+    fn new(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_domain(num_coeffs)? as u64;
+        let group_gen = root_of_unity::<F>(size)?;
+        debug_assert_eq!(group_gen.pow([size]), F::one());
+        let size_as_field_element = F::from(size);
+
+        Some(Self {
+            size,
+            log_size_of_group: ark_std::log2(size as usize),
+            size_as_field_element,
+            size_inv: size_as_field_element.inverse()?,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            offset: F::one(),
+            offset_inv: F::one(),
+            offset_pow_size: F::one(),
+        })
+    }
+
+    fn get_coset(&self, offset: F) -> Option<Self> {
+        Some(Self {
+            offset,
+            offset_inv: offset.inverse()?,
+            offset_pow_size: offset.pow([self.size]),
+            ..*self
+        })
+    }
+
+    /// Returns the smallest divisor of `p - 1` that is at least `num_coeffs`,
+    /// if there is one below the next power of two.
+    fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        let num_coeffs = num_coeffs.max(1);
+        (num_coeffs..=num_coeffs.checked_next_power_of_two()?)
+            .find(|size| divide_modulus_minus_one::<F>(*size as u64).is_some())
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.size.try_into().unwrap()
+    }
+
+    #[inline]
+    fn log_size_of_group(&self) -> u64 {
+        self.log_size_of_group as u64
+    }
+
+    #[inline]
+    fn size_inv(&self) -> F {
+        self.size_inv
+    }
+
+    #[inline]
+    fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    #[inline]
+    fn group_gen_inv(&self) -> F {
+        self.group_gen_inv
+    }
+
+    #[inline]
+    fn coset_offset(&self) -> F {
+        self.offset
+    }
+
+    #[inline]
+    fn coset_offset_inv(&self) -> F {
+        self.offset_inv
+    }
+
+    #[inline]
+    fn coset_offset_pow_size(&self) -> F {
+        self.offset_pow_size
+    }
+
+    /// Evaluates the polynomial with coefficients `coeffs` over the domain,
+    /// even if it has more coefficients than the size of the domain.
+    #[inline]
+    fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        *coeffs = chirp_z_transform(coeffs, self.offset, self.group_gen, self.size());
+    }
+
+    #[inline]
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.size(), T::zero());
+        *evals = chirp_z_transform(evals, F::one(), self.group_gen_inv, self.size());
+        Self::distribute_powers_and_mul_by_const(evals, self.offset_inv, self.size_inv);
+    }
+
+    /// Return an iterator over the elements of the domain.
+    fn elements(&self) -> Elements<F> {
+        Elements {
+            cur_elem: self.offset,
+            cur_pow: 0,
+            size: self.size,
+            group_gen: self.group_gen,
+        }
+    }
+}
+
+/// Returns `(p - 1) / divisor` if `divisor` divides `p - 1`.
+fn divide_modulus_minus_one<F: PrimeField>(divisor: u64) -> Option<F::BigInt> {
+    if divisor == 0 {
+        return None;
+    }
+    let mut quotient = F::MODULUS;
+    quotient.sub_with_borrow(&F::BigInt::from(1u64));
+    let mut remainder = 0u128;
+    for limb in quotient.as_mut().iter_mut().rev() {
+        let current = (remainder << 64) | u128::from(*limb);
+        *limb = (current / u128::from(divisor)) as u64;
+        remainder = current % u128::from(divisor);
+    }
+    (remainder == 0).then_some(quotient)
+}
+
+/// Returns a primitive `size`-th root of unity, if `size` divides `p - 1`.
+fn root_of_unity<F: PrimeField>(size: u64) -> Option<F> {
+    F::get_root_of_unity(size)
+        .or_else(|| divide_modulus_minus_one::<F>(size).map(|exp| F::GENERATOR.pow(exp)))
+}
+
+/// Returns `r^(k * (k - 1) / 2)` for `k` in `0..len`.
+fn chirp<F: FftField>(r: F, len: usize) -> Vec<F> {
+    let mut chirp = Vec::with_capacity(len);
+    let (mut current, mut r_pow) = (F::one(), F::one());
+    for _ in 0..len {
+        chirp.push(current);
+        current *= r_pow;
+        r_pow *= r;
+    }
+    chirp
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` over the geometric
+/// progression `start * ratio^j` for `j` in `0..num_points`, with Bluestein's
+/// chirp-z transform.
+///
+/// Since `i * j = C(i + j, 2) - C(i, 2) - C(j, 2)`, the evaluation at
+/// `start * ratio^j` is `ratio^(-C(j, 2)) * sum_i u_i * ratio^C(i + j, 2)`,
+/// with `u_i = coeffs[i] * start^i * ratio^(-C(i, 2))`, so that all the
+/// evaluations are given by a single convolution.
+pub fn chirp_z_transform<F: FftField, T: DomainCoeff<F>>(
+    coeffs: &[T],
+    start: F,
+    ratio: F,
+    num_points: usize,
+) -> Vec<T> {
+    let n = coeffs.len();
+    if n == 0 || num_points == 0 {
+        return vec![T::zero(); num_points];
+    }
+    let Some(ratio_inv) = ratio.inverse() else {
+        // All the points but the first are zero.
+        let mut evals = vec![coeffs[0]; num_points];
+        evals[0] = coeffs.iter().rev().fold(T::zero(), |mut acc, c| {
+            acc *= start;
+            acc + *c
+        });
+        return evals;
+    };
+
+    let chirp_inv = chirp(ratio_inv, n.max(num_points));
+    let chirp = chirp(ratio, n + num_points - 1);
+    // `u` in reverse order, so that the evaluations are the coefficients
+    // `n - 1..n + num_points - 1` of the convolution of `u` and `chirp`.
+    let mut u = coeffs.to_vec();
+    GeneralEvaluationDomain::distribute_powers(&mut u, start);
+    cfg_iter_mut!(u).zip(&chirp_inv).for_each(|(u, c)| *u *= *c);
+    u.reverse();
+
+    let mut evals = match GeneralEvaluationDomain::<F>::new(n + num_points - 1) {
+        // The cyclic convolution of size at least `n + num_points - 1` agrees
+        // with the convolution on the coefficients that are needed.
+        Some(domain) => {
+            domain.fft_in_place(&mut u);
+            let chirp = domain.fft(&chirp);
+            cfg_iter_mut!(u).zip(chirp).for_each(|(u, c)| *u *= c);
+            domain.ifft_in_place(&mut u);
+            u.drain(..n - 1);
+            u.truncate(num_points);
+            u
+        },
+        None => (0..num_points)
+            .map(|j| {
+                u.iter()
+                    .rev()
+                    .zip(&chirp[j..])
+                    .fold(T::zero(), |acc, (u, c)| {
+                        let mut term = *u;
+                        term *= *c;
+                        acc + term
+                    })
+            })
+            .collect(),
+    };
+    cfg_iter_mut!(evals)
+        .zip(chirp_inv)
+        .for_each(|(e, c)| *e *= c);
+    evals
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::{bluestein::chirp_z_transform, BluesteinEvaluationDomain},
+        univariate::DensePolynomial,
+        DenseUVPolynomial, EvaluationDomain, Polynomial,
+    };
+    use ark_ff::{FftField, Field, One, UniformRand, Zero};
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::{bls12_381::Fr, bn384_small_two_adicity::Fr as BNFr};
+
+    #[test]
+    fn sizes() {
+        // `r - 1 = 2^32 * 3 * 11 * 19 * 10177 * ...` for BLS12-381.
+        for (num_coeffs, size) in [(0, 1), (3, 3), (5, 6), (7, 8), (10, 11), (13, 16), (50, 57)] {
+            let domain = BluesteinEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            assert_eq!(domain.size(), size);
+            assert_eq!(domain.log_size_of_group(), ark_std::log2(size) as u64);
+            assert_eq!(domain.group_gen.pow([size as u64]), Fr::one());
+            assert!(domain.elements().skip(1).all(|x| !x.is_one()));
+        }
+    }
+
+    #[test]
+    fn fft_matches_evaluation() {
+        let rng = &mut test_rng();
+        for num_coeffs in [1, 3, 6, 11, 33, 57] {
+            let domain = BluesteinEvaluationDomain::<Fr>::new(num_coeffs)
+                .unwrap()
+                .get_coset(Fr::GENERATOR)
+                .unwrap();
+            assert_eq!(domain.size(), num_coeffs);
+            let poly = DensePolynomial::<Fr>::rand(num_coeffs - 1, rng);
+            let evals = domain.fft(&poly.coeffs);
+            let expected: Vec<Fr> = domain.elements().map(|x| poly.evaluate(&x)).collect();
+            assert_eq!(evals, expected);
+            assert_eq!(domain.ifft(&evals), poly.coeffs);
+        }
+
+        // `17` divides `r - 1`, but is neither a power of two nor of three.
+        let domain = BluesteinEvaluationDomain::<BNFr>::new(17).unwrap();
+        assert_eq!(domain.size(), 17);
+        let poly = DensePolynomial::<BNFr>::rand(16, rng);
+        let evals = domain.fft(&poly.coeffs);
+        let expected: Vec<BNFr> = domain.elements().map(|x| poly.evaluate(&x)).collect();
+        assert_eq!(evals, expected);
+        assert_eq!(domain.ifft(&evals), poly.coeffs);
+    }
+
+    #[test]
+    fn geometric_progressions() {
+        let rng = &mut test_rng();
+        let poly = DensePolynomial::<Fr>::rand(20, rng);
+        let start = Fr::rand(rng);
+        for ratio in [Fr::rand(rng), Fr::one(), Fr::zero()] {
+            for num_points in [0, 1, 5, 21, 40] {
+                let evals = chirp_z_transform(&poly.coeffs, start, ratio, num_points);
+                let mut point = start;
+                let expected: Vec<Fr> = (0..num_points)
+                    .map(|_| {
+                        let eval = poly.evaluate(&point);
+                        point *= ratio;
+                        eval
+                    })
+                    .collect();
+                assert_eq!(evals, expected);
+            }
+        }
+        assert_eq!(
+            chirp_z_transform::<Fr, Fr>(&[], start, start, 3),
+            [Fr::zero(); 3]
+        );
+
+        // The convolution is larger than the largest domain `2^12 * 3^2` of
+        // the field, so that it is computed naively.
+        let poly = DensePolynomial::<BNFr>::rand(1, rng);
+        let (start, ratio) = (BNFr::rand(rng), BNFr::rand(rng));
+        let evals = chirp_z_transform(&poly.coeffs, start, ratio, 1 << 16);
+        assert_eq!(evals[0], poly.evaluate(&start));
+        assert_eq!(
+            evals[(1 << 16) - 1],
+            poly.evaluate(&(start * ratio.pow([(1 << 16) - 1])))
+        );
+    }
+}
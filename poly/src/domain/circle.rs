@@ -0,0 +1,430 @@
+//! This module defines `CircleEvaluationDomain`, a domain of points of the
+//! circle curve `x^2 + y^2 = 1` over which the circle FFT of
+//! [HLP24](https://eprint.iacr.org/2024/278) can be performed.
+//!
+//! The points of the circle over `F_p` form a cyclic group of order `p + 1`
+//! if `p = 3 mod 4`, and `p - 1` otherwise. The circle FFT thus works over
+//! primes such as Mersenne-31, for which `p - 1` has almost no two-adicity
+//! but `p + 1 = 2^31`.
+
+use crate::domain::{utils::bitreverse, DomainCoeff};
+use ark_ff::{batch_inversion, BigInteger, BitIteratorBE, Field, LegendreSymbol, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    vec::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A point `(x, y)` of the circle curve `x^2 + y^2 = 1`.
+///
+/// The group law is written additively: `(x0, y0) + (x1, y1) = (x0 x1 - y0
+/// y1, x0 y1 + y0 x1)`, with identity `(1, 0)`, and the inverse of a point is
+/// its conjugate `(x, -y)`.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    CanonicalSerialize,
+    CanonicalDeserialize,
+)]
+pub struct CirclePoint<F: Field> {
+    /// The `x` coordinate.
+    pub x: F,
+    /// The `y` coordinate.
+    pub y: F,
+}
+
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: Field] CirclePoint<F>);
+
+impl<F: Field> CirclePoint<F> {
+    /// Construct the point `(x, y)`, without checking that it is on the
+    /// circle.
+    pub const fn new(x: F, y: F) -> Self {
+        Self { x, y }
+    }
+
+    /// The identity `(1, 0)`.
+    pub const fn identity() -> Self {
+        Self::new(F::ONE, F::ZERO)
+    }
+
+    /// Checks that `x^2 + y^2 = 1`.
+    pub fn is_on_circle(&self) -> bool {
+        self.x.square() + self.y.square() == F::ONE
+    }
+
+    /// Returns `(x, -y)`, the inverse of `self`.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.x, -self.y)
+    }
+
+    /// Returns `(-x, -y)`, i.e. `self + (-1, 0)`.
+    pub fn antipode(&self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+
+    /// Returns `2 * self`, which is `(2x^2 - 1, 2xy)`.
+    pub fn double(&self) -> Self {
+        Self::new(Self::double_x(self.x), (self.x * self.y).double())
+    }
+
+    /// Returns the `x` coordinate of `2 * (x, y)`, i.e. the squaring map
+    /// `π(x) = 2x^2 - 1`.
+    pub fn double_x(x: F) -> F {
+        x.square().double() - F::ONE
+    }
+
+    /// Returns `2^n * self`.
+    pub fn repeated_double(&self, n: u32) -> Self {
+        (0..n).fold(*self, |p, _| p.double())
+    }
+
+    /// Returns `scalar * self`, where `scalar` is given in little-endian
+    /// limbs.
+    pub fn mul_bigint(&self, scalar: impl AsRef<[u64]>) -> Self {
+        let mut res = Self::identity();
+        for bit in BitIteratorBE::without_leading_zeros(scalar) {
+            res = res.double();
+            if bit {
+                res += *self;
+            }
+        }
+        res
+    }
+}
+
+impl<F: PrimeField> CirclePoint<F> {
+    /// Returns a generator of the subgroup of order `2^log_size` of the
+    /// circle group, or `None` if there is no such subgroup.
+    ///
+    /// The generators of different orders are consistent, i.e. twice the
+    /// generator of order `2^n` is the generator of order `2^{n-1}`.
+    pub fn subgroup_gen(log_size: u32) -> Option<Self> {
+        let (gen, two_adicity) = Self::two_sylow_gen();
+        (log_size <= two_adicity).then(|| gen.repeated_double(two_adicity - log_size))
+    }
+
+    /// Returns the two-adicity `k` of the order of the circle group, and a
+    /// generator of its subgroup of order `2^k`.
+    fn two_sylow_gen() -> (Self, u32) {
+        // The order is `p + 1` if `-1` is a non-residue, and `p - 1` otherwise.
+        let mut order = F::MODULUS;
+        let minus_one_is_qnr = (-F::ONE).legendre() == LegendreSymbol::QuadraticNonResidue;
+        if minus_one_is_qnr {
+            assert!(!order.add_with_carry(&F::BigInt::from(1u64)));
+        } else {
+            order.sub_with_borrow(&F::BigInt::from(1u64));
+        }
+        let two_adicity = (0..).take_while(|i| !order.get_bit(*i)).count() as u32;
+        let odd_part = order >> two_adicity;
+        // The rational parametrization `t -> ((1 - t^2) / (1 + t^2), 2t / (1 +
+        // t^2))` covers every point but `(-1, 0)`, so some small `t` yields a
+        // point whose odd multiple has order exactly `2^k`.
+        let mut t = F::ONE;
+        loop {
+            let t2 = t.square();
+            if let Some(inv) = (F::ONE + t2).inverse() {
+                let point = Self::new((F::ONE - t2) * inv, t.double() * inv);
+                let gen = point.mul_bigint(odd_part);
+                if two_adicity == 0 || gen.repeated_double(two_adicity - 1) != Self::identity() {
+                    return (gen, two_adicity);
+                }
+            }
+            t += F::ONE;
+        }
+    }
+}
+
+impl<F: Field> Add for CirclePoint<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.x * other.x - self.y * other.y,
+            self.x * other.y + self.y * other.x,
+        )
+    }
+}
+
+impl<F: Field> AddAssign for CirclePoint<F> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<F: Field> Neg for CirclePoint<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.conjugate()
+    }
+}
+
+impl<F: Field> Sub for CirclePoint<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + other.conjugate()
+    }
+}
+
+impl<F: Field> SubAssign for CirclePoint<F> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// Defines a twin coset of the circle group over which circle (I)FFTs can be
+/// performed.
+///
+/// A twin coset of size `2^n` is `(Q + G_{n-1}) ∪ (-Q + G_{n-1})`, where
+/// `G_{n-1}` is the subgroup of order `2^{n-1}` and `2^n Q` is not the
+/// identity, so that the two halves are disjoint. Its `i`-th element is
+/// `Q + i g` for `i < 2^{n-1}`, where `g` generates `G_{n-1}`, followed by
+/// the conjugates of these points in the same order.
+///
+/// The circle polynomials of [`CirclePolynomial`](crate::circle::CirclePolynomial)
+/// of size `2^n` are interpolated from their evaluations over such a domain.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CircleEvaluationDomain<F: PrimeField> {
+    /// The size of the domain.
+    pub size: u64,
+    /// `log_2(self.size)`.
+    pub log_size: u32,
+    /// The point `Q` of the half coset `Q + G_{n-1}`.
+    pub initial: CirclePoint<F>,
+    /// A generator of `G_{n-1}`.
+    pub step: CirclePoint<F>,
+}
+
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: PrimeField] CircleEvaluationDomain<F>);
+
+impl<F: PrimeField> ark_std::fmt::Debug for CircleEvaluationDomain<F> {
+    fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
+        write!(f, "Circle twin coset of size {}", self.size)
+    }
+}
+
+impl<F: PrimeField> CircleEvaluationDomain<F> {
+    /// Construct the standard position coset of size `2^log_size`, i.e. the
+    /// coset `g_{n+1} + G_n`, where `g_{n+1}` generates `G_{n+1}`.
+    ///
+    /// This is the twin coset with `Q = g_{n+1}`, and is invariant under
+    /// conjugation. Returns `None` if `log_size` is zero, or if the circle
+    /// group has no subgroup of order `2^{log_size + 1}`.
+    pub fn new(log_size: u32) -> Option<Self> {
+        let initial = CirclePoint::subgroup_gen(log_size.checked_add(1)?)?;
+        Self::new_twin_coset(initial, log_size)
+    }
+
+    /// Construct the twin coset `(Q + G_{n-1}) ∪ (-Q + G_{n-1})` of size
+    /// `2^log_size`, where `Q = initial`.
+    ///
+    /// Returns `None` if `log_size` is zero or at least 64, if `initial` is
+    /// not on the circle, if the circle group has no subgroup of order
+    /// `2^{log_size - 1}`, or if the two halves of the coset intersect.
+    pub fn new_twin_coset(initial: CirclePoint<F>, log_size: u32) -> Option<Self> {
+        let size = 1u64.checked_shl(log_size)?;
+        if log_size == 0 || !initial.is_on_circle() {
+            return None;
+        }
+        if initial.repeated_double(log_size) == CirclePoint::identity() {
+            return None;
+        }
+        Some(Self {
+            size,
+            log_size,
+            initial,
+            step: CirclePoint::subgroup_gen(log_size - 1)?,
+        })
+    }
+
+    /// Return the size of `self`.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size.try_into().unwrap()
+    }
+
+    /// Return `log_2(self.size())`.
+    #[inline]
+    pub const fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Checks whether `self` is the standard position coset of its size.
+    pub fn is_standard_position(&self) -> bool {
+        self.initial.repeated_double(self.log_size + 1) == CirclePoint::identity()
+    }
+
+    /// Returns the `i`-th element of the domain.
+    pub fn element(&self, i: usize) -> CirclePoint<F> {
+        let half = self.size() / 2;
+        let point = self.initial + self.step.mul_bigint([(i % half) as u64]);
+        if i < half {
+            point
+        } else {
+            point.conjugate()
+        }
+    }
+
+    /// Return an iterator over the elements of the domain.
+    pub const fn elements(&self) -> CircleElements<F> {
+        CircleElements {
+            cur_elem: self.initial,
+            cur_index: 0,
+            size: self.size,
+            initial: self.initial,
+            step: self.step,
+        }
+    }
+
+    /// Evaluates at `point` the vanishing polynomial of the domain,
+    /// `π^{n-1}(x) - x(2^{n-1} Q)`, where `π(x) = 2x^2 - 1` is the `x`
+    /// coordinate of the doubling map.
+    pub fn evaluate_vanishing_polynomial(&self, point: &CirclePoint<F>) -> F {
+        let x = (1..self.log_size).fold(point.x, |x, _| CirclePoint::double_x(x));
+        x - self.initial.repeated_double(self.log_size - 1).x
+    }
+
+    /// Returns the twiddles of every layer of the FFT: the `y` coordinates of
+    /// the half coset, then, for each layer `k >= 1`, the values of
+    /// `π^{k-1}(x)` on its first `size / 2^{k+1}` points.
+    fn twiddles(&self) -> Vec<Vec<F>> {
+        let half = self.size() / 2;
+        let mut points = Vec::with_capacity(half);
+        let mut point = self.initial;
+        for _ in 0..half {
+            points.push(point);
+            point += self.step;
+        }
+        let mut twiddles = Vec::with_capacity(self.log_size as usize);
+        twiddles.push(points.iter().map(|p| p.y).collect::<Vec<_>>());
+        let mut xs: Vec<F> = points[..half / 2].iter().map(|p| p.x).collect();
+        while !xs.is_empty() {
+            let next = xs[..xs.len() / 2]
+                .iter()
+                .map(|x| CirclePoint::double_x(*x))
+                .collect();
+            twiddles.push(ark_std::mem::replace(&mut xs, next));
+        }
+        twiddles
+    }
+
+    /// Compute a circle FFT, i.e. evaluate over the domain the circle
+    /// polynomial with coefficients `coeffs`.
+    #[inline]
+    pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute a circle FFT, modifying the vector in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more coefficients than points in the domain.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        assert!(coeffs.len() <= self.size());
+        coeffs.resize(self.size(), T::zero());
+        derange(coeffs, self.log_size);
+        for (k, twiddles) in self.twiddles().iter().enumerate().rev() {
+            let half = self.size() >> (k + 1);
+            ark_std::cfg_chunks_mut!(coeffs, 2 * half).for_each(|block| {
+                let (lo, hi) = block.split_at_mut(half);
+                for ((a, b), t) in lo.iter_mut().zip(hi).zip(twiddles) {
+                    let mut tb = *b;
+                    tb *= *t;
+                    *b = *a - tb;
+                    *a += tb;
+                }
+            });
+        }
+    }
+
+    /// Compute a circle IFFT, i.e. interpolate the coefficients of the
+    /// circle polynomial with evaluations `evals` over the domain.
+    #[inline]
+    pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute a circle IFFT, modifying the vector in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of evaluations differs from the size of the
+    /// domain.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        assert_eq!(evals.len(), self.size());
+        for (k, mut twiddles) in self.twiddles().into_iter().enumerate() {
+            batch_inversion(&mut twiddles);
+            let half = self.size() >> (k + 1);
+            ark_std::cfg_chunks_mut!(evals, 2 * half).for_each(|block| {
+                let (lo, hi) = block.split_at_mut(half);
+                for ((a, b), t) in lo.iter_mut().zip(hi).zip(&twiddles) {
+                    let mut diff = *a - *b;
+                    diff *= *t;
+                    *a += *b;
+                    *b = diff;
+                }
+            });
+        }
+        derange(evals, self.log_size);
+        let size_inv = F::from(self.size).inverse().unwrap();
+        ark_std::cfg_iter_mut!(evals).for_each(|e| *e *= size_inv);
+    }
+}
+
+/// Applies the bit-reversal permutation to `xs`, which has length `2^log_len`.
+fn derange<T>(xs: &mut [T], log_len: u32) {
+    for idx in 1..(xs.len() as u64 - 1) {
+        let ridx = bitreverse(idx as u32, log_len);
+        if idx < ridx as u64 {
+            xs.swap(idx as usize, ridx as usize);
+        }
+    }
+}
+
+/// An iterator over the elements of a [`CircleEvaluationDomain`].
+pub struct CircleElements<F: PrimeField> {
+    cur_elem: CirclePoint<F>,
+    cur_index: u64,
+    size: u64,
+    initial: CirclePoint<F>,
+    step: CirclePoint<F>,
+}
+
+impl<F: PrimeField> Iterator for CircleElements<F> {
+    type Item = CirclePoint<F>;
+    fn next(&mut self) -> Option<CirclePoint<F>> {
+        if self.cur_index == self.size {
+            return None;
+        }
+        let half = self.size / 2;
+        let cur_elem = if self.cur_index < half {
+            self.cur_elem
+        } else {
+            self.cur_elem.conjugate()
+        };
+        self.cur_index += 1;
+        self.cur_elem = if self.cur_index == half {
+            self.initial
+        } else {
+            self.cur_elem + self.step
+        };
+        Some(cur_elem)
+    }
+}
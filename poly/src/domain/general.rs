@@ -117,6 +117,9 @@ impl<F: FftField> CanonicalDeserialize for GeneralEvaluationDomain<F> {
     }
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: FftField] GeneralEvaluationDomain<F>);
+
 impl<F: FftField> EvaluationDomain<F> for GeneralEvaluationDomain<F> {
     type Elements = GeneralElements<F>;
 
@@ -202,6 +205,36 @@ impl<F: FftField> EvaluationDomain<F> for GeneralEvaluationDomain<F> {
         map!(self, ifft_in_place, evals)
     }
 
+    #[inline]
+    fn fft_slice_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        map!(self, fft_slice_in_place, coeffs)
+    }
+
+    #[inline]
+    fn ifft_slice_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        map!(self, ifft_slice_in_place, evals)
+    }
+
+    #[inline]
+    fn fft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        map!(self, fft_batch, columns)
+    }
+
+    #[inline]
+    fn ifft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        map!(self, ifft_batch, columns)
+    }
+
+    #[inline]
+    fn fft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T]) {
+        map!(self, fft_batch_column_major, matrix)
+    }
+
+    #[inline]
+    fn ifft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T]) {
+        map!(self, ifft_batch_column_major, matrix)
+    }
+
     #[inline]
     fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
         map!(self, evaluate_all_lagrange_coefficients, tau)
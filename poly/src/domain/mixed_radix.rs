@@ -12,7 +12,10 @@
 
 pub use crate::domain::utils::Elements;
 use crate::domain::{
-    utils::{best_fft, bitreverse},
+    utils::{
+        best_fft, bitreverse, compute_powers_and_mul_by_const_serial, compute_powers_serial,
+        resized_columns,
+    },
     DomainCoeff, EvaluationDomain,
 };
 use ark_ff::{fields::utils::k_adicity, FftField};
@@ -48,6 +51,9 @@ pub struct MixedRadixEvaluationDomain<F: FftField> {
     pub offset_pow_size: F,
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: FftField] MixedRadixEvaluationDomain<F>);
+
 impl<F: FftField> fmt::Debug for MixedRadixEvaluationDomain<F> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -169,10 +175,25 @@ impl<F: FftField> EvaluationDomain<F> for MixedRadixEvaluationDomain<F> {
 
     #[inline]
     fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        coeffs.resize(self.size(), T::zero());
+        self.fft_slice_in_place(coeffs);
+    }
+
+    #[inline]
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.size(), T::zero());
+        self.ifft_slice_in_place(evals);
+    }
+
+    fn fft_slice_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        assert_eq!(
+            coeffs.len(),
+            self.size(),
+            "the slice must have the domain size"
+        );
         if !self.offset.is_one() {
             Self::distribute_powers(coeffs, self.offset);
         }
-        coeffs.resize(self.size(), T::zero());
         best_fft(
             coeffs,
             self.group_gen,
@@ -181,9 +202,12 @@ impl<F: FftField> EvaluationDomain<F> for MixedRadixEvaluationDomain<F> {
         )
     }
 
-    #[inline]
-    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        evals.resize(self.size(), T::zero());
+    fn ifft_slice_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        assert_eq!(
+            evals.len(),
+            self.size(),
+            "the slice must have the domain size"
+        );
         best_fft(
             evals,
             self.group_gen_inv,
@@ -197,6 +221,36 @@ impl<F: FftField> EvaluationDomain<F> for MixedRadixEvaluationDomain<F> {
         }
     }
 
+    fn fft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        let mut columns = resized_columns(columns, self.size());
+        self.fft_columns(&mut columns);
+    }
+
+    fn ifft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        let mut columns = resized_columns(columns, self.size());
+        self.ifft_columns(&mut columns);
+    }
+
+    fn fft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T]) {
+        assert_eq!(
+            matrix.len() % self.size(),
+            0,
+            "the columns must have the size of the domain"
+        );
+        let mut columns: Vec<&mut [T]> = matrix.chunks_mut(self.size()).collect();
+        self.fft_columns(&mut columns);
+    }
+
+    fn ifft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T]) {
+        assert_eq!(
+            matrix.len() % self.size(),
+            0,
+            "the columns must have the size of the domain"
+        );
+        let mut columns: Vec<&mut [T]> = matrix.chunks_mut(self.size()).collect();
+        self.ifft_columns(&mut columns);
+    }
+
     /// Return an iterator over the elements of the domain.
     fn elements(&self) -> Elements<F> {
         Elements {
@@ -208,6 +262,34 @@ impl<F: FftField> EvaluationDomain<F> for MixedRadixEvaluationDomain<F> {
     }
 }
 
+impl<F: FftField> MixedRadixEvaluationDomain<F> {
+    /// Computes the FFTs of `columns`, which have the size of the domain,
+    /// with the same roots of unity and coset offset powers for all of them.
+    fn fft_columns<T: DomainCoeff<F>>(&self, columns: &mut [&mut [T]]) {
+        let roots = compute_powers_serial(self.size(), self.group_gen);
+        let offset_powers =
+            (!self.offset.is_one()).then(|| compute_powers_serial(self.size(), self.offset));
+        ark_std::cfg_iter_mut!(columns).for_each(|column| {
+            if let Some(powers) = &offset_powers {
+                column.iter_mut().zip(powers).for_each(|(c, p)| *c *= *p);
+            }
+            serial_mixed_radix_fft_with_roots(column, &roots, self.log_size_of_group);
+        });
+    }
+
+    /// Computes the IFFTs of `columns`, which have the size of the domain,
+    /// with the same roots of unity and coset offset powers for all of them.
+    fn ifft_columns<T: DomainCoeff<F>>(&self, columns: &mut [&mut [T]]) {
+        let roots = compute_powers_serial(self.size(), self.group_gen_inv);
+        let scalars =
+            compute_powers_and_mul_by_const_serial(self.size(), self.offset_inv, self.size_inv);
+        ark_std::cfg_iter_mut!(columns).for_each(|column| {
+            serial_mixed_radix_fft_with_roots(column, &roots, self.log_size_of_group);
+            column.iter_mut().zip(&scalars).for_each(|(c, s)| *c *= *s);
+        });
+    }
+}
+
 fn mixed_radix_fft_permute(
     two_adicity: u32,
     q_adicity: u32,
@@ -267,6 +349,17 @@ pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
     a: &mut [T],
     omega: F,
     two_adicity: u32,
+) {
+    let roots = compute_powers_serial(a.len(), omega);
+    serial_mixed_radix_fft_with_roots(a, &roots, two_adicity);
+}
+
+/// Computes the FFT of `a` with `roots` the powers of a generator of the
+/// subgroup of size `a.len()`, so that they can be shared across FFTs.
+fn serial_mixed_radix_fft_with_roots<T: DomainCoeff<F>, F: FftField>(
+    a: &mut [T],
+    roots: &[F],
+    two_adicity: u32,
 ) {
     // Conceptually, this FFT first splits into 2 sub-arrays two_adicity many times,
     // and then splits into q sub-arrays q_adicity many times.
@@ -313,22 +406,17 @@ pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
             }
         }
 
-        let omega_q = omega.pow([(n / q) as u64]);
-        let mut qth_roots = Vec::with_capacity(q);
-        qth_roots.push(F::one());
-        for i in 1..q {
-            qth_roots.push(qth_roots[i - 1] * omega_q);
-        }
+        let qth_roots: Vec<F> = roots.iter().step_by(n / q).copied().collect();
 
         let mut terms = vec![T::zero(); q - 1];
 
         // Doing the q_adicity passes.
         for _ in 0..q_adicity {
-            let w_m = omega.pow([(n / (q * m)) as u64]);
+            let stride = n / (q * m);
             let mut k = 0;
             while k < n {
-                let mut w_j = F::one(); // w_j is omega_m ^ j
                 for j in 0..m {
+                    let w_j = roots[j * stride]; // w_j is omega_m ^ j
                     let base_term = a[k + j];
                     let mut w_j_i = w_j;
                     for i in 1..q {
@@ -345,8 +433,6 @@ pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
                             a[k + j + i * m] += tmp;
                         }
                     }
-
-                    w_j *= w_m;
                 }
 
                 k += q * m;
@@ -364,19 +450,17 @@ pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
     }
 
     for _ in 0..two_adicity {
-        // w_m is 2^s-th root of unity now
-        let w_m = omega.pow([(n / (2 * m)) as u64]);
+        // the twiddles are the powers of a 2^s-th root of unity now
+        let stride = n / (2 * m);
 
         let mut k = 0;
         while k < n {
-            let mut w = F::one();
             for j in 0..m {
                 let mut t = a[(k + m) + j];
-                t *= w;
+                t *= roots[j * stride];
                 a[(k + m) + j] = a[k + j];
                 a[(k + m) + j] -= t;
                 a[k + j] += t;
-                w *= w_m;
             }
             k += 2 * m;
         }
@@ -7,21 +7,29 @@
 //! These roots of unity comprise the domain over which
 //! polynomial arithmetic is performed.
 
-use ark_ff::{FftField, Zero};
+use ark_ff::{FftField, Field, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{fmt, hash, rand::Rng, vec::*};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+pub mod additive;
+pub mod bluestein;
+pub mod circle;
 pub mod general;
 pub mod mixed_radix;
 pub mod radix2;
 pub(crate) mod utils;
 
+pub use additive::AdditiveEvaluationDomain;
+pub use bluestein::{chirp_z_transform, BluesteinEvaluationDomain};
+pub use circle::{CircleEvaluationDomain, CirclePoint};
 pub use general::GeneralEvaluationDomain;
 pub use mixed_radix::MixedRadixEvaluationDomain;
-pub use radix2::Radix2EvaluationDomain;
+#[cfg(feature = "std")]
+pub use radix2::out_of_core::FileStorage;
+pub use radix2::{out_of_core::FftStorage, plan::FftPlan, Radix2EvaluationDomain};
 
 /// Defines a domain over which finite field (I)FFTs can be performed.
 ///
@@ -111,6 +119,132 @@ pub trait EvaluationDomain<F: FftField>:
     /// Compute a IFFT, modifying the vector in place.
     fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>);
 
+    /// Compute a FFT of `coeffs`, which has the size of the domain, modifying
+    /// the slice in place.
+    ///
+    /// The default implementation goes through a copy of `coeffs` for
+    /// [`Self::fft_in_place`], and domains whose FFTs work in place override
+    /// it.
+    fn fft_slice_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        assert_eq!(
+            coeffs.len(),
+            self.size(),
+            "the slice must have the domain size"
+        );
+        coeffs.copy_from_slice(&self.fft(coeffs));
+    }
+
+    /// Compute a IFFT of `evals`, which has the size of the domain, modifying
+    /// the slice in place, as [`Self::fft_slice_in_place`].
+    fn ifft_slice_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        assert_eq!(
+            evals.len(),
+            self.size(),
+            "the slice must have the domain size"
+        );
+        evals.copy_from_slice(&self.ifft(evals));
+    }
+
+    /// Compute the FFTs of several vectors, modifying them in place.
+    ///
+    /// The vectors are transformed in parallel, and implementations share
+    /// the twiddle factors across them.
+    fn fft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>])
+    where
+        Self: Sync,
+    {
+        cfg_iter_mut!(columns).for_each(|column| self.fft_in_place(column));
+    }
+
+    /// Compute the IFFTs of several vectors, modifying them in place.
+    ///
+    /// The vectors are transformed in parallel, and implementations share
+    /// the twiddle factors across them.
+    fn ifft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>])
+    where
+        Self: Sync,
+    {
+        cfg_iter_mut!(columns).for_each(|column| self.ifft_in_place(column));
+    }
+
+    /// Compute the FFTs of several vectors over the coset `offset * H` of the
+    /// subgroup `H` of `self`, modifying them in place.
+    fn coset_fft_batch<T: DomainCoeff<F>>(&self, offset: F, columns: &mut [Vec<T>])
+    where
+        Self: Sync,
+    {
+        let coset = self
+            .get_coset(offset)
+            .expect("coset offset is not invertible");
+        coset.fft_batch(columns);
+    }
+
+    /// Compute the IFFTs of several vectors over the coset `offset * H` of
+    /// the subgroup `H` of `self`, modifying them in place.
+    fn coset_ifft_batch<T: DomainCoeff<F>>(&self, offset: F, columns: &mut [Vec<T>])
+    where
+        Self: Sync,
+    {
+        let coset = self
+            .get_coset(offset)
+            .expect("coset offset is not invertible");
+        coset.ifft_batch(columns);
+    }
+
+    /// Compute the FFTs of the columns of a column-major matrix, whose
+    /// columns have the size of the domain, modifying them in place.
+    fn fft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T])
+    where
+        Self: Sync,
+    {
+        assert_eq!(
+            matrix.len() % self.size(),
+            0,
+            "the columns must have the size of the domain"
+        );
+        cfg_chunks_mut!(matrix, self.size()).for_each(|column| self.fft_slice_in_place(column));
+    }
+
+    /// Compute the IFFTs of the columns of a column-major matrix, whose
+    /// columns have the size of the domain, modifying them in place.
+    fn ifft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T])
+    where
+        Self: Sync,
+    {
+        assert_eq!(
+            matrix.len() % self.size(),
+            0,
+            "the columns must have the size of the domain"
+        );
+        cfg_chunks_mut!(matrix, self.size()).for_each(|column| self.ifft_slice_in_place(column));
+    }
+
+    /// Compute a FFT of coefficients in an extension `E` of the field, with
+    /// twiddle factors in the field.
+    ///
+    /// The coefficients are split into their `[E : F]` components, which are
+    /// transformed with [`Self::fft_batch`], so that the FFT only performs
+    /// multiplications in the field instead of in `E`.
+    fn fft_extension<E: Field<BasePrimeField = F>>(&self, coeffs: &[E]) -> Vec<E>
+    where
+        Self: Sync,
+    {
+        let mut components = utils::to_base_prime_field_components(coeffs);
+        self.fft_batch(&mut components);
+        utils::from_base_prime_field_components(&components)
+    }
+
+    /// Compute a IFFT of evaluations in an extension `E` of the field, with
+    /// twiddle factors in the field, as in [`Self::fft_extension`].
+    fn ifft_extension<E: Field<BasePrimeField = F>>(&self, evals: &[E]) -> Vec<E>
+    where
+        Self: Sync,
+    {
+        let mut components = utils::to_base_prime_field_components(evals);
+        self.ifft_batch(&mut components);
+        utils::from_base_prime_field_components(&components)
+    }
+
     /// Multiply the `i`-th element of `coeffs` with `g^i`.
     fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
         Self::distribute_powers_and_mul_by_const(coeffs, g, F::one());
@@ -235,6 +369,35 @@ pub trait EvaluationDomain<F: FftField>:
         tau.pow([self.size() as u64]) - self.coset_offset_pow_size()
     }
 
+    /// Reduce in place the coefficients `coeffs` of a polynomial modulo the
+    /// vanishing polynomial of this domain, which preserves its evaluations
+    /// over the domain. The result has at most `self.size()` coefficients.
+    fn reduce_mod_vanishing<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        let size = self.size();
+        if coeffs.len() <= size {
+            return;
+        }
+        let (reduced, rest) = coeffs.split_at_mut(size);
+        // `X^size = offset^size` modulo the vanishing polynomial.
+        let offset_pow_size = self.coset_offset_pow_size();
+        let mut offset_power = F::one();
+        for chunk in rest.chunks(size) {
+            offset_power *= offset_pow_size;
+            if offset_power.is_one() {
+                cfg_iter_mut!(reduced)
+                    .zip(chunk)
+                    .for_each(|(x, y)| *x += *y);
+            } else {
+                cfg_iter_mut!(reduced).zip(chunk).for_each(|(x, y)| {
+                    let mut t = *y;
+                    t *= offset_power;
+                    *x += t;
+                });
+            }
+        }
+        coeffs.truncate(size);
+    }
+
     /// Return the filter polynomial of `self` with respect to the subdomain `subdomain`.
     /// Assumes that `subdomain` is contained within `self`.
     ///
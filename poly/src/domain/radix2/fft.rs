@@ -3,7 +3,7 @@
 
 use crate::domain::{
     radix2::{fft, EvaluationDomain, Radix2EvaluationDomain},
-    utils::compute_powers_serial,
+    utils::{compute_powers_and_mul_by_const_serial, compute_powers_serial},
     DomainCoeff,
 };
 use ark_ff::FftField;
@@ -87,6 +87,59 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
         }
     }
 
+    /// Computes the in-order FFTs of `columns`, which have the size of the
+    /// domain, with the same roots of unity and coset offset powers for all
+    /// of them.
+    pub(super) fn fft_columns<T: DomainCoeff<F>>(&self, columns: &mut [&mut [T]]) {
+        let roots = self.roots_of_unity(self.group_gen);
+        let offset_powers =
+            (!self.offset.is_one()).then(|| compute_powers_serial(self.size(), self.offset));
+        Self::fft_columns_with_tables(columns, &roots, offset_powers.as_deref());
+    }
+
+    /// Computes the in-order IFFTs of `columns`, which have the size of the
+    /// domain, with the same roots of unity and coset offset powers for all
+    /// of them.
+    pub(super) fn ifft_columns<T: DomainCoeff<F>>(&self, columns: &mut [&mut [T]]) {
+        let roots = self.roots_of_unity(self.group_gen_inv);
+        let scalars =
+            compute_powers_and_mul_by_const_serial(self.size(), self.offset_inv, self.size_inv);
+        Self::ifft_columns_with_tables(columns, &roots, &scalars);
+    }
+
+    /// Computes the in-order FFTs of `columns` with `roots` the first half of
+    /// the powers of the generator, after multiplying them by
+    /// `offset_powers`, the powers of the coset offset, if any.
+    pub(super) fn fft_columns_with_tables<T: DomainCoeff<F>>(
+        columns: &mut [&mut [T]],
+        roots: &[F],
+        offset_powers: Option<&[F]>,
+    ) {
+        ark_std::cfg_iter_mut!(columns).for_each(|column| {
+            if let Some(powers) = offset_powers {
+                column.iter_mut().zip(powers).for_each(|(c, p)| *c *= *p);
+            }
+            derange(column, ark_std::log2(column.len()));
+            Self::oi_helper_with_roots(column, roots, 1);
+        });
+    }
+
+    /// Computes the in-order IFFTs of `columns` with `roots` the first half
+    /// of the powers of the inverse of the generator, and multiplies them by
+    /// `scalars`, the powers of the inverse of the coset offset divided by
+    /// the size of the domain.
+    pub(super) fn ifft_columns_with_tables<T: DomainCoeff<F>>(
+        columns: &mut [&mut [T]],
+        roots: &[F],
+        scalars: &[F],
+    ) {
+        ark_std::cfg_iter_mut!(columns).for_each(|column| {
+            derange(column, ark_std::log2(column.len()));
+            Self::oi_helper_with_roots(column, roots, 1);
+            column.iter_mut().zip(scalars).for_each(|(c, s)| *c *= *s);
+        });
+    }
+
     fn fft_helper_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], ord: FFTOrder) {
         let log_len = ark_std::log2(x_s.len());
 
@@ -296,7 +349,17 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
 
     fn oi_helper<T: DomainCoeff<F>>(&self, xi: &mut [T], root: F, start_gap: usize) {
         let roots_cache = self.roots_of_unity(root);
+        Self::oi_helper_with_roots(xi, &roots_cache, start_gap);
+    }
 
+    /// Runs the butterflies of [`Self::oi_helper`] with `roots_cache` the first
+    /// `xi.len() / 2` powers of a root of unity of order `xi.len()`, so that
+    /// they can be shared across several FFTs of the same size.
+    pub(super) fn oi_helper_with_roots<T: DomainCoeff<F>>(
+        xi: &mut [T],
+        roots_cache: &[F],
+        start_gap: usize,
+    ) {
         // The `cmp::min` is only necessary for the case where
         // `MIN_NUM_CHUNKS_FOR_COMPACTION = 1`. Else, notice that we compact
         // the roots cache by a stride of at least `MIN_NUM_CHUNKS_FOR_COMPACTION`.
@@ -330,7 +393,7 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
 
                 (&compacted_roots[..gap], 1)
             } else {
-                (&roots_cache[..], num_chunks)
+                (roots_cache, num_chunks)
             };
 
             Self::apply_butterfly(
@@ -370,7 +433,7 @@ const fn bitrev(a: u64, log_len: u32) -> u64 {
     a.reverse_bits().wrapping_shr(64 - log_len)
 }
 
-fn derange<T>(xi: &mut [T], log_len: u32) {
+pub(super) fn derange<T>(xi: &mut [T], log_len: u32) {
     for idx in 1..(xi.len() as u64 - 1) {
         let ridx = bitrev(idx, log_len);
         if idx < ridx {
@@ -5,12 +5,14 @@
 //! `Radix2EvaluationDomain` supports FFTs of size at most `2^F::TWO_ADICITY`.
 
 pub use crate::domain::utils::Elements;
-use crate::domain::{DomainCoeff, EvaluationDomain};
+use crate::domain::{utils::resized_columns, DomainCoeff, EvaluationDomain};
 use ark_ff::FftField;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{fmt, vec::*};
 
 mod fft;
+pub mod out_of_core;
+pub mod plan;
 
 /// Factor that determines if a the degree aware FFT should be called.
 const DEGREE_AWARE_FFT_THRESHOLD_FACTOR: usize = 1 << 2;
@@ -41,6 +43,9 @@ pub struct Radix2EvaluationDomain<F: FftField> {
     pub offset_pow_size: F,
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: FftField] Radix2EvaluationDomain<F>);
+
 impl<F: FftField> fmt::Debug for Radix2EvaluationDomain<F> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Radix-2 multiplicative subgroup of size {}", self.size)
@@ -152,6 +157,54 @@ impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
         self.in_order_ifft_in_place(&mut *evals);
     }
 
+    fn fft_slice_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        assert_eq!(
+            coeffs.len(),
+            self.size(),
+            "the slice must have the domain size"
+        );
+        self.in_order_fft_in_place(coeffs);
+    }
+
+    fn ifft_slice_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        assert_eq!(
+            evals.len(),
+            self.size(),
+            "the slice must have the domain size"
+        );
+        self.in_order_ifft_in_place(evals);
+    }
+
+    fn fft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        let mut columns = resized_columns(columns, self.size());
+        self.fft_columns(&mut columns);
+    }
+
+    fn ifft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        let mut columns = resized_columns(columns, self.size());
+        self.ifft_columns(&mut columns);
+    }
+
+    fn fft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T]) {
+        assert_eq!(
+            matrix.len() % self.size(),
+            0,
+            "the columns must have the size of the domain"
+        );
+        let mut columns: Vec<&mut [T]> = matrix.chunks_mut(self.size()).collect();
+        self.fft_columns(&mut columns);
+    }
+
+    fn ifft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T]) {
+        assert_eq!(
+            matrix.len() % self.size(),
+            0,
+            "the columns must have the size of the domain"
+        );
+        let mut columns: Vec<&mut [T]> = matrix.chunks_mut(self.size()).collect();
+        self.ifft_columns(&mut columns);
+    }
+
     /// Return an iterator over the elements of the domain.
     fn elements(&self) -> Elements<F> {
         Elements {
@@ -347,6 +400,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_small_field_fft() {
+        // Checks FFT/IFFT over 31- and 64-bit fields with dedicated backends.
+        fn check<F: FftField>() {
+            let rng = &mut test_rng();
+            for log_size in [1, 5, 10] {
+                let domain = Radix2EvaluationDomain::<F>::new(1 << log_size).unwrap();
+                let coset_domain = domain.get_coset(F::GENERATOR).unwrap();
+                let poly = DensePolynomial::<F>::rand(domain.size() - 1, rng);
+                let evals = domain.fft(&poly.coeffs);
+                let coset_evals = coset_domain.fft(&poly.coeffs);
+                for (i, (x, coset_x)) in domain.elements().zip(coset_domain.elements()).enumerate()
+                {
+                    assert_eq!(evals[i], poly.evaluate(&x));
+                    assert_eq!(coset_evals[i], poly.evaluate(&coset_x));
+                }
+                assert_eq!(domain.ifft(&evals), poly.coeffs);
+                assert_eq!(coset_domain.ifft(&coset_evals), poly.coeffs);
+            }
+        }
+        check::<ark_test_curves::small_fields::BabyBear>();
+        check::<ark_test_curves::small_fields::KoalaBear>();
+        check::<ark_test_curves::solinas::Goldilocks>();
+    }
+
     #[test]
     fn test_fft_correctness() {
         // Tests that the ffts output the correct result.
@@ -0,0 +1,465 @@
+//! Out-of-core FFTs over [`Radix2EvaluationDomain`]s, for vectors that do not
+//! fit in memory.
+//!
+//! The vector lives in an [`FftStorage`], which is only accessed by blocks of
+//! contiguous elements, and the FFT of size `n = n_1 * n_2` is computed with
+//! Bailey's decomposition, in three passes over the storage which each hold at
+//! most `memory_budget` elements in memory. This budget covers the tables of
+//! roots of unity of the column and row FFTs, and an input buffer, an output
+//! buffer and the serialization buffer of a block of the storage, which are
+//! counted as one element per element:
+//! 1. the vector is viewed as a matrix with `n_1` rows and `n_2` columns, and
+//!    the FFTs of size `n_1` of its columns are computed and multiplied by the
+//!    twiddle factors, by panels of consecutive columns;
+//! 2. the FFTs of size `n_2` of its rows are computed by panels of
+//!    consecutive rows, and written transposed to a scratch storage;
+//! 3. the scratch storage is copied back.
+//!
+//! Memory-mapped storage is not implemented: memory-mapping a file requires
+//! `unsafe` code, which this crate forbids. With the `std` feature,
+//! `FileStorage` instead seeks and reads or writes its file once per block.
+//! The panels of the first pass are not contiguous, so that pass accesses one
+//! block of `width` elements per row, i.e. `n_1` blocks per panel, while the
+//! other passes access whole contiguous panels. A memory-mapped file can
+//! still be used from a crate that allows `unsafe` code, through its slice,
+//! which is an [`FftStorage`].
+
+use crate::domain::{
+    radix2::{fft::derange, Radix2EvaluationDomain},
+    DomainCoeff, EvaluationDomain,
+};
+use ark_ff::FftField;
+use ark_std::{cfg_chunks_mut, io, vec, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A vector of elements accessed by blocks of contiguous elements, e.g. a
+/// file on disk.
+pub trait FftStorage<T> {
+    /// Returns the number of elements of the storage.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the storage has no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reads the elements `start..start + block.len()` into `block`.
+    fn read_block(&mut self, start: usize, block: &mut [T]) -> io::Result<()>;
+
+    /// Writes `block` to the elements `start..start + block.len()`.
+    fn write_block(&mut self, start: usize, block: &[T]) -> io::Result<()>;
+}
+
+impl<T: Copy> FftStorage<T> for [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn read_block(&mut self, start: usize, block: &mut [T]) -> io::Result<()> {
+        block.copy_from_slice(&self[start..start + block.len()]);
+        Ok(())
+    }
+
+    fn write_block(&mut self, start: usize, block: &[T]) -> io::Result<()> {
+        self[start..start + block.len()].copy_from_slice(block);
+        Ok(())
+    }
+}
+
+impl<T: Copy> FftStorage<T> for Vec<T> {
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn read_block(&mut self, start: usize, block: &mut [T]) -> io::Result<()> {
+        self.as_mut_slice().read_block(start, block)
+    }
+
+    fn write_block(&mut self, start: usize, block: &[T]) -> io::Result<()> {
+        self.as_mut_slice().write_block(start, block)
+    }
+}
+
+#[cfg(feature = "std")]
+pub use file::FileStorage;
+
+#[cfg(feature = "std")]
+mod file {
+    use super::FftStorage;
+    use ark_ff::Zero;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{
+        fs::File,
+        io::{self, Read, Seek, SeekFrom, Write},
+        marker::PhantomData,
+        vec,
+        vec::*,
+    };
+
+    /// An [`FftStorage`] backed by a file, in which the elements are stored
+    /// contiguously in their uncompressed serialization.
+    ///
+    /// This is not a memory-mapped file, which would require `unsafe` code
+    /// that this crate forbids: each access seeks to the block and reads or
+    /// writes its serialization through a temporary buffer of the size of the
+    /// block, so the first pass of the out-of-core FFTs issues one read and
+    /// one write per row of each panel.
+    #[derive(Debug)]
+    pub struct FileStorage<T> {
+        file: File,
+        len: usize,
+        element_size: usize,
+        _marker: PhantomData<T>,
+    }
+
+    impl<T: CanonicalSerialize + Zero> FileStorage<T> {
+        /// Uses `file` to store `len` elements, resizing it accordingly.
+        ///
+        /// Returns an error of kind [`io::ErrorKind::InvalidInput`] if the
+        /// size of the file in bytes overflows.
+        pub fn new(file: File, len: usize) -> io::Result<Self> {
+            let element_size = T::zero().uncompressed_size();
+            let file_size = len
+                .checked_mul(element_size)
+                .and_then(|size| u64::try_from(size).ok())
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "the file size overflows")
+                })?;
+            file.set_len(file_size)?;
+            Ok(Self {
+                file,
+                len,
+                element_size,
+                _marker: PhantomData,
+            })
+        }
+
+        /// Returns the underlying file.
+        pub fn into_inner(self) -> File {
+            self.file
+        }
+
+        fn seek(&mut self, start: usize) -> io::Result<()> {
+            let offset = (start * self.element_size) as u64;
+            self.file.seek(SeekFrom::Start(offset)).map(|_| ())
+        }
+    }
+
+    impl<T: CanonicalSerialize + CanonicalDeserialize + Zero> FftStorage<T> for FileStorage<T> {
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        fn read_block(&mut self, start: usize, block: &mut [T]) -> io::Result<()> {
+            assert!(start + block.len() <= self.len, "block out of bounds");
+            let mut bytes = vec![0u8; block.len() * self.element_size];
+            self.seek(start)?;
+            self.file.read_exact(&mut bytes)?;
+            for (elem, bytes) in block.iter_mut().zip(bytes.chunks(self.element_size)) {
+                *elem = T::deserialize_uncompressed_unchecked(bytes)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            }
+            Ok(())
+        }
+
+        fn write_block(&mut self, start: usize, block: &[T]) -> io::Result<()> {
+            assert!(start + block.len() <= self.len, "block out of bounds");
+            let mut bytes = Vec::with_capacity(block.len() * self.element_size);
+            for elem in block {
+                elem.serialize_uncompressed(&mut bytes)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            }
+            self.seek(start)?;
+            self.file.write_all(&bytes)
+        }
+    }
+}
+
+impl<F: FftField> Radix2EvaluationDomain<F> {
+    /// Computes the FFT of the `self.size()` elements of `storage` in place,
+    /// using `scratch`, of the same length, as temporary storage.
+    ///
+    /// At most `memory_budget` elements are held in memory at once, which
+    /// must be at least [`Self::out_of_core_min_memory_budget`], or an error
+    /// of kind [`io::ErrorKind::InvalidInput`] is returned.
+    pub fn out_of_core_fft<T: DomainCoeff<F>, S: FftStorage<T> + ?Sized>(
+        &self,
+        storage: &mut S,
+        scratch: &mut S,
+        memory_budget: usize,
+    ) -> io::Result<()> {
+        self.out_of_core_helper(storage, scratch, memory_budget, false)
+    }
+
+    /// Computes the inverse FFT of the `self.size()` elements of `storage` in
+    /// place, using `scratch`, of the same length, as temporary storage.
+    ///
+    /// At most `memory_budget` elements are held in memory at once, which
+    /// must be at least [`Self::out_of_core_min_memory_budget`], or an error
+    /// of kind [`io::ErrorKind::InvalidInput`] is returned.
+    pub fn out_of_core_ifft<T: DomainCoeff<F>, S: FftStorage<T> + ?Sized>(
+        &self,
+        storage: &mut S,
+        scratch: &mut S,
+        memory_budget: usize,
+    ) -> io::Result<()> {
+        self.out_of_core_helper(storage, scratch, memory_budget, true)
+    }
+
+    /// Returns the smallest memory budget, in elements, of
+    /// [`Self::out_of_core_fft`] and [`Self::out_of_core_ifft`].
+    ///
+    /// With `n = self.size()` split as `n = n_1 * n_2`, where
+    /// `n_1 = 2^ceil(log_2(n) / 2)`, this is the `3 * n_1 + n_1 / 2 + n_2 / 2`
+    /// elements of three buffers of `n_1` elements and of the roots of unity
+    /// of the column and row FFTs, or the `2 * n + n / 2` elements of the FFT
+    /// in memory if that is smaller.
+    pub fn out_of_core_min_memory_budget(&self) -> usize {
+        let n = self.size();
+        let (n1, n2) = Self::out_of_core_split(n);
+        (3 * n1 + n1 / 2 + n2 / 2).min(2 * n + n / 2)
+    }
+
+    /// Returns `(n_1, n_2)`, the numbers of rows and columns of the matrix
+    /// of `n` elements.
+    const fn out_of_core_split(n: usize) -> (usize, usize) {
+        let log_n2 = n.trailing_zeros() / 2;
+        (n >> log_n2, 1 << log_n2)
+    }
+
+    fn out_of_core_helper<T: DomainCoeff<F>, S: FftStorage<T> + ?Sized>(
+        &self,
+        storage: &mut S,
+        scratch: &mut S,
+        memory_budget: usize,
+        inverse: bool,
+    ) -> io::Result<()> {
+        let n = self.size();
+        assert_eq!(storage.len(), n, "the storage must have the domain size");
+        // The FFT in memory holds the vector, its serialization buffer and
+        // `n / 2` roots of unity.
+        if 2 * n + n / 2 <= memory_budget {
+            let mut v = vec![T::zero(); n];
+            storage.read_block(0, &mut v)?;
+            if inverse {
+                self.ifft_in_place(&mut v);
+            } else {
+                self.fft_in_place(&mut v);
+            }
+            return storage.write_block(0, &v);
+        }
+        assert_eq!(
+            scratch.len(),
+            n,
+            "the scratch storage must have the domain size"
+        );
+
+        let (n1, n2) = Self::out_of_core_split(n);
+        // Besides the `n_1 / 2 + n_2 / 2` roots of unity, each pass holds an
+        // input, an output and a serialization buffer of the same size.
+        let buffer_size = memory_budget.saturating_sub(n1 / 2 + n2 / 2) / 3;
+        if n1 > buffer_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the memory budget is too small",
+            ));
+        }
+        let (root, offset) = if inverse {
+            (self.group_gen_inv, F::one())
+        } else {
+            (self.group_gen, self.offset)
+        };
+        let column_domain = Self::new(n1).unwrap();
+        let row_domain = Self::new(n2).unwrap();
+        let column_roots = column_domain.roots_of_unity(root.pow([n2 as u64]));
+        let row_roots = row_domain.roots_of_unity(root.pow([n1 as u64]));
+
+        // 1. FFTs of the columns `j_2`, of the elements `n_2 j_1 + j_2`, which
+        // are multiplied by `offset^(n_2 j_1 + j_2)` beforehand and by the
+        // twiddle factors `root^(j_2 k_1)` afterwards.
+        let mut rows_buffer = vec![T::zero(); buffer_size];
+        let mut columns_buffer = vec![T::zero(); buffer_size];
+        let width = prev_power_of_two(buffer_size / n1).min(n2);
+        let rows = &mut rows_buffer[..n1 * width];
+        let columns = &mut columns_buffer[..n1 * width];
+        let offset_pow_n2 = offset.pow([n2 as u64]);
+        for start in (0..n2).step_by(width) {
+            for (j1, row) in rows.chunks_mut(width).enumerate() {
+                storage.read_block(n2 * j1 + start, row)?;
+            }
+            transpose(rows, columns, n1, width);
+            let (root_start, offset_start) = (root.pow([start as u64]), offset.pow([start as u64]));
+            cfg_chunks_mut!(columns, n1)
+                .enumerate()
+                .for_each(|(j, column)| {
+                    let j = j as u64;
+                    if !offset.is_one() {
+                        let first = offset_start * offset.pow([j]);
+                        Self::distribute_powers_and_mul_by_const(column, offset_pow_n2, first);
+                    }
+                    derange(column, column_domain.log_size_of_group);
+                    Self::oi_helper_with_roots(column, &column_roots, 1);
+                    let twiddle = root_start * root.pow([j]);
+                    let mut power = F::one();
+                    for c in column {
+                        *c *= power;
+                        power *= twiddle;
+                    }
+                });
+            transpose(columns, rows, width, n1);
+            for (j1, row) in rows.chunks(width).enumerate() {
+                storage.write_block(n2 * j1 + start, row)?;
+            }
+        }
+
+        // 2. FFTs of the rows `k_1`, whose elements `k_2` are the evaluations
+        // `k_1 + n_1 k_2`.
+        let height = prev_power_of_two(buffer_size / n2).min(n1);
+        let rows = &mut rows_buffer[..height * n2];
+        let columns = &mut columns_buffer[..height * n2];
+        for start in (0..n1).step_by(height) {
+            storage.read_block(n2 * start, rows)?;
+            cfg_chunks_mut!(rows, n2).for_each(|row| {
+                derange(row, row_domain.log_size_of_group);
+                Self::oi_helper_with_roots(row, &row_roots, 1);
+            });
+            transpose(rows, columns, height, n2);
+            for (k2, column) in columns.chunks(height).enumerate() {
+                scratch.write_block(start + n1 * k2, column)?;
+            }
+        }
+
+        // 3. Copy back, dividing by `n` and by `offset^k` for the inverse FFT.
+        for start in (0..n).step_by(buffer_size) {
+            let block = &mut rows_buffer[..buffer_size.min(n - start)];
+            scratch.read_block(start, block)?;
+            if inverse {
+                let first = self.size_inv * self.offset_inv.pow([start as u64]);
+                Self::distribute_powers_and_mul_by_const(block, self.offset_inv, first);
+            }
+            storage.write_block(start, block)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes to `dst` the transpose of the matrix `src`, which has `rows` rows of
+/// `cols` contiguous elements.
+fn transpose<T: Copy>(src: &[T], dst: &mut [T], rows: usize, cols: usize) {
+    for (i, row) in src.chunks(cols).enumerate() {
+        for (j, &elem) in row.iter().enumerate() {
+            dst[j * rows + i] = elem;
+        }
+    }
+}
+
+const fn prev_power_of_two(n: usize) -> usize {
+    1 << n.ilog2()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{UniformRand, Zero};
+    use ark_std::test_rng;
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn out_of_core_fft_matches_fft() {
+        let mut rng = test_rng();
+        for log_size in [4, 7, 10] {
+            let domain = Radix2EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            for domain in [domain, domain.get_coset(Fr::GENERATOR).unwrap()] {
+                let coeffs: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect();
+                let smallest = domain.out_of_core_min_memory_budget();
+                for memory_budget in [smallest, 1 << log_size, 3 << log_size] {
+                    let mut storage = coeffs.clone();
+                    let mut scratch = vec![Fr::zero(); domain.size()];
+                    domain
+                        .out_of_core_fft(&mut storage, &mut scratch, memory_budget)
+                        .unwrap();
+                    assert_eq!(storage, domain.fft(&coeffs));
+                    domain
+                        .out_of_core_ifft(&mut storage, &mut scratch, memory_budget)
+                        .unwrap();
+                    assert_eq!(storage, coeffs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn min_memory_budget() {
+        for (log_size, expected) in [(0, 2), (1, 5), (2, 8), (3, 15), (10, 128), (11, 240)] {
+            let domain = Radix2EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let smallest = domain.out_of_core_min_memory_budget();
+            assert_eq!(smallest, expected);
+            let mut storage = vec![Fr::zero(); domain.size()];
+            let mut scratch = vec![Fr::zero(); domain.size()];
+            domain
+                .out_of_core_fft(&mut storage, &mut scratch, smallest)
+                .unwrap();
+            let error = domain
+                .out_of_core_fft(&mut storage, &mut scratch, smallest - 1)
+                .unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        }
+    }
+
+    /// A temporary file, removed when dropped even if the test fails.
+    #[cfg(feature = "std")]
+    struct TempFile(std::path::PathBuf);
+
+    #[cfg(feature = "std")]
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let name = format!("ark-poly-{}-{}", name, std::process::id());
+            Self(std::env::temp_dir().join(name))
+        }
+
+        fn open(&self) -> std::fs::File {
+            std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&self.0)
+                .unwrap()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_storage() {
+        let mut rng = test_rng();
+        let domain = Radix2EvaluationDomain::<Fr>::new(1 << 9).unwrap();
+        let coeffs: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect();
+        let (storage_file, scratch_file) = (TempFile::new("storage"), TempFile::new("scratch"));
+        let mut storage = FileStorage::<Fr>::new(storage_file.open(), domain.size()).unwrap();
+        let mut scratch = FileStorage::<Fr>::new(scratch_file.open(), domain.size()).unwrap();
+        storage.write_block(0, &coeffs).unwrap();
+        let memory_budget = domain.out_of_core_min_memory_budget();
+        domain
+            .out_of_core_fft(&mut storage, &mut scratch, memory_budget)
+            .unwrap();
+        let mut evals = vec![Fr::zero(); domain.size()];
+        storage.read_block(0, &mut evals).unwrap();
+        assert_eq!(evals, domain.fft(&coeffs));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_storage_rejects_overflowing_size() {
+        let file = TempFile::new("overflow");
+        let error = FileStorage::<Fr>::new(file.open(), usize::MAX / 2).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,329 @@
+//! FFT plans, which cache the twiddle factors of a [`Radix2EvaluationDomain`]
+//! so that they are computed once for all the FFTs over the domain.
+
+use crate::domain::{
+    radix2::Radix2EvaluationDomain,
+    utils::{compute_powers_and_mul_by_const_serial, compute_powers_serial, resized_columns},
+    DomainCoeff, EvaluationDomain,
+};
+use ark_ff::FftField;
+use ark_serialize::{CanonicalSerialize, Compress, SerializationError, Valid, Write};
+use ark_std::{fmt, hash, sync::Arc, vec::*};
+
+/// A [`Radix2EvaluationDomain`] along with its forward and inverse roots of
+/// unity and the powers of its coset offset, which are otherwise recomputed
+/// on each FFT.
+///
+/// The FFTs of the plan give the same results as those of its domain, and the
+/// rest of the [`EvaluationDomain`] API is available through
+/// [`FftPlan::domain`]. Since evaluation domains are `Copy`, the plan, which
+/// owns its tables, is not one itself. Clones of the plan and the plans of
+/// its cosets share its roots of unity, and the tables are freed with the
+/// last plan that uses them.
+///
+/// Only the domain is serialized. To restore a plan, deserialize a
+/// [`Radix2EvaluationDomain`], check that its size is acceptable, since the
+/// tables have that size, and build the plan with [`FftPlan::from`].
+///
+/// # Examples
+///
+/// ```
+/// use ark_poly::{domain::FftPlan, EvaluationDomain, Radix2EvaluationDomain};
+/// use ark_test_curves::bls12_381::Fr;
+///
+/// let domain = Radix2EvaluationDomain::<Fr>::new(8).unwrap();
+/// let plan = FftPlan::from(domain);
+/// let coeffs = vec![Fr::from(1u8), Fr::from(2u8), Fr::from(3u8)];
+/// let evals = plan.fft(&coeffs);
+/// assert_eq!(evals, domain.fft(&coeffs));
+/// assert_eq!(plan.domain().size(), 8);
+/// ```
+#[derive(Clone)]
+pub struct FftPlan<F: FftField> {
+    domain: Radix2EvaluationDomain<F>,
+    /// The first `size / 2` powers of the generator.
+    roots: Arc<Vec<F>>,
+    /// The first `size / 2` powers of the inverse of the generator.
+    inverse_roots: Arc<Vec<F>>,
+    /// The first `size` powers of the coset offset, if it is not one.
+    offset_powers: Option<Arc<Vec<F>>>,
+    /// The first `size` powers of the inverse of the coset offset, divided
+    /// by the size.
+    inverse_scalars: Arc<Vec<F>>,
+}
+
+impl<F: FftField> hash::Hash for FftPlan<F> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.domain.hash(state);
+    }
+}
+
+impl<F: FftField> PartialEq for FftPlan<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.domain == other.domain
+    }
+}
+
+impl<F: FftField> Eq for FftPlan<F> {}
+
+/// Only the domain is serialized.
+impl<F: FftField> CanonicalSerialize for FftPlan<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.domain.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.domain.serialized_size(compress)
+    }
+}
+
+impl<F: FftField> Valid for FftPlan<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.domain.check()
+    }
+}
+
+impl<F: FftField> fmt::Debug for FftPlan<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FFT plan over {:?}", self.domain)
+    }
+}
+
+impl<F: FftField> From<Radix2EvaluationDomain<F>> for FftPlan<F> {
+    /// Computes the twiddle factors of `domain`.
+    fn from(domain: Radix2EvaluationDomain<F>) -> Self {
+        let roots = Arc::new(domain.roots_of_unity(domain.group_gen));
+        let inverse_roots = Arc::new(domain.roots_of_unity(domain.group_gen_inv));
+        Self::with_roots(domain, roots, inverse_roots)
+    }
+}
+
+impl<F: FftField> FftPlan<F> {
+    /// Computes the coset offset tables of `domain`, whose roots of unity
+    /// are `roots` and `inverse_roots`.
+    fn with_roots(
+        domain: Radix2EvaluationDomain<F>,
+        roots: Arc<Vec<F>>,
+        inverse_roots: Arc<Vec<F>>,
+    ) -> Self {
+        let size = domain.size();
+        Self {
+            domain,
+            roots,
+            inverse_roots,
+            offset_powers: (!domain.offset.is_one())
+                .then(|| Arc::new(compute_powers_serial(size, domain.offset))),
+            inverse_scalars: Arc::new(compute_powers_and_mul_by_const_serial(
+                size,
+                domain.offset_inv,
+                domain.size_inv,
+            )),
+        }
+    }
+
+    /// Returns the domain of the plan.
+    pub const fn domain(&self) -> Radix2EvaluationDomain<F> {
+        self.domain
+    }
+
+    /// Returns the plan of the coset `offset * H` of the domain `H` of
+    /// `self`, which shares its roots of unity, or `None` if `offset` is
+    /// zero. Only the powers of `offset` are computed.
+    pub fn get_coset(&self, offset: F) -> Option<Self> {
+        let domain = self.domain.get_coset(offset)?;
+        Some(Self::with_roots(
+            domain,
+            self.roots.clone(),
+            self.inverse_roots.clone(),
+        ))
+    }
+
+    /// Computes the FFT of `coeffs` over the domain, as
+    /// [`EvaluationDomain::fft`].
+    pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Computes the FFT of `coeffs` over the domain in place, as
+    /// [`EvaluationDomain::fft_in_place`].
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        self.fft_batch(ark_std::slice::from_mut(coeffs));
+    }
+
+    /// Computes the IFFT of `evals` over the domain, as
+    /// [`EvaluationDomain::ifft`].
+    pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Computes the IFFT of `evals` over the domain in place, as
+    /// [`EvaluationDomain::ifft_in_place`].
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        self.ifft_batch(ark_std::slice::from_mut(evals));
+    }
+
+    /// Computes the FFT of `coeffs`, which has the size of the domain, in
+    /// place, as [`EvaluationDomain::fft_slice_in_place`].
+    pub fn fft_slice_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        assert_eq!(
+            coeffs.len(),
+            self.domain.size(),
+            "the slice must have the domain size"
+        );
+        self.fft_columns(&mut [coeffs]);
+    }
+
+    /// Computes the IFFT of `evals`, which has the size of the domain, in
+    /// place, as [`EvaluationDomain::ifft_slice_in_place`].
+    pub fn ifft_slice_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        assert_eq!(
+            evals.len(),
+            self.domain.size(),
+            "the slice must have the domain size"
+        );
+        self.ifft_columns(&mut [evals]);
+    }
+
+    /// Computes the FFTs of `columns` over the domain in place, as
+    /// [`EvaluationDomain::fft_batch`].
+    pub fn fft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        let mut columns = resized_columns(columns, self.domain.size());
+        self.fft_columns(&mut columns);
+    }
+
+    /// Computes the IFFTs of `columns` over the domain in place, as
+    /// [`EvaluationDomain::ifft_batch`].
+    pub fn ifft_batch<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        let mut columns = resized_columns(columns, self.domain.size());
+        self.ifft_columns(&mut columns);
+    }
+
+    /// Computes the FFTs of the consecutive columns of `matrix` over the
+    /// domain in place, as [`EvaluationDomain::fft_batch_column_major`].
+    pub fn fft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T]) {
+        let mut columns = self.split_columns(matrix);
+        self.fft_columns(&mut columns);
+    }
+
+    /// Computes the IFFTs of the consecutive columns of `matrix` over the
+    /// domain in place, as [`EvaluationDomain::ifft_batch_column_major`].
+    pub fn ifft_batch_column_major<T: DomainCoeff<F>>(&self, matrix: &mut [T]) {
+        let mut columns = self.split_columns(matrix);
+        self.ifft_columns(&mut columns);
+    }
+
+    fn split_columns<'a, T>(&self, matrix: &'a mut [T]) -> Vec<&'a mut [T]> {
+        assert_eq!(
+            matrix.len() % self.domain.size(),
+            0,
+            "the columns must have the size of the domain"
+        );
+        matrix.chunks_mut(self.domain.size()).collect()
+    }
+
+    fn fft_columns<T: DomainCoeff<F>>(&self, columns: &mut [&mut [T]]) {
+        Radix2EvaluationDomain::fft_columns_with_tables(
+            columns,
+            &self.roots,
+            self.offset_powers.as_deref().map(Vec::as_slice),
+        );
+    }
+
+    fn ifft_columns<T: DomainCoeff<F>>(&self, columns: &mut [&mut [T]]) {
+        Radix2EvaluationDomain::ifft_columns_with_tables(
+            columns,
+            &self.inverse_roots,
+            &self.inverse_scalars,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{EvaluationDomain, FftPlan, Radix2EvaluationDomain};
+    use ark_ff::{FftField, UniformRand};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{sync::Arc, test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn plan_matches_domain() {
+        let rng = &mut test_rng();
+        for log_size in 0..10 {
+            let domain = Radix2EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            for domain in [domain, domain.get_coset(Fr::GENERATOR).unwrap()] {
+                let plan = FftPlan::from(domain);
+                let coeffs: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+                assert_eq!(plan.fft(&coeffs), domain.fft(&coeffs));
+                assert_eq!(plan.ifft(&coeffs), domain.ifft(&coeffs));
+                assert_eq!(plan.fft(&coeffs[..1]), domain.fft(&coeffs[..1]));
+
+                let mut columns = vec![coeffs.clone(); 3];
+                plan.fft_batch(&mut columns);
+                assert!(columns.iter().all(|c| *c == domain.fft(&coeffs)));
+                let mut matrix = columns.concat();
+                plan.ifft_batch_column_major(&mut matrix);
+                assert_eq!(matrix, coeffs.repeat(3));
+
+                let mut column = coeffs.clone();
+                plan.fft_slice_in_place(&mut column);
+                assert_eq!(column, domain.fft(&coeffs));
+                plan.ifft_slice_in_place(&mut column);
+                assert_eq!(column, coeffs);
+
+                let coset = domain.get_coset(Fr::from(5u8)).unwrap();
+                let coset_plan = plan.get_coset(Fr::from(5u8)).unwrap();
+                assert_eq!(coset_plan.domain(), coset);
+                assert!(Arc::ptr_eq(&coset_plan.roots, &plan.roots));
+                assert!(Arc::ptr_eq(&coset_plan.inverse_roots, &plan.inverse_roots));
+                assert_eq!(coset_plan.fft(&coeffs), coset.fft(&coeffs));
+                assert_eq!(coset_plan.ifft(&coeffs), coset.ifft(&coeffs));
+            }
+        }
+    }
+
+    #[test]
+    fn clones_share_tables() {
+        let domain = Radix2EvaluationDomain::<Fr>::new(16)
+            .unwrap()
+            .get_coset(Fr::GENERATOR)
+            .unwrap();
+        let plan = FftPlan::from(domain);
+        let clone = plan.clone();
+        assert!(Arc::ptr_eq(&plan.roots, &clone.roots));
+        assert!(Arc::ptr_eq(
+            plan.offset_powers.as_ref().unwrap(),
+            clone.offset_powers.as_ref().unwrap()
+        ));
+        assert!(Arc::ptr_eq(&plan.inverse_scalars, &clone.inverse_scalars));
+        drop(plan);
+        assert_eq!(Arc::strong_count(&clone.roots), 1);
+    }
+
+    #[test]
+    fn serialization() {
+        let domain = Radix2EvaluationDomain::<Fr>::new(64)
+            .unwrap()
+            .get_coset(Fr::GENERATOR)
+            .unwrap();
+        let plan = FftPlan::from(domain);
+        let mut bytes = Vec::new();
+        plan.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), domain.compressed_size());
+        let deserialized =
+            FftPlan::from(Radix2EvaluationDomain::deserialize_compressed(&bytes[..]).unwrap());
+        assert_eq!(deserialized, plan);
+        assert_eq!(
+            deserialized.fft(&[Fr::from(1u8), Fr::from(2u8)]),
+            domain.fft(&[Fr::from(1u8), Fr::from(2u8)])
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::domain::DomainCoeff;
-use ark_ff::{FftField, Field};
+use ark_ff::{FftField, Field, Zero};
 use ark_std::vec::*;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -38,6 +38,20 @@ pub(crate) fn compute_powers_and_mul_by_const_serial<F: Field>(
         .collect()
 }
 
+/// Resizes each of `columns` to `size` and returns them as slices.
+pub(crate) fn resized_columns<T: Clone + Zero>(
+    columns: &mut [Vec<T>],
+    size: usize,
+) -> Vec<&mut [T]> {
+    columns
+        .iter_mut()
+        .map(|column| {
+            column.resize(size, T::zero());
+            column.as_mut_slice()
+        })
+        .collect()
+}
+
 #[allow(unused)]
 #[cfg(feature = "parallel")]
 pub(crate) fn compute_powers<F: Field>(size: usize, g: F) -> Vec<F> {
@@ -188,6 +202,49 @@ pub(crate) fn parallel_fft<T: DomainCoeff<F>, F: FftField>(
         .for_each(|(i, a)| *a = tmp[i % num_cosets][i / num_cosets]);
 }
 
+/// Reduces `coeffs` modulo the monic polynomial with the sparse coefficients
+/// `modulus`, leaving a vector of length `deg(modulus)` if it was longer.
+pub(crate) fn reduce_by_monic_sparse<T: DomainCoeff<F>, F: FftField>(
+    coeffs: &mut Vec<T>,
+    modulus: &[(usize, F)],
+) {
+    let ((degree, leading), lower) = modulus.split_last().unwrap();
+    debug_assert_eq!(*leading, F::ONE);
+    for d in (*degree..coeffs.len()).rev() {
+        let q = coeffs[d];
+        for (i, c) in lower {
+            let mut t = q;
+            t *= *c;
+            coeffs[d - degree + i] -= t;
+        }
+    }
+    coeffs.truncate(*degree);
+}
+
+/// Splits `elems` into the vectors of their components over the base prime
+/// field.
+pub(crate) fn to_base_prime_field_components<E: Field>(elems: &[E]) -> Vec<Vec<E::BasePrimeField>> {
+    let mut components = vec![Vec::with_capacity(elems.len()); E::extension_degree() as usize];
+    for elem in elems {
+        components
+            .iter_mut()
+            .zip(elem.to_base_prime_field_elements())
+            .for_each(|(component, c)| component.push(c));
+    }
+    components
+}
+
+/// Recombines the vectors of components over the base prime field returned
+/// by [`to_base_prime_field_components`].
+pub(crate) fn from_base_prime_field_components<E: Field>(
+    components: &[Vec<E::BasePrimeField>],
+) -> Vec<E> {
+    let len = components.first().map_or(0, Vec::len);
+    (0..len)
+        .map(|i| E::from_base_prime_field_elems(components.iter().map(|c| c[i])).unwrap())
+        .collect()
+}
+
 /// An iterator over the elements of a domain.
 pub struct Elements<F: FftField> {
     pub(crate) cur_elem: F,
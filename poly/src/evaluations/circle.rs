@@ -0,0 +1,127 @@
+//! A circle polynomial represented in evaluations form.
+
+use crate::{circle::CirclePolynomial, domain::circle::CircleEvaluationDomain};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    ops::{Add, AddAssign, Index, Mul, MulAssign, Sub, SubAssign},
+    vec::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Stores a circle polynomial in evaluation form.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CircleEvaluations<F: PrimeField> {
+    /// The evaluations of a polynomial over the domain
+    pub evals: Vec<F>,
+    #[doc(hidden)]
+    domain: CircleEvaluationDomain<F>,
+}
+
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: PrimeField] CircleEvaluations<F>);
+
+impl<F: PrimeField> CircleEvaluations<F> {
+    /// Evaluations of the zero polynomial over `domain`.
+    pub fn zero(domain: CircleEvaluationDomain<F>) -> Self {
+        Self {
+            evals: vec![F::zero(); domain.size()],
+            domain,
+        }
+    }
+
+    /// Construct `Self` from evaluations and a domain.
+    pub const fn from_vec_and_domain(evals: Vec<F>, domain: CircleEvaluationDomain<F>) -> Self {
+        Self { evals, domain }
+    }
+
+    /// Interpolate a polynomial from a list of evaluations
+    pub fn interpolate_by_ref(&self) -> CirclePolynomial<F> {
+        CirclePolynomial::from_coefficients_vec(self.domain.ifft(&self.evals))
+    }
+
+    /// Interpolate a polynomial from a list of evaluations
+    pub fn interpolate(self) -> CirclePolynomial<F> {
+        let Self { mut evals, domain } = self;
+        domain.ifft_in_place(&mut evals);
+        CirclePolynomial::from_coefficients_vec(evals)
+    }
+
+    /// Return the domain `self` is defined over
+    pub const fn domain(&self) -> CircleEvaluationDomain<F> {
+        self.domain
+    }
+}
+
+impl<F: PrimeField> Index<usize> for CircleEvaluations<F> {
+    type Output = F;
+
+    fn index(&self, index: usize) -> &F {
+        &self.evals[index]
+    }
+}
+
+impl<'a, F: PrimeField> Mul<&'a CircleEvaluations<F>> for &CircleEvaluations<F> {
+    type Output = CircleEvaluations<F>;
+
+    #[inline]
+    fn mul(self, other: &'a CircleEvaluations<F>) -> CircleEvaluations<F> {
+        let mut result = self.clone();
+        result *= other;
+        result
+    }
+}
+
+impl<'a, F: PrimeField> MulAssign<&'a Self> for CircleEvaluations<F> {
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Self) {
+        assert_eq!(self.domain, other.domain, "domains are unequal");
+        ark_std::cfg_iter_mut!(self.evals)
+            .zip(&other.evals)
+            .for_each(|(a, b)| *a *= b);
+    }
+}
+
+impl<'a, F: PrimeField> Add<&'a CircleEvaluations<F>> for &CircleEvaluations<F> {
+    type Output = CircleEvaluations<F>;
+
+    #[inline]
+    fn add(self, other: &'a CircleEvaluations<F>) -> CircleEvaluations<F> {
+        let mut result = self.clone();
+        result += other;
+        result
+    }
+}
+
+impl<'a, F: PrimeField> AddAssign<&'a Self> for CircleEvaluations<F> {
+    #[inline]
+    fn add_assign(&mut self, other: &'a Self) {
+        assert_eq!(self.domain, other.domain, "domains are unequal");
+        ark_std::cfg_iter_mut!(self.evals)
+            .zip(&other.evals)
+            .for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<'a, F: PrimeField> Sub<&'a CircleEvaluations<F>> for &CircleEvaluations<F> {
+    type Output = CircleEvaluations<F>;
+
+    #[inline]
+    fn sub(self, other: &'a CircleEvaluations<F>) -> CircleEvaluations<F> {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<'a, F: PrimeField> SubAssign<&'a Self> for CircleEvaluations<F> {
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Self) {
+        assert_eq!(self.domain, other.domain, "domains are unequal");
+        ark_std::cfg_iter_mut!(self.evals)
+            .zip(&other.evals)
+            .for_each(|(a, b)| *a -= b);
+    }
+}
@@ -1,2 +1,3 @@
+pub mod circle;
 pub mod multivariate;
 pub mod univariate;
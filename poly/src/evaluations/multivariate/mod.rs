@@ -1,4 +1,6 @@
 pub mod multilinear;
 pub use multilinear::{
-    DenseMultilinearExtension, MultilinearExtension, SparseMultilinearExtension,
+    DenseMultilinearExtension, EqPolynomial, IterableMultilinearExtension, MultilinearExtension,
+    OracleMultilinearExtension, SparseMultilinearExtension, SplitEqPolynomial,
+    StreamingMultilinearExtension,
 };
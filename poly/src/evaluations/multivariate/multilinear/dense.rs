@@ -28,6 +28,9 @@ pub struct DenseMultilinearExtension<F: Field> {
     pub num_vars: usize,
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: Field] DenseMultilinearExtension<F>);
+
 impl<F: Field> DenseMultilinearExtension<F> {
     /// Construct a new polynomial from a list of evaluations where the index
     /// represents a point in {0,1}^`num_vars` in little endian form. For
@@ -90,6 +93,115 @@ impl<F: Field> DenseMultilinearExtension<F> {
         }
     }
 
+    /// Binds the lowest `partial_point.len()` variables of `self` to the values
+    /// in `partial_point` (from left to right) in place, as
+    /// [`MultilinearExtension::fix_variables`].
+    pub fn fix_variables_in_place(&mut self, partial_point: &[F]) {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "invalid size of partial point"
+        );
+        if partial_point.is_empty() {
+            return;
+        }
+        // bind the lowest remaining variable, which pairs up consecutive
+        // evaluations; the `i`-th pair is read before it can be overwritten,
+        // since it starts at index `2i >= i`
+        for r in partial_point {
+            let half = self.evaluations.len() / 2;
+            for i in 0..half {
+                let (left, right) = (self.evaluations[2 * i], self.evaluations[2 * i + 1]);
+                self.evaluations[i] = left + *r * (right - left);
+            }
+            self.evaluations.truncate(half);
+        }
+        self.num_vars -= partial_point.len();
+    }
+
+    /// Return the MLE resulting from binding the last variables of self to
+    /// the values in `partial_point`, so that `partial_point[j]` is bound to
+    /// `x_{num_vars - partial_point.len() + j}`.
+    pub fn fix_high_variables(&self, partial_point: &[F]) -> Self {
+        let mut copied = self.clone();
+        copied.fix_high_variables_in_place(partial_point);
+        copied
+    }
+
+    /// Binds the last variables of `self` in place, as
+    /// [`Self::fix_high_variables`].
+    pub fn fix_high_variables_in_place(&mut self, partial_point: &[F]) {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "invalid size of partial point"
+        );
+        // bind the highest remaining variable, which splits the evaluations in halves
+        for r in partial_point.iter().rev() {
+            let half = self.evaluations.len() / 2;
+            let (low, high) = self.evaluations.split_at_mut(half);
+            cfg_iter_mut!(low)
+                .zip(high)
+                .for_each(|(left, right)| *left += *r * (*right - *left));
+            self.evaluations.truncate(half);
+        }
+        self.num_vars -= partial_point.len();
+    }
+
+    /// Return the MLE resulting from binding the variable `x_{indices[j]}` of
+    /// self to `values[j]` for each `j`. The remaining variables keep their
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` and `values` have different lengths, or if the
+    /// indices are not distinct variables of self.
+    pub fn fix_variables_at(&self, indices: &[usize], values: &[F]) -> Self {
+        assert_eq!(
+            indices.len(),
+            values.len(),
+            "indices and values should have the same length"
+        );
+        assert!(
+            indices
+                .iter()
+                .enumerate()
+                .all(|(j, i)| *i < self.num_vars && !indices[..j].contains(i)),
+            "invalid indices"
+        );
+        let mut indices = indices.to_vec();
+        let mut evaluations = self.evaluations.clone();
+        for j in 0..indices.len() {
+            let i = indices[j];
+            let low_mask = (1 << i) - 1;
+            evaluations = cfg_into_iter!(0..evaluations.len() / 2)
+                .map(|b| {
+                    let index = ((b & !low_mask) << 1) | (b & low_mask);
+                    let (left, right) = (evaluations[index], evaluations[index | (1 << i)]);
+                    left + values[j] * (right - left)
+                })
+                .collect();
+            // the variables above `x_i` move down by one position
+            indices[j + 1..]
+                .iter_mut()
+                .filter(|k| **k > i)
+                .for_each(|k| *k -= 1);
+        }
+        Self::from_evaluations_vec(self.num_vars - indices.len(), evaluations)
+    }
+
+    /// Binds the lowest variables of each of `polys` to `partial_point` in
+    /// place, in parallel over the polynomials, as
+    /// [`Self::fix_variables_in_place`].
+    pub fn batch_fix_variables_in_place(polys: &mut [Self], partial_point: &[F]) {
+        cfg_iter_mut!(polys).for_each(|poly| poly.fix_variables_in_place(partial_point));
+    }
+
+    /// Binds the last variables of each of `polys` to `partial_point` in
+    /// place, in parallel over the polynomials, as
+    /// [`Self::fix_high_variables_in_place`].
+    pub fn batch_fix_high_variables_in_place(polys: &mut [Self], partial_point: &[F]) {
+        cfg_iter_mut!(polys).for_each(|poly| poly.fix_high_variables_in_place(partial_point));
+    }
+
     /// Returns an iterator that iterates over the evaluations over {0,1}^`num_vars`
     pub fn iter(&self) -> Iter<'_, F> {
         self.evaluations.iter()
@@ -223,19 +335,17 @@ impl<F: Field> MultilinearExtension<F> for DenseMultilinearExtension<F> {
             partial_point.len() <= self.num_vars,
             "invalid size of partial point"
         );
-        let mut poly = self.evaluations.clone();
-        let nv = self.num_vars;
-        let dim = partial_point.len();
-        // evaluate single variable of partial point from left to right
-        for i in 1..dim + 1 {
-            let r = partial_point[i - 1];
-            for b in 0..(1 << (nv - i)) {
-                let left = poly[b << 1];
-                let right = poly[(b << 1) + 1];
-                poly[b] = left + r * (right - left);
-            }
-        }
-        Self::from_evaluations_slice(nv - dim, &poly[..(1 << (nv - dim))])
+        let Some((r, rest)) = partial_point.split_first() else {
+            return self.clone();
+        };
+        // bind the first variable into a new table of half the size, and the
+        // others in place
+        let evaluations = cfg_chunks!(self.evaluations, 2)
+            .map(|pair| pair[0] + *r * (pair[1] - pair[0]))
+            .collect();
+        let mut poly = Self::from_evaluations_vec(self.num_vars - 1, evaluations);
+        poly.fix_variables_in_place(rest);
+        poly
     }
 
     fn to_evaluations(&self) -> Vec<F> {
@@ -484,6 +594,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn evaluate_at_a_point_small_field() {
+        use ark_test_curves::small_fields::BabyBear;
+
+        let mut rng = test_rng();
+        let poly = DenseMultilinearExtension::<BabyBear>::rand(10, &mut rng);
+        for _ in 0..10 {
+            let point: Vec<_> = (0..10).map(|_| BabyBear::rand(&mut rng)).collect();
+            assert_eq!(
+                evaluate_data_array(&poly.evaluations, &point),
+                poly.evaluate(&point)
+            );
+            assert_eq!(
+                poly.fix_variables(&point[..4])
+                    .evaluate(&point[4..].to_vec()),
+                poly.evaluate(&point)
+            );
+        }
+    }
+
     #[test]
     fn relabel_polynomial() {
         let mut rng = test_rng();
@@ -521,6 +651,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fix_variables_in_place_high_and_at() {
+        let mut rng = test_rng();
+        for nv in [0, 1, 6] {
+            let poly = DenseMultilinearExtension::rand(nv, &mut rng);
+            let point: Vec<_> = (0..nv).map(|_| Fr::rand(&mut rng)).collect();
+            let expected = poly.evaluate(&point);
+            for k in 0..=nv {
+                let low = poly.fix_variables(&point[..k]);
+                let mut in_place = poly.clone();
+                in_place.fix_variables_in_place(&point[..k]);
+                assert_eq!(in_place, low);
+                assert_eq!(low.evaluate(&point[k..].to_vec()), expected);
+
+                let high = poly.fix_high_variables(&point[nv - k..]);
+                assert_eq!(high.num_vars, nv - k);
+                assert_eq!(high.evaluate(&point[..nv - k].to_vec()), expected);
+
+                let mut polys = vec![poly.clone(); 2];
+                DenseMultilinearExtension::batch_fix_variables_in_place(&mut polys, &point[..k]);
+                assert_eq!(polys, [low.clone(), low]);
+                let mut polys = vec![poly.clone()];
+                DenseMultilinearExtension::batch_fix_high_variables_in_place(
+                    &mut polys,
+                    &point[nv - k..],
+                );
+                assert_eq!(polys, [high]);
+            }
+        }
+
+        // bind x_4, x_1 and x_3 of a polynomial in 6 variables
+        let poly = DenseMultilinearExtension::rand(6, &mut rng);
+        let point: Vec<_> = (0..6).map(|_| Fr::rand(&mut rng)).collect();
+        let bound = poly.fix_variables_at(&[4, 1, 3], &[point[4], point[1], point[3]]);
+        assert_eq!(
+            bound.evaluate(&vec![point[0], point[2], point[5]]),
+            poly.evaluate(&point)
+        );
+    }
+
     #[test]
     fn arithmetic() {
         const NV: usize = 10;
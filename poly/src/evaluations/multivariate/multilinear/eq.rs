@@ -0,0 +1,373 @@
+//! The equality polynomial `eq(r, x)`, whose evaluations over the boolean
+//! hypercube are the multilinear Lagrange basis at `r`.
+
+use crate::{
+    sumcheck::{has_distinct_nodes, interpolate_at},
+    DenseMultilinearExtension,
+};
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{vec, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The multilinear polynomial
+/// `eq(r, x) = prod_i (r_i * x_i + (1 - r_i) * (1 - x_i))`, which is one if
+/// `x = r` and zero elsewhere on the boolean hypercube, for a fixed point `r`.
+///
+/// Its evaluations over the boolean hypercube are the multilinear Lagrange
+/// basis at `r`, so that any multilinear extension `f` satisfies
+/// `f(r) = sum_x f(x) * eq(r, x)`.
+///
+/// # Example
+/// ```
+/// use ark_poly::{DenseMultilinearExtension, EqPolynomial, MultilinearExtension, Polynomial};
+/// use ark_test_curves::bls12_381::Fr;
+/// use ark_std::{test_rng, UniformRand};
+///
+/// let rng = &mut test_rng();
+/// let point: Vec<Fr> = (0..4).map(|_| Fr::rand(rng)).collect();
+/// let mle = DenseMultilinearExtension::rand(4, rng);
+/// let eq = EqPolynomial::new(point.clone());
+/// assert_eq!(eq.evaluate_mle(&mle), mle.evaluate(&point));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct EqPolynomial<F: Field> {
+    point: Vec<F>,
+}
+
+impl<F: Field> EqPolynomial<F> {
+    /// Constructs `eq(point, x)`.
+    pub const fn new(point: Vec<F>) -> Self {
+        Self { point }
+    }
+
+    /// Returns the point `r` of `eq(r, x)`.
+    pub fn point(&self) -> &[F] {
+        &self.point
+    }
+
+    /// Returns the number of variables of the polynomial.
+    pub fn num_vars(&self) -> usize {
+        self.point.len()
+    }
+
+    /// Evaluates `eq(r, other)` in `O(n)`.
+    pub fn evaluate(&self, other: &[F]) -> F {
+        assert_eq!(
+            other.len(),
+            self.point.len(),
+            "the points have different sizes"
+        );
+        self.point
+            .iter()
+            .zip(other)
+            .map(|(r, x)| linear_factor(*r, *x))
+            .product()
+    }
+
+    /// Returns the evaluations of `eq(r, x)` over the boolean hypercube, in
+    /// little-endian order, in `O(2^n)`.
+    ///
+    /// These are the multilinear Lagrange basis polynomials evaluated at
+    /// `r`.
+    pub fn evaluations(&self) -> Vec<F> {
+        let mut table = vec![F::zero(); 1 << self.point.len()];
+        table[0] = F::one();
+        for (i, r) in self.point.iter().enumerate() {
+            // Doubling the table for `x_0, ..., x_{i - 1}` by `x_i`.
+            let (low, high) = table[..2 << i].split_at_mut(1 << i);
+            cfg_iter_mut!(low).zip(high).for_each(|(low, high)| {
+                *high = *low * r;
+                *low -= *high;
+            });
+        }
+        table
+    }
+
+    /// Returns `eq(r, x)` as a multilinear extension.
+    pub fn to_mle(&self) -> DenseMultilinearExtension<F> {
+        DenseMultilinearExtension::from_evaluations_vec(self.point.len(), self.evaluations())
+    }
+
+    /// Evaluates `mle` at `r`, as its inner product with the multilinear
+    /// Lagrange basis at `r`.
+    pub fn evaluate_mle(&self, mle: &DenseMultilinearExtension<F>) -> F {
+        assert_eq!(
+            mle.num_vars,
+            self.point.len(),
+            "the multilinear extension has the wrong number of variables"
+        );
+        cfg_iter!(mle.evaluations)
+            .zip(self.evaluations())
+            .map(|(f, eq)| *f * eq)
+            .sum()
+    }
+}
+
+/// Returns `eq(r, x) = r * x + (1 - r) * (1 - x)` in one variable.
+#[inline]
+fn linear_factor<F: Field>(r: F, x: F) -> F {
+    let rx = r * x;
+    rx.double() - r - x + F::one()
+}
+
+/// The equality polynomial `eq(r, x)` during a sumcheck on `eq(r, x) * g(x)`
+/// that binds `x_0, x_1, ...` to challenges `c_0, c_1, ...`, with the
+/// split-eq and Gruen optimizations.
+///
+/// In round `i`, `eq(r, (c_0, ..., c_{i - 1}, X, x'))` is the product of the
+/// scalar `eq((r_0, ..., r_{i - 1}), (c_0, ..., c_{i - 1}))`, of the linear
+/// factor `eq(r_i, X)`, and of `eq((r_{i + 1}, ...), x')`:
+/// - the last factor is given by [`Self::remaining_tables`] as the product of
+///   two tables over the low and high halves of `x'`, which take `O(2^(n/2))`
+///   memory in total instead of `O(2^n)`;
+/// - the round polynomial is derived by [`Self::round_polynomial`] from the
+///   evaluations of `q(X) = sum_x' eq((r_{i + 1}, ...), x') * g(c, X, x')`,
+///   of degree one less, and from the claim of the round.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SplitEqPolynomial<F: Field> {
+    point: Vec<F>,
+    round: usize,
+    scalar: F,
+    /// The index at which `r` is split into its low and high halves.
+    split: usize,
+    /// The tables of `eq((r_k, ..., r_{split - 1}), x)` for `k` in
+    /// `1..=split`, indexed by `k - 1`.
+    low_tables: Vec<Vec<F>>,
+    /// The tables of `eq((r_k, ..., r_{n - 1}), x)` for `k` in `split..=n`,
+    /// indexed by `k - split`.
+    high_tables: Vec<Vec<F>>,
+}
+
+impl<F: Field> SplitEqPolynomial<F> {
+    /// Prepares the tables of `eq(point, x)` for a sumcheck that binds its
+    /// variables from low to high.
+    pub fn new(point: &[F]) -> Self {
+        let n = point.len();
+        let split = n.div_ceil(2);
+        Self {
+            point: point.to_vec(),
+            round: 0,
+            scalar: F::one(),
+            split,
+            low_tables: suffix_tables(&point[..split], 1),
+            high_tables: suffix_tables(&point[split..], 0),
+        }
+    }
+
+    /// Returns the number of variables that are already bound.
+    pub const fn round(&self) -> usize {
+        self.round
+    }
+
+    /// Returns `eq((r_0, ..., r_{i - 1}), (c_0, ..., c_{i - 1}))` for the
+    /// bound variables.
+    pub const fn bound_scalar(&self) -> F {
+        self.scalar
+    }
+
+    /// Returns the tables `(low, high)` of the variables `x'` after the
+    /// current one, so that `eq((r_{i + 1}, ...), x') = low[x'_low] *
+    /// high[x'_high]` for the index `x' = x'_low + low.len() * x'_high`.
+    pub fn remaining_tables(&self) -> (&[F], &[F]) {
+        let next = self.round + 1;
+        if next < self.split {
+            (&self.low_tables[next - 1], &self.high_tables[0])
+        } else {
+            // The last low table is the one of no variables.
+            let low = self.low_tables.last().unwrap();
+            (
+                low,
+                &self.high_tables[next.min(self.point.len()) - self.split],
+            )
+        }
+    }
+
+    /// Returns the evaluations at `0, 1, ..., d` of the round polynomial
+    /// `s(X) = scalar * eq(r_i, X) * q(X)`, given the evaluations of `q`, of
+    /// degree `d - 1`, at `0, 2, 3, ..., d - 1`, and the claim
+    /// `s(0) + s(1)` of the round.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no variable is left, if `q_evals` is empty, if the
+    /// characteristic of the field is at most `d`, or if
+    /// `eq(r_i, 1) * scalar` is zero.
+    pub fn round_polynomial(&self, q_evals: &[F], claim: F) -> Vec<F> {
+        assert!(self.round < self.point.len(), "all variables are bound");
+        assert!(!q_evals.is_empty(), "q must have at least one evaluation");
+        let r = self.point[self.round];
+        let degree = q_evals.len() + 1;
+        assert!(
+            has_distinct_nodes::<F>(degree),
+            "the degree must be smaller than the characteristic"
+        );
+        let factor = |x: F| self.scalar * linear_factor(r, x);
+        // `q(1)` follows from the claim `s(0) + s(1)`.
+        let s_0 = factor(F::zero()) * q_evals[0];
+        let q_1 = (claim - s_0)
+            * factor(F::one())
+                .inverse()
+                .expect("the linear factor vanishes at one");
+        let mut q: Vec<F> = [q_evals[0], q_1]
+            .into_iter()
+            .chain(q_evals[1..].iter().copied())
+            .collect();
+        q.push(interpolate_at(&q, F::from(degree as u64)));
+        q.into_iter()
+            .enumerate()
+            .map(|(x, q)| factor(F::from(x as u64)) * q)
+            .collect()
+    }
+
+    /// Binds the current variable to `challenge`.
+    pub fn bind(&mut self, challenge: F) {
+        assert!(self.round < self.point.len(), "all variables are bound");
+        self.scalar *= linear_factor(self.point[self.round], challenge);
+        self.round += 1;
+    }
+}
+
+/// Returns the tables of `eq(point[k..], x)` for `k` in `start..=point.len()`.
+fn suffix_tables<F: Field>(point: &[F], start: usize) -> Vec<Vec<F>> {
+    let mut tables = vec![vec![F::one()]];
+    // Prepends the variables one at a time, as the new lowest variable.
+    for r in point.iter().skip(start).rev() {
+        let previous = tables.last().unwrap();
+        let table = cfg_iter!(previous)
+            .map(|e| {
+                let high = *e * r;
+                [*e - high, high]
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        tables.push(table);
+    }
+    tables.reverse();
+    tables
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        evaluations::multivariate::multilinear::eq::SplitEqPolynomial, DenseMultilinearExtension,
+        EqPolynomial, MultilinearExtension, Polynomial,
+    };
+    use ark_ff::{binary::BinaryField128b, One, UniformRand, Zero};
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn evaluations() {
+        let rng = &mut test_rng();
+        for num_vars in [0, 1, 6] {
+            let point: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+            let eq = EqPolynomial::new(point.clone());
+            let mle = eq.to_mle();
+            for x in 0..1usize << num_vars {
+                let bits: Vec<Fr> = (0..num_vars)
+                    .map(|i| Fr::from((x >> i & 1) as u64))
+                    .collect();
+                assert_eq!(mle[x], eq.evaluate(&bits));
+            }
+            let other: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+            assert_eq!(mle.evaluate(&other), eq.evaluate(&other));
+            assert_eq!(
+                eq.evaluate(&other),
+                EqPolynomial::new(other).evaluate(&point)
+            );
+
+            let f = DenseMultilinearExtension::rand(num_vars, rng);
+            assert_eq!(eq.evaluate_mle(&f), f.evaluate(&point));
+        }
+    }
+
+    #[test]
+    fn lagrange_basis() {
+        let rng = &mut test_rng();
+        for num_vars in [0, 1, 2, 5, 8] {
+            // At a boolean point `b`, the basis is the indicator of `b`.
+            for b in [0, (1usize << num_vars) - 1, 5 % (1 << num_vars)] {
+                let bits: Vec<Fr> = (0..num_vars)
+                    .map(|i| Fr::from((b >> i & 1) as u64))
+                    .collect();
+                let basis = EqPolynomial::new(bits).evaluations();
+                for (x, e) in basis.iter().enumerate() {
+                    assert_eq!(*e, Fr::from((x == b) as u64));
+                }
+            }
+
+            // The basis at any point sums to one and recovers the evaluation
+            // of a multilinear extension from its evaluations.
+            let point: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+            let basis = EqPolynomial::new(point.clone()).evaluations();
+            assert_eq!(basis.iter().sum::<Fr>(), Fr::one());
+            let f = DenseMultilinearExtension::rand(num_vars, rng);
+            let recovered: Fr = f.iter().zip(&basis).map(|(f, e)| *f * e).sum();
+            assert_eq!(recovered, f.evaluate(&point));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one evaluation")]
+    fn round_polynomial_rejects_empty_q() {
+        SplitEqPolynomial::new(&[Fr::one()]).round_polynomial(&[], Fr::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "smaller than the characteristic")]
+    fn round_polynomial_rejects_small_characteristic() {
+        let split_eq = SplitEqPolynomial::new(&[BinaryField128b::one()]);
+        split_eq.round_polynomial(&[BinaryField128b::one()], BinaryField128b::one());
+    }
+
+    #[test]
+    fn split_eq() {
+        for num_vars in [1, 2, 7, 8] {
+            check_split_eq(num_vars);
+        }
+    }
+
+    fn check_split_eq(num_vars: usize) {
+        let rng = &mut test_rng();
+        let point: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+        let mut eq = EqPolynomial::new(point.clone()).to_mle();
+        let mut g = DenseMultilinearExtension::rand(num_vars, rng);
+        let mut split_eq = SplitEqPolynomial::new(&point);
+        let mut claim = eq.iter().zip(g.iter()).map(|(e, g)| *e * g).sum::<Fr>();
+        for round in 0..num_vars {
+            // `s(X) = sum_x' eq(r, (c, X, x')) * g(c, X, x')` of degree 2.
+            let s: Vec<Fr> = (0..3u64)
+                .map(|x| {
+                    let (eq, g) = (eq.fix_variables(&[x.into()]), g.fix_variables(&[x.into()]));
+                    eq.iter().zip(g.iter()).map(|(e, g)| *e * g).sum()
+                })
+                .collect();
+            assert_eq!(s[0] + s[1], claim);
+
+            let (low, high) = split_eq.remaining_tables();
+            let remaining = EqPolynomial::new(point[round + 1..].to_vec()).evaluations();
+            assert_eq!(low.len() * high.len(), remaining.len());
+            for (x, e) in remaining.iter().enumerate() {
+                assert_eq!(low[x % low.len()] * high[x / low.len()], *e);
+            }
+            let q_0: Fr = g
+                .fix_variables(&[Fr::zero()])
+                .iter()
+                .zip(&remaining)
+                .map(|(g, e)| *g * e)
+                .sum();
+            assert_eq!(split_eq.round_polynomial(&[q_0], claim), s);
+
+            let challenge = Fr::rand(rng);
+            split_eq.bind(challenge);
+            eq = eq.fix_variables(&[challenge]);
+            g = g.fix_variables(&[challenge]);
+            claim = crate::sumcheck::interpolate_at(&s, challenge);
+        }
+        assert_eq!(split_eq.round(), num_vars);
+        assert_eq!(split_eq.bound_scalar(), eq[0]);
+        assert_eq!(claim, eq[0] * g[0]);
+    }
+}
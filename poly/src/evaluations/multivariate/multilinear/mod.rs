@@ -1,8 +1,16 @@
 mod dense;
+mod eq;
 mod sparse;
+mod stream;
+mod univariate;
 
 pub use dense::DenseMultilinearExtension;
+pub use eq::{EqPolynomial, SplitEqPolynomial};
 pub use sparse::SparseMultilinearExtension;
+pub(crate) use stream::FixedVariables;
+pub use stream::{
+    IterableMultilinearExtension, OracleMultilinearExtension, StreamingMultilinearExtension,
+};
 
 use ark_std::{
     fmt::Debug,
@@ -1,7 +1,7 @@
 //! multilinear polynomial represented in sparse evaluation form.
 
 use crate::{
-    evaluations::multivariate::multilinear::swap_bits, DenseMultilinearExtension,
+    evaluations::multivariate::multilinear::swap_bits, DenseMultilinearExtension, EqPolynomial,
     MultilinearExtension, Polynomial,
 };
 use ark_ff::{Field, Zero};
@@ -31,6 +31,9 @@ pub struct SparseMultilinearExtension<F: Field> {
     zero: F,
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: Field] SparseMultilinearExtension<F>);
+
 impl<F: Field> SparseMultilinearExtension<F> {
     pub fn from_evaluations<'a>(
         num_vars: usize,
@@ -95,22 +98,6 @@ impl<F: Field> SparseMultilinearExtension<F> {
     }
 }
 
-/// utility: precompute f(x) = eq(g,x)
-fn precompute_eq<F: Field>(g: &[F]) -> Vec<F> {
-    let dim = g.len();
-    let mut dp = vec![F::zero(); 1 << dim];
-    dp[0] = F::one() - g[0];
-    dp[1] = g[0];
-    for i in 1..dim {
-        for b in 0..(1 << i) {
-            let prev = dp[b];
-            dp[b + (1 << i)] = prev * g[i];
-            dp[b] = prev - dp[b + (1 << i)];
-        }
-    }
-    dp
-}
-
 impl<F: Field> MultilinearExtension<F> for SparseMultilinearExtension<F> {
     fn num_vars(&self) -> usize {
         self.num_vars
@@ -168,7 +155,7 @@ impl<F: Field> MultilinearExtension<F> for SparseMultilinearExtension<F> {
             };
             let focus = &point[..focus_length];
             point = &point[focus_length..];
-            let pre = precompute_eq(focus);
+            let pre = EqPolynomial::new(focus.to_vec()).evaluations();
             let dim = focus.len();
             let mut result =
                 HashMap::with_hasher(core::hash::BuildHasherDefault::<DefaultHasher>::default());
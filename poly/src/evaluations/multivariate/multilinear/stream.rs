@@ -0,0 +1,213 @@
+//! Multilinear extensions whose evaluations are streamed rather than stored.
+//!
+//! A [`StreamingMultilinearExtension`] produces its `2^n` evaluations over
+//! the boolean hypercube in index order on each pass, so that it can be
+//! evaluated, or have some of its variables fixed, in memory logarithmic in
+//! the number of evaluations, as [`crate::sumcheck::SumcheckProof::prove_streaming`]
+//! does round by round.
+
+use crate::DenseMultilinearExtension;
+use ark_ff::Field;
+use ark_std::{borrow::Borrow, iterable::Iterable, vec::*};
+
+/// A multilinear extension in `n` variables whose evaluations over the
+/// boolean hypercube are streamed, in little-endian index order, from an
+/// iterator or an oracle.
+pub trait StreamingMultilinearExtension<F: Field> {
+    /// Returns the number of variables.
+    fn stream_num_vars(&self) -> usize;
+
+    /// Returns a stream of the `2^n` evaluations, in index order.
+    fn stream(&self) -> impl Iterator<Item = F> + '_;
+
+    /// Evaluates `self` at `point` in a single pass over [`Self::stream`],
+    /// with `O(n)` field elements of memory.
+    fn stream_evaluate(&self, point: &[F]) -> F {
+        assert_eq!(
+            point.len(),
+            self.stream_num_vars(),
+            "the point has the wrong size"
+        );
+        FixedVariables::new(self.stream(), point).next().unwrap()
+    }
+
+    /// Fixes the lowest `partial_point.len()` variables of `self`, as
+    /// [`crate::MultilinearExtension::fix_variables`], in a single pass over
+    /// [`Self::stream`].
+    ///
+    /// Only the `2^(n - k)` evaluations of the result are stored, where `k`
+    /// is the size of `partial_point`.
+    fn stream_fix_variables(&self, partial_point: &[F]) -> DenseMultilinearExtension<F> {
+        assert!(
+            partial_point.len() <= self.stream_num_vars(),
+            "invalid size of partial point"
+        );
+        DenseMultilinearExtension::from_evaluations_vec(
+            self.stream_num_vars() - partial_point.len(),
+            FixedVariables::new(self.stream(), partial_point).collect(),
+        )
+    }
+}
+
+impl<F: Field> StreamingMultilinearExtension<F> for DenseMultilinearExtension<F> {
+    fn stream_num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn stream(&self) -> impl Iterator<Item = F> + '_ {
+        self.evaluations.iter().copied()
+    }
+}
+
+/// A multilinear extension whose evaluations are read from an [`Iterable`],
+/// such as a slice or a file-backed stream, on each pass.
+#[derive(Clone, Debug)]
+pub struct IterableMultilinearExtension<I> {
+    num_vars: usize,
+    evaluations: I,
+}
+
+impl<I: Iterable> IterableMultilinearExtension<I> {
+    /// Constructs the multilinear extension in `num_vars` variables with the
+    /// evaluations `evaluations`, in index order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `evaluations` does not have `2^num_vars` items.
+    pub fn new(num_vars: usize, evaluations: I) -> Self {
+        assert_eq!(
+            evaluations.len(),
+            1 << num_vars,
+            "The size of evaluations should be 2^num_vars."
+        );
+        Self {
+            num_vars,
+            evaluations,
+        }
+    }
+}
+
+impl<F: Field, I> StreamingMultilinearExtension<F> for IterableMultilinearExtension<I>
+where
+    I: Iterable,
+    I::Item: Borrow<F>,
+{
+    fn stream_num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn stream(&self) -> impl Iterator<Item = F> + '_ {
+        self.evaluations.iter().map(|e| *e.borrow())
+    }
+}
+
+/// A multilinear extension whose evaluation at the `i`-th point of the
+/// boolean hypercube is computed by an oracle on each pass, and never
+/// stored.
+#[derive(Clone, Debug)]
+pub struct OracleMultilinearExtension<O> {
+    num_vars: usize,
+    oracle: O,
+}
+
+impl<O> OracleMultilinearExtension<O> {
+    /// Constructs the multilinear extension in `num_vars` variables whose
+    /// evaluation at the index `i` is `oracle(i)`.
+    pub const fn new(num_vars: usize, oracle: O) -> Self {
+        Self { num_vars, oracle }
+    }
+}
+
+impl<F: Field, O: Fn(usize) -> F> StreamingMultilinearExtension<F>
+    for OracleMultilinearExtension<O>
+{
+    fn stream_num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn stream(&self) -> impl Iterator<Item = F> + '_ {
+        (0..1 << self.num_vars).map(&self.oracle)
+    }
+}
+
+/// The evaluations, in index order, of a stream of evaluations with its
+/// lowest variables fixed to a point.
+///
+/// Each evaluation is folded with its sibling as soon as both are known, so
+/// that at most one pending evaluation per fixed variable is kept.
+pub(crate) struct FixedVariables<'a, I, F> {
+    stream: I,
+    point: &'a [F],
+    pending: Vec<Option<F>>,
+}
+
+impl<'a, I: Iterator<Item = F>, F: Field> FixedVariables<'a, I, F> {
+    pub(crate) fn new(stream: I, point: &'a [F]) -> Self {
+        Self {
+            stream,
+            point,
+            pending: vec![None; point.len()],
+        }
+    }
+}
+
+impl<I: Iterator<Item = F>, F: Field> Iterator for FixedVariables<'_, I, F> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        for mut value in self.stream.by_ref() {
+            let mut level = 0;
+            loop {
+                if level == self.point.len() {
+                    return Some(value);
+                }
+                match self.pending[level].take() {
+                    None => {
+                        self.pending[level] = Some(value);
+                        break;
+                    },
+                    Some(low) => {
+                        value = low + self.point[level] * (value - low);
+                        level += 1;
+                    },
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DenseMultilinearExtension, IterableMultilinearExtension, MultilinearExtension,
+        OracleMultilinearExtension, Polynomial, StreamingMultilinearExtension,
+    };
+    use ark_ff::UniformRand;
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn streaming_matches_dense() {
+        let rng = &mut test_rng();
+        for num_vars in [0, 1, 6] {
+            let dense = DenseMultilinearExtension::<Fr>::rand(num_vars, rng);
+            let iterable =
+                IterableMultilinearExtension::new(num_vars, dense.evaluations.as_slice());
+            let oracle = OracleMultilinearExtension::new(num_vars, |i| dense.evaluations[i]);
+
+            let point: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+            let expected = dense.evaluate(&point);
+            assert_eq!(dense.stream_evaluate(&point), expected);
+            assert_eq!(iterable.stream_evaluate(&point), expected);
+            assert_eq!(oracle.stream_evaluate(&point), expected);
+
+            for k in 0..=num_vars {
+                let expected = dense.fix_variables(&point[..k]);
+                assert_eq!(dense.stream_fix_variables(&point[..k]), expected);
+                assert_eq!(iterable.stream_fix_variables(&point[..k]), expected);
+                assert_eq!(oracle.stream_fix_variables(&point[..k]), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Conversions between multilinear extensions and univariate polynomials.
+//!
+//! The evaluations of a multilinear extension `f` over the boolean hypercube
+//! are identified with the coefficients of the univariate polynomial
+//! `U(f)(X) = sum_i f(i) X^i`, as in Gemini and ZeroMorph. Binding the lowest
+//! variable of `f` to `u` then folds `U(f) = f_e(X^2) + X f_o(X^2)` into
+//! `(1 - u) f_e + u f_o`.
+
+use crate::{
+    univariate::DensePolynomial, DenseMultilinearExtension, DenseUVPolynomial, EvaluationDomain,
+    Evaluations,
+};
+use ark_ff::{FftField, Field};
+use ark_std::vec::*;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl<F: Field> DenseMultilinearExtension<F> {
+    /// Returns the coefficients of `self` in the multilinear monomial basis,
+    /// where the coefficient of index `b` is the one of `prod_{i : b_i = 1} x_i`.
+    ///
+    /// They are computed from the evaluations by a Möbius transform over the
+    /// boolean hypercube, in `O(n 2^n)`.
+    pub fn to_monomial_coefficients(&self) -> Vec<F> {
+        let mut coeffs = self.evaluations.clone();
+        for i in 0..self.num_vars {
+            cfg_chunks_mut!(coeffs, 2 << i).for_each(|chunk| {
+                let (low, high) = chunk.split_at_mut(1 << i);
+                high.iter_mut().zip(low).for_each(|(h, l)| *h -= *l);
+            });
+        }
+        coeffs
+    }
+
+    /// Constructs the multilinear extension in `num_vars` variables with
+    /// coefficients `coeffs` in the multilinear monomial basis, as returned by
+    /// [`Self::to_monomial_coefficients`].
+    pub fn from_monomial_coefficients(num_vars: usize, mut coeffs: Vec<F>) -> Self {
+        assert_eq!(
+            coeffs.len(),
+            1 << num_vars,
+            "the number of coefficients does not match the number of variables"
+        );
+        for i in 0..num_vars {
+            cfg_chunks_mut!(coeffs, 2 << i).for_each(|chunk| {
+                let (low, high) = chunk.split_at_mut(1 << i);
+                high.iter_mut().zip(low).for_each(|(h, l)| *h += *l);
+            });
+        }
+        Self::from_evaluations_vec(num_vars, coeffs)
+    }
+
+    /// Returns the univariate polynomial `U(f)(X) = sum_i f(i) X^i` whose
+    /// coefficients are the evaluations of `self`.
+    pub fn to_univariate(&self) -> DensePolynomial<F> {
+        DensePolynomial::from_coefficients_slice(&self.evaluations)
+    }
+
+    /// Constructs the multilinear extension in `num_vars` variables whose
+    /// evaluations are the coefficients of `poly`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `poly` has more than `2^num_vars` coefficients.
+    pub fn from_univariate(num_vars: usize, poly: &DensePolynomial<F>) -> Self {
+        assert!(
+            poly.coeffs.len() <= 1 << num_vars,
+            "the polynomial has too many coefficients"
+        );
+        let mut evaluations = poly.coeffs.clone();
+        evaluations.resize(1 << num_vars, F::zero());
+        Self::from_evaluations_vec(num_vars, evaluations)
+    }
+
+    /// Returns the univariate polynomials `U(f_0), ..., U(f_k)` of Gemini,
+    /// where `f_0 = self` and `f_{j + 1}` is `f_j` with its lowest variable
+    /// bound to `point[j]`, so that `U(f_k)` is the constant `f(point)` if
+    /// `point` has `n` coordinates.
+    pub fn gemini_folds(&self, point: &[F]) -> Vec<DensePolynomial<F>> {
+        assert!(
+            point.len() <= self.num_vars,
+            "the point has too many coordinates"
+        );
+        let mut folds = Vec::with_capacity(point.len() + 1);
+        folds.push(self.to_univariate());
+        for u in point {
+            let fold = folds.last().unwrap().gemini_fold(*u);
+            folds.push(fold);
+        }
+        folds
+    }
+}
+
+impl<F: FftField> DenseMultilinearExtension<F> {
+    /// Evaluates `U(f)(X) = sum_i f(i) X^i` over `domain`, which may be
+    /// smaller than the number of evaluations of `self`.
+    pub fn evaluate_univariate_over_domain<D: EvaluationDomain<F>>(
+        &self,
+        domain: D,
+    ) -> Evaluations<F, D> {
+        self.to_univariate().evaluate_over_domain_by_ref(domain)
+    }
+}
+
+impl<F: Field> DensePolynomial<F> {
+    /// Folds `self = f_e(X^2) + X f_o(X^2)` into `(1 - u) f_e + u f_o`,
+    /// which binds the lowest variable of the multilinear extension with the
+    /// coefficients of `self` as evaluations to `u`.
+    pub fn gemini_fold(&self, u: F) -> Self {
+        Self::from_coefficients_vec(
+            cfg_chunks!(self.coeffs, 2)
+                .map(|pair| {
+                    let (even, odd) = (pair[0], pair.get(1).copied().unwrap_or_default());
+                    even + u * (odd - even)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the evaluation at `beta^2` of the fold of `f` by `u`, given
+    /// the evaluations of `f` at `beta` and `-beta`, as checked by the
+    /// verifier of Gemini.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `beta` is zero or if the characteristic is two.
+    pub fn gemini_fold_evaluation(u: F, beta: F, eval_at_beta: F, eval_at_minus_beta: F) -> F {
+        let two_inv = F::from(2u64).inverse().expect("the characteristic is two");
+        let beta_inv = beta.inverse().expect("beta is zero");
+        let even = (eval_at_beta + eval_at_minus_beta) * two_inv;
+        let odd = (eval_at_beta - eval_at_minus_beta) * two_inv * beta_inv;
+        even + u * (odd - even)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        univariate::DensePolynomial, DenseMultilinearExtension, EvaluationDomain,
+        GeneralEvaluationDomain, MultilinearExtension, Polynomial,
+    };
+    use ark_ff::{FftField, Field, UniformRand};
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn monomial_coefficients() {
+        let rng = &mut test_rng();
+        for num_vars in [0, 1, 5] {
+            let mle = DenseMultilinearExtension::<Fr>::rand(num_vars, rng);
+            let coeffs = mle.to_monomial_coefficients();
+            let point: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+            let expected: Fr = coeffs
+                .iter()
+                .enumerate()
+                .map(|(b, c)| {
+                    (0..num_vars)
+                        .filter(|i| b >> i & 1 == 1)
+                        .fold(*c, |acc, i| acc * point[i])
+                })
+                .sum();
+            assert_eq!(mle.evaluate(&point), expected);
+            assert_eq!(
+                DenseMultilinearExtension::from_monomial_coefficients(num_vars, coeffs),
+                mle
+            );
+        }
+    }
+
+    #[test]
+    fn univariate_and_gemini() {
+        let rng = &mut test_rng();
+        let num_vars = 6;
+        let mle = DenseMultilinearExtension::<Fr>::rand(num_vars, rng);
+        let poly = mle.to_univariate();
+        assert_eq!(
+            DenseMultilinearExtension::from_univariate(num_vars, &poly),
+            mle
+        );
+
+        let point: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+        let folds = mle.gemini_folds(&point);
+        assert_eq!(folds.len(), num_vars + 1);
+        for (j, fold) in folds.iter().enumerate() {
+            let bound = mle.fix_variables(&point[..j]);
+            assert_eq!(*fold, bound.to_univariate());
+        }
+        assert_eq!(folds[num_vars].coeffs, [mle.evaluate(&point)]);
+
+        let beta = Fr::rand(rng);
+        for (j, u) in point.iter().enumerate() {
+            assert_eq!(
+                DensePolynomial::gemini_fold_evaluation(
+                    *u,
+                    beta,
+                    folds[j].evaluate(&beta),
+                    folds[j].evaluate(&-beta)
+                ),
+                folds[j + 1].evaluate(&beta.square())
+            );
+        }
+
+        let domain = GeneralEvaluationDomain::<Fr>::new(128).unwrap();
+        assert_eq!(
+            mle.evaluate_univariate_over_domain(domain),
+            poly.evaluate_over_domain(domain)
+        );
+    }
+
+    #[test]
+    fn evaluate_univariate_over_small_domain() {
+        let rng = &mut test_rng();
+        let mle = DenseMultilinearExtension::<Fr>::rand(6, rng);
+        let poly = mle.to_univariate();
+        for size in [1, 8, 32] {
+            let domain = GeneralEvaluationDomain::<Fr>::new(size).unwrap();
+            for domain in [domain, domain.get_coset(Fr::GENERATOR).unwrap()] {
+                let expected: Vec<Fr> = domain.elements().map(|x| poly.evaluate(&x)).collect();
+                assert_eq!(mle.evaluate_univariate_over_domain(domain).evals, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Primitives of FRI-style protocols on polynomials in evaluation form:
+//! even/odd folding, bit-reversed layouts and quotients by `X - z`.
+
+use crate::{domain::utils::bitreverse, EvaluationDomain, Evaluations, Polynomial};
+use ark_ff::{batch_inversion, FftField, Field};
+use ark_std::vec::*;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Returns the domain `{x^2 : x in domain}`, of half the size of `domain`.
+fn folded_domain<F: FftField, D: EvaluationDomain<F>>(domain: D) -> D {
+    let size = domain.size();
+    // In characteristic two, `x = -x` and the evaluations cannot be paired.
+    assert!(
+        !F::from(2u64).is_zero(),
+        "only domains over fields of odd characteristic can be folded"
+    );
+    assert!(
+        size >= 2 && size % 2 == 0,
+        "only domains of even size can be folded"
+    );
+    D::new(size / 2)
+        .and_then(|d| d.get_coset(domain.coset_offset().square()))
+        .filter(|d| d.size() == size / 2 && d.group_gen() == domain.group_gen().square())
+        .expect("the domain has no folded domain of the same type")
+}
+
+/// Permutes `values` from natural to bit-reversed order, or back.
+fn bit_reverse_permute<T>(values: &mut [T]) {
+    let size = values.len();
+    assert!(
+        size.is_power_of_two(),
+        "bit reversal requires a power-of-two size"
+    );
+    let log_size = size.trailing_zeros();
+    for i in 0..size {
+        let j = bitreverse(i as u32, log_size) as usize;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+/// Returns `(a + b) / 2 + challenge * (a - b) / (2x)`, which is the
+/// evaluation at `x^2` of `f_e + challenge * f_o` if `a = f(x)` and
+/// `b = f(-x)`, where `f(X) = f_e(X^2) + X f_o(X^2)`.
+#[inline]
+fn fold_pair<F: Field>(a: F, b: F, half_x_inv: F, half: F, challenge: F) -> F {
+    (a + b) * half + challenge * (a - b) * half_x_inv
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> Evaluations<F, D> {
+    /// Folds `self`, the evaluations of `f(X) = f_e(X^2) + X f_o(X^2)` over
+    /// a coset `g * H`, into the evaluations of `f_e + challenge * f_o` over
+    /// `g^2 * H^2`, as in a round of FRI.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field has characteristic two, if the size of the domain
+    /// is odd, or if `D` has no domain of half its size whose generator is the
+    /// square of its generator.
+    pub fn fold(&self, challenge: F) -> Self {
+        let domain = self.domain();
+        let folded = folded_domain(domain);
+        let half_size = folded.size();
+        let half = F::one().double().inverse().unwrap();
+        // `x_{i + n/2} = -x_i`, so that the pairs are `n/2` apart.
+        let mut half_x_inv: Vec<F> = domain.elements().take(half_size).collect();
+        batch_inversion(&mut half_x_inv);
+        let (low, high) = self.evals.split_at(half_size);
+        let evals = cfg_iter!(low)
+            .zip(high)
+            .zip(half_x_inv)
+            .map(|((a, b), x_inv)| fold_pair(*a, *b, x_inv * half, half, challenge))
+            .collect();
+        Self::from_vec_and_domain(evals, folded)
+    }
+
+    /// Folds the evaluations `evals` over `domain`, given in bit-reversed
+    /// order, and returns the folded evaluations in bit-reversed order along
+    /// with the folded domain.
+    ///
+    /// In bit-reversed order, the evaluations at `x` and `-x` are adjacent,
+    /// so that each round of FRI reads contiguous pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `domain` is not a power of two or does not
+    /// match `evals`, or in the same cases as [`Self::fold`].
+    pub fn fold_bit_reversed(evals: &[F], domain: D, challenge: F) -> (Vec<F>, D) {
+        assert_eq!(
+            evals.len(),
+            domain.size(),
+            "evaluations do not match the domain"
+        );
+        let folded = folded_domain(domain);
+        let half = F::one().double().inverse().unwrap();
+        let mut half_x_inv: Vec<F> = domain.elements().take(folded.size()).collect();
+        bit_reverse_permute(&mut half_x_inv);
+        batch_inversion(&mut half_x_inv);
+        // The element at the even position `2i` in bit-reversed order is the
+        // element at position `i` of the first half in bit-reversed order.
+        let evals = cfg_chunks!(evals, 2)
+            .zip(half_x_inv)
+            .map(|(pair, x_inv)| fold_pair(pair[0], pair[1], x_inv * half, half, challenge))
+            .collect();
+        (evals, folded)
+    }
+
+    /// Returns the evaluations of `self` in bit-reversed order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the domain is not a power of two.
+    pub fn to_bit_reversed(&self) -> Vec<F> {
+        let mut evals = self.evals.clone();
+        bit_reverse_permute(&mut evals);
+        evals
+    }
+
+    /// Constructs `Self` from evaluations over `domain` in bit-reversed
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `evals` is not a power of two.
+    pub fn from_bit_reversed(mut evals: Vec<F>, domain: D) -> Self {
+        bit_reverse_permute(&mut evals);
+        Self::from_vec_and_domain(evals, domain)
+    }
+
+    /// Evaluates the interpolant of `self` at `point`, with the Lagrange
+    /// coefficients of the domain.
+    pub fn evaluate(&self, point: &F) -> F {
+        let coefficients = self.domain().evaluate_all_lagrange_coefficients(*point);
+        cfg_iter!(self.evals)
+            .zip(coefficients)
+            .map(|(e, c)| *e * c)
+            .sum()
+    }
+
+    /// Returns the evaluations of `(f - value) / (X - point)`, where `f` is
+    /// the interpolant of `self` and `value = f(point)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `point` is in the domain.
+    pub fn quotient(&self, point: F, value: F) -> Self {
+        let domain = self.domain();
+        let mut denominators: Vec<F> = domain.elements().map(|x| x - point).collect();
+        assert!(
+            !denominators.iter().any(F::is_zero),
+            "the point must lie outside of the domain"
+        );
+        batch_inversion(&mut denominators);
+        let evals = cfg_iter!(self.evals)
+            .zip(denominators)
+            .map(|(e, d)| (*e - value) * d)
+            .collect();
+        Self::from_vec_and_domain(evals, domain)
+    }
+
+    /// Returns whether the interpolant of `self` has degree less than
+    /// `degree_bound`.
+    pub fn is_low_degree(&self, degree_bound: usize) -> bool {
+        let poly = self.interpolate_by_ref();
+        poly.coeffs.is_empty() || poly.degree() < degree_bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, Evaluations,
+        GeneralEvaluationDomain, Polynomial, Radix2EvaluationDomain,
+    };
+    use ark_ff::{FftField, UniformRand};
+    use ark_std::{test_rng, vec};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// Returns `f_e + challenge * f_o`, where `f(X) = f_e(X^2) + X f_o(X^2)`.
+    fn fold_coefficients(poly: &DensePolynomial<Fr>, challenge: Fr) -> DensePolynomial<Fr> {
+        DensePolynomial::from_coefficients_vec(
+            poly.coeffs
+                .chunks(2)
+                .map(|c| c[0] + challenge * c.get(1).copied().unwrap_or_default())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn fold() {
+        let rng = &mut test_rng();
+        let domain = Radix2EvaluationDomain::<Fr>::new(64)
+            .unwrap()
+            .get_coset(Fr::GENERATOR)
+            .unwrap();
+        let poly = DensePolynomial::<Fr>::rand(15, rng);
+        let evals = poly.evaluate_over_domain_by_ref(domain);
+        let challenge = Fr::rand(rng);
+        let folded = evals.fold(challenge);
+        let expected = fold_coefficients(&poly, challenge);
+        assert_eq!(folded.domain().size(), 32);
+        assert_eq!(
+            folded.domain().coset_offset(),
+            Fr::GENERATOR * Fr::GENERATOR
+        );
+        assert_eq!(
+            folded,
+            expected.evaluate_over_domain_by_ref(folded.domain())
+        );
+
+        // Folding in bit-reversed order commutes with the permutation.
+        let (bit_reversed, folded_domain) =
+            Evaluations::fold_bit_reversed(&evals.to_bit_reversed(), domain, challenge);
+        assert_eq!(folded_domain, folded.domain());
+        assert_eq!(bit_reversed, folded.to_bit_reversed());
+        assert_eq!(
+            Evaluations::from_bit_reversed(bit_reversed, folded_domain),
+            folded
+        );
+
+        // Folding repeatedly leaves a constant.
+        let mut evals = evals;
+        for _ in 0..4 {
+            evals = evals.fold(Fr::rand(rng));
+        }
+        assert!(evals.is_low_degree(1));
+        assert!(evals.evals.iter().all(|e| *e == evals.evals[0]));
+    }
+
+    #[test]
+    fn quotient_and_low_degree() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(48).unwrap();
+        let poly = DensePolynomial::<Fr>::rand(20, rng);
+        let evals = poly.evaluate_over_domain_by_ref(domain);
+        let point = Fr::rand(rng);
+        let value = evals.evaluate(&point);
+        assert_eq!(value, poly.evaluate(&point));
+        assert!(evals.is_low_degree(21));
+        assert!(!evals.is_low_degree(20));
+
+        let quotient = evals.quotient(point, value);
+        assert!(quotient.is_low_degree(20));
+        let divisor = DensePolynomial::from_coefficients_vec(vec![-point, Fr::from(1u64)]);
+        assert_eq!(
+            quotient.interpolate(),
+            &(&poly - &DensePolynomial::from_coefficients_vec(vec![value])) / &divisor
+        );
+
+        // A wrong value does not give a low-degree quotient.
+        assert!(!evals
+            .quotient(point, value + Fr::from(1u64))
+            .is_low_degree(20));
+    }
+}
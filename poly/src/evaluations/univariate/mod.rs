@@ -13,6 +13,8 @@ use ark_std::{
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+mod fri;
+
 /// Stores a UV polynomial in evaluation form.
 #[derive(Clone, PartialEq, Eq, Hash, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Evaluations<F: FftField, D: EvaluationDomain<F> = GeneralEvaluationDomain<F>> {
@@ -22,6 +24,9 @@ pub struct Evaluations<F: FftField, D: EvaluationDomain<F> = GeneralEvaluationDo
     domain: D,
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: FftField, D: EvaluationDomain<F>] Evaluations<F, D>);
+
 impl<F: FftField, D: EvaluationDomain<F>> Evaluations<F, D> {
     /// Evaluations of the zero polynomial over `domain`.
     pub fn zero(domain: D) -> Self {
@@ -14,17 +14,25 @@ pub mod domain;
 
 pub mod evaluations;
 pub mod polynomial;
+pub mod reed_solomon;
+pub mod sumcheck;
 
 pub use domain::{
-    EvaluationDomain, GeneralEvaluationDomain, MixedRadixEvaluationDomain, Radix2EvaluationDomain,
+    AdditiveEvaluationDomain, BluesteinEvaluationDomain, CircleEvaluationDomain, EvaluationDomain,
+    GeneralEvaluationDomain, MixedRadixEvaluationDomain, Radix2EvaluationDomain,
 };
 pub use evaluations::{
+    circle::CircleEvaluations,
     multivariate::multilinear::{
-        DenseMultilinearExtension, MultilinearExtension, SparseMultilinearExtension,
+        DenseMultilinearExtension, EqPolynomial, IterableMultilinearExtension,
+        MultilinearExtension, OracleMultilinearExtension, SparseMultilinearExtension,
+        SplitEqPolynomial, StreamingMultilinearExtension,
     },
     univariate::Evaluations,
 };
-pub use polynomial::{multivariate, univariate, DenseMVPolynomial, DenseUVPolynomial, Polynomial};
+pub use polynomial::{
+    circle, multivariate, univariate, DenseMVPolynomial, DenseUVPolynomial, Polynomial,
+};
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,335 @@
+//! Polynomials on the circle curve `x^2 + y^2 = 1`, in the basis of the
+//! circle FFT.
+
+use crate::{
+    domain::circle::{CircleEvaluationDomain, CirclePoint},
+    CircleEvaluations, Polynomial,
+};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    rand::Rng,
+    vec::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A polynomial on the circle `x^2 + y^2 = 1`, stored as its coefficients in
+/// the basis of the circle FFT of [HLP24](https://eprint.iacr.org/2024/278).
+///
+/// The `j`-th basis element is `y^{j_0} x^{j_1} π(x)^{j_2} π^2(x)^{j_3} ...`,
+/// where `j_k` is the `k`-th bit of `j` and `π(x) = 2x^2 - 1`. A polynomial
+/// with at most `2^n` coefficients is determined by its evaluations over a
+/// twin coset of size `2^n`.
+#[derive(Clone, PartialEq, Eq, Hash, Default, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CirclePolynomial<F: Field> {
+    /// The coefficients of the polynomial in the circle FFT basis.
+    pub coeffs: Vec<F>,
+}
+
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: Field] CirclePolynomial<F>);
+
+impl<F: Field> CirclePolynomial<F> {
+    /// Constructs a new polynomial from a list of coefficients.
+    pub fn from_coefficients_slice(coeffs: &[F]) -> Self {
+        Self::from_coefficients_vec(coeffs.to_vec())
+    }
+
+    /// Constructs a new polynomial from a list of coefficients.
+    pub fn from_coefficients_vec(coeffs: Vec<F>) -> Self {
+        let mut result = Self { coeffs };
+        result.truncate_leading_zeros();
+        result
+    }
+
+    /// Outputs a random polynomial with `2^log_size` coefficients.
+    pub fn rand<R: Rng>(log_size: u32, rng: &mut R) -> Self {
+        Self::from_coefficients_vec((0..1 << log_size).map(|_| F::rand(rng)).collect())
+    }
+
+    fn truncate_leading_zeros(&mut self) {
+        while self.coeffs.last().is_some_and(|c| c.is_zero()) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+impl<F: PrimeField> CirclePolynomial<F> {
+    /// Evaluates `self` over `domain`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has more coefficients than `domain` has points.
+    pub fn evaluate_over_domain_by_ref(
+        &self,
+        domain: CircleEvaluationDomain<F>,
+    ) -> CircleEvaluations<F> {
+        CircleEvaluations::from_vec_and_domain(domain.fft(&self.coeffs), domain)
+    }
+
+    /// Evaluates `self` over `domain`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has more coefficients than `domain` has points.
+    pub fn evaluate_over_domain(self, domain: CircleEvaluationDomain<F>) -> CircleEvaluations<F> {
+        let mut coeffs = self.coeffs;
+        domain.fft_in_place(&mut coeffs);
+        CircleEvaluations::from_vec_and_domain(coeffs, domain)
+    }
+}
+
+impl<F: Field> Polynomial<F> for CirclePolynomial<F> {
+    type Point = CirclePoint<F>;
+
+    /// Returns the total degree of the polynomial in `x` and `y`, which is
+    /// `j_0 + floor(j / 2)` for the `j`-th basis element.
+    fn degree(&self) -> usize {
+        self.coeffs
+            .len()
+            .checked_sub(1)
+            .map_or(0, |j| (j & 1) + (j >> 1))
+    }
+
+    /// Evaluates `self` at `point` by folding the coefficients along the
+    /// factors of the basis, from the last one to `y`.
+    fn evaluate(&self, point: &CirclePoint<F>) -> F {
+        if self.coeffs.is_empty() {
+            return F::zero();
+        }
+        let log_size = self.coeffs.len().next_power_of_two().trailing_zeros() as usize;
+        let mut factors = Vec::with_capacity(log_size);
+        let mut x = point.x;
+        for k in 0..log_size {
+            match k {
+                0 => factors.push(point.y),
+                1 => factors.push(x),
+                _ => {
+                    x = CirclePoint::double_x(x);
+                    factors.push(x);
+                },
+            }
+        }
+        let mut folded = self.coeffs.clone();
+        folded.resize(1 << log_size, F::zero());
+        for (k, factor) in factors.iter().enumerate().rev() {
+            let (lo, hi) = folded.split_at_mut(1 << k);
+            lo.iter_mut().zip(&*hi).for_each(|(a, b)| *a += *b * factor);
+        }
+        folded[0]
+    }
+}
+
+impl<F: Field> Zero for CirclePolynomial<F> {
+    fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<'a, F: Field> Add<&'a CirclePolynomial<F>> for &CirclePolynomial<F> {
+    type Output = CirclePolynomial<F>;
+
+    fn add(self, other: &'a CirclePolynomial<F>) -> CirclePolynomial<F> {
+        let mut result = self.clone();
+        result += other;
+        result
+    }
+}
+
+impl<F: Field> Add for CirclePolynomial<F> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += &other;
+        self
+    }
+}
+
+impl<'a, F: Field> AddAssign<&'a Self> for CirclePolynomial<F> {
+    fn add_assign(&mut self, other: &'a Self) {
+        *self += (F::one(), other);
+    }
+}
+
+impl<'a, F: Field> AddAssign<(F, &'a Self)> for CirclePolynomial<F> {
+    fn add_assign(&mut self, (f, other): (F, &'a Self)) {
+        if self.coeffs.len() < other.coeffs.len() {
+            self.coeffs.resize(other.coeffs.len(), F::zero());
+        }
+        ark_std::cfg_iter_mut!(self.coeffs)
+            .zip(&other.coeffs)
+            .for_each(|(a, b)| *a += f * b);
+        self.truncate_leading_zeros();
+    }
+}
+
+impl<'a, F: Field> Sub<&'a CirclePolynomial<F>> for &CirclePolynomial<F> {
+    type Output = CirclePolynomial<F>;
+
+    fn sub(self, other: &'a CirclePolynomial<F>) -> CirclePolynomial<F> {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<'a, F: Field> SubAssign<&'a Self> for CirclePolynomial<F> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += (-F::one(), other);
+    }
+}
+
+impl<F: Field> Neg for CirclePolynomial<F> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.coeffs.iter_mut().for_each(|c| *c = -*c);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        circle::CirclePolynomial,
+        domain::circle::{CircleEvaluationDomain, CirclePoint},
+        CircleEvaluations, Polynomial,
+    };
+    use ark_ff::{One, PrimeField, Zero};
+    use ark_std::{test_rng, vec::*, UniformRand};
+    use ark_test_curves::{bls12_381::Fr, small_fields::Mersenne31};
+
+    type F = Mersenne31;
+
+    /// Evaluates the basis element of index `j` at `point`.
+    fn basis(j: usize, point: &CirclePoint<F>) -> F {
+        let mut result = if j & 1 == 1 { point.y } else { F::one() };
+        let mut x = point.x;
+        for k in 1..usize::BITS as usize {
+            if (j >> k) & 1 == 1 {
+                result *= x;
+            }
+            x = CirclePoint::double_x(x);
+        }
+        result
+    }
+
+    #[test]
+    fn subgroup_generators() {
+        let g = CirclePoint::<F>::subgroup_gen(31).unwrap();
+        assert!(g.is_on_circle());
+        assert_ne!(g.repeated_double(30), CirclePoint::identity());
+        assert_eq!(g.repeated_double(31), CirclePoint::identity());
+        assert!(CirclePoint::<F>::subgroup_gen(32).is_none());
+        assert_eq!(
+            CirclePoint::<F>::subgroup_gen(5).unwrap(),
+            g.repeated_double(26)
+        );
+        assert_eq!(g + -g, CirclePoint::identity());
+        assert_eq!(g.mul_bigint([3u64]), g.double() + g);
+        assert_eq!(g.antipode(), g + CirclePoint::<F>::subgroup_gen(1).unwrap());
+        // The circle group of BLS12-381's scalar field has order `p - 1`.
+        let g = CirclePoint::<Fr>::subgroup_gen(32).unwrap();
+        assert!(g.is_on_circle());
+        assert_ne!(g.repeated_double(31), CirclePoint::identity());
+    }
+
+    #[test]
+    fn domains() {
+        for log_size in 1..10 {
+            let domain = CircleEvaluationDomain::<F>::new(log_size).unwrap();
+            assert!(domain.is_standard_position());
+            let elements: Vec<_> = domain.elements().collect();
+            assert_eq!(elements.len(), domain.size());
+            for (i, p) in elements.iter().enumerate() {
+                assert!(p.is_on_circle());
+                assert_eq!(*p, domain.element(i));
+                assert!(!elements[..i].contains(p));
+                assert!(domain.evaluate_vanishing_polynomial(p).is_zero());
+                // The standard position coset is closed under conjugation.
+                assert!(elements.contains(&p.conjugate()));
+            }
+            let point = CirclePoint::<F>::subgroup_gen(31).unwrap();
+            assert!(!domain.evaluate_vanishing_polynomial(&point).is_zero());
+        }
+        assert!(CircleEvaluationDomain::<F>::new(0).is_none());
+        assert!(CircleEvaluationDomain::<F>::new(31).is_none());
+        let g = CirclePoint::<F>::subgroup_gen(8).unwrap();
+        assert!(CircleEvaluationDomain::new_twin_coset(g, 8).is_none());
+        assert!(CircleEvaluationDomain::new_twin_coset(g, 64).is_none());
+        assert!(CircleEvaluationDomain::new_twin_coset(g, u32::MAX).is_none());
+        let twin = CircleEvaluationDomain::new_twin_coset(g, 7).unwrap();
+        assert!(twin.is_standard_position());
+        let twin = CircleEvaluationDomain::new_twin_coset(g.double(), 3).unwrap();
+        assert!(!twin.is_standard_position());
+    }
+
+    #[test]
+    fn evaluate_matches_basis() {
+        let rng = &mut test_rng();
+        let poly = CirclePolynomial::<F>::rand(6, rng);
+        let point = CirclePoint::<F>::subgroup_gen(31)
+            .unwrap()
+            .mul_bigint([12345u64]);
+        let expected: F = poly
+            .coeffs
+            .iter()
+            .enumerate()
+            .map(|(j, c)| *c * basis(j, &point))
+            .sum();
+        assert_eq!(poly.evaluate(&point), expected);
+        assert_eq!(poly.degree(), 32);
+    }
+
+    #[test]
+    fn fft_and_interpolation() {
+        let rng = &mut test_rng();
+        for log_size in 1..10 {
+            let initial = CirclePoint::<F>::subgroup_gen(31)
+                .unwrap()
+                .mul_bigint([F::rand(rng).into_bigint().0[0] | 1]);
+            for domain in [
+                CircleEvaluationDomain::<F>::new(log_size).unwrap(),
+                CircleEvaluationDomain::new_twin_coset(initial, log_size).unwrap(),
+            ] {
+                for poly_log_size in [0, log_size.saturating_sub(2), log_size] {
+                    let poly = CirclePolynomial::<F>::rand(poly_log_size, rng);
+                    let evals = poly.evaluate_over_domain_by_ref(domain);
+                    for (p, e) in domain.elements().zip(&evals.evals) {
+                        assert_eq!(poly.evaluate(&p), *e);
+                    }
+                    assert_eq!(evals.interpolate(), poly);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn evaluations_arithmetic() {
+        let rng = &mut test_rng();
+        let domain = CircleEvaluationDomain::<F>::new(6).unwrap();
+        let a = CirclePolynomial::<F>::rand(5, rng);
+        let b = CirclePolynomial::<F>::rand(6, rng);
+        let (ea, eb) = (
+            a.evaluate_over_domain_by_ref(domain),
+            b.evaluate_over_domain_by_ref(domain),
+        );
+        assert_eq!((&ea + &eb).interpolate(), &a + &b);
+        assert_eq!((&ea - &eb).interpolate(), &a - &b);
+        let product = &ea * &eb;
+        for (i, p) in domain.elements().enumerate() {
+            assert_eq!(product[i], a.evaluate(&p) * b.evaluate(&p));
+        }
+        assert_eq!(
+            CircleEvaluations::zero(domain).interpolate(),
+            CirclePolynomial::zero()
+        );
+    }
+}
@@ -9,6 +9,7 @@ use ark_std::{
     vec::*,
 };
 
+pub mod circle;
 pub mod multivariate;
 pub mod univariate;
 
@@ -27,6 +27,9 @@ pub struct SparsePolynomial<F: Field, T: Term> {
     pub terms: Vec<(F, T)>,
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: Field, T: Term] SparsePolynomial<F, T>);
+
 impl<F: Field, T: Term> SparsePolynomial<F, T> {
     fn remove_zeros(&mut self) {
         self.terms.retain(|(c, _)| !c.is_zero());
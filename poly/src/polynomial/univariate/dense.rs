@@ -1,6 +1,9 @@
 //! A dense univariate polynomial represented in coefficient form.
 use crate::{
-    univariate::{DenseOrSparsePolynomial, SparsePolynomial},
+    univariate::{
+        gcd::{from_prime_field, to_prime_field},
+        DenseOrSparsePolynomial, SparsePolynomial,
+    },
     DenseUVPolynomial, EvaluationDomain, Evaluations, GeneralEvaluationDomain, Polynomial,
 };
 use ark_ff::{FftField, Field, Zero};
@@ -24,6 +27,9 @@ pub struct DensePolynomial<F: Field> {
     pub coeffs: Vec<F>,
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: Field] DensePolynomial<F>);
+
 impl<F: Field> Polynomial<F> for DensePolynomial<F> {
     type Point = F;
 
@@ -216,6 +222,14 @@ impl<F: Field> DensePolynomial<F> {
         }
     }
 
+    /// Returns the reversal `x^d * self(1/x)` of `self`, where `d` is the
+    /// degree of `self`.
+    pub fn reverse(&self) -> Self {
+        let mut coeffs = self.coeffs.clone();
+        coeffs.reverse();
+        Self::from_coefficients_vec(coeffs)
+    }
+
     /// Perform a naive n^2 multiplication of `self` by `other`.
     pub fn naive_mul(&self, other: &Self) -> Self {
         if self.is_zero() || other.is_zero() {
@@ -232,6 +246,101 @@ impl<F: Field> DensePolynomial<F> {
     }
 }
 
+/// The degree above which [`DensePolynomial::div_rem`] is faster than long
+/// division, for both the divisor and the quotient.
+const DIV_REM_THRESHOLD: usize = 64;
+
+impl<F: FftField> DensePolynomial<F> {
+    /// Returns the inverse of `self` modulo `x^n`, computed with Newton's
+    /// iteration in `O(M(n))` operations, where `M(n)` is the cost of
+    /// multiplying polynomials of degree `n`.
+    ///
+    /// Returns `None` if the constant coefficient of `self` is zero and `n` is
+    /// positive. Every polynomial is zero modulo `x^0`, so the zero polynomial
+    /// is returned when `n` is zero.
+    pub fn inverse_mod_xn(&self, n: usize) -> Option<Self> {
+        if n == 0 {
+            return Some(Self::zero());
+        }
+        let mut inverse = Self::from_coefficients_vec(vec![self.coeffs.first()?.inverse()?]);
+        let mut k = 1;
+        while k < n {
+            // If `g = 1/f mod x^k`, then `g - g * (f * g - 1) = 1/f mod x^2k`.
+            k = (2 * k).min(n);
+            let f = Self::from_coefficients_slice(&self.coeffs[..k.min(self.coeffs.len())]);
+            let mut error = f.fast_or_naive_mul(&inverse);
+            error.coeffs.truncate(k);
+            error.coeffs[0] -= F::one();
+            let error = Self::from_coefficients_vec(error.coeffs);
+            let mut correction = inverse.fast_or_naive_mul(&error);
+            correction.coeffs.truncate(k);
+            inverse -= &Self::from_coefficients_vec(correction.coeffs);
+        }
+        Some(inverse)
+    }
+
+    /// Divides `self` by `divisor`, and returns the quotient and remainder.
+    ///
+    /// The reversal of the quotient is computed as the product of the
+    /// reversal of `self` with the inverse of the reversal of `divisor`
+    /// modulo `x^(deg(self) - deg(divisor) + 1)`, so that the division takes
+    /// `O(M(n))` operations instead of the `O(n^2)` of long division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "Dividing by zero polynomial");
+        // The reversal of the divisor must have a nonzero constant coefficient.
+        if divisor.coeffs.last().is_some_and(|c| c.is_zero()) {
+            return self.div_rem(&Self::from_coefficients_slice(&divisor.coeffs));
+        }
+        if self.coeffs.len() < divisor.coeffs.len() {
+            return (Self::zero(), self.clone());
+        }
+        let quotient_len = self.coeffs.len() - divisor.coeffs.len() + 1;
+        let inverse = divisor.reverse().inverse_mod_xn(quotient_len).unwrap();
+        let reversed: Vec<F> = self
+            .coeffs
+            .iter()
+            .rev()
+            .take(quotient_len)
+            .copied()
+            .collect();
+        let mut reversed = Self::from_coefficients_vec(reversed)
+            .fast_or_naive_mul(&inverse)
+            .coeffs;
+        reversed.resize(quotient_len, F::zero());
+        reversed.reverse();
+        let quotient = Self::from_coefficients_vec(reversed);
+        let remainder = self - &divisor.fast_or_naive_mul(&quotient);
+        (quotient, remainder)
+    }
+
+    /// Divides `self` by `divisor` with [`Self::div_rem`] when both the
+    /// divisor and the quotient are large, and with long division otherwise.
+    pub(crate) fn divide(&self, divisor: &Self) -> (Self, Self) {
+        let quotient_len = (self.coeffs.len() + 1).saturating_sub(divisor.coeffs.len());
+        if divisor.coeffs.len().min(quotient_len) > DIV_REM_THRESHOLD {
+            return self.div_rem(divisor);
+        }
+        let a = DenseOrSparsePolynomial::from(self);
+        let b = DenseOrSparsePolynomial::from(divisor);
+        a.divide_with_q_and_r(&b).expect("division failed")
+    }
+
+    /// Multiplies with FFTs if `F` has a large enough domain, and naively
+    /// otherwise.
+    pub(crate) fn fast_or_naive_mul(&self, other: &Self) -> Self {
+        let len = (self.coeffs.len() + other.coeffs.len()).saturating_sub(1);
+        if GeneralEvaluationDomain::<F>::compute_size_of_domain(len).is_some() {
+            self * other
+        } else {
+            self.naive_mul(other)
+        }
+    }
+}
+
 impl<F: FftField> DensePolynomial<F> {
     /// Evaluate `self` over `domain`.
     pub fn evaluate_over_domain_by_ref<D: EvaluationDomain<F>>(
@@ -585,11 +694,16 @@ impl<'a, F: Field> SubAssign<&'a SparsePolynomial<F>> for DensePolynomial<F> {
     }
 }
 
+/// Over prime fields, uses [`DensePolynomial::div_rem`] when both the divisor
+/// and the quotient are large, and long division otherwise.
 impl<'a, F: Field> Div<&'a DensePolynomial<F>> for &DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
     #[inline]
     fn div(self, divisor: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        if let (Some(a), Some(b)) = (to_prime_field(self), to_prime_field(divisor)) {
+            return from_prime_field(a.divide(&b).0);
+        }
         let a = DenseOrSparsePolynomial::from(self);
         let b = DenseOrSparsePolynomial::from(divisor);
         a.divide_with_q_and_r(&b).expect("division failed").0
@@ -896,6 +1010,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn div_rem_matches_long_division() {
+        let rng = &mut test_rng();
+        for (a_degree, b_degree) in [(0, 0), (5, 7), (10, 3), (100, 1), (300, 150), (1000, 999)] {
+            let dividend = DensePolynomial::<Fr>::rand(a_degree, rng);
+            let divisor = DensePolynomial::<Fr>::rand(b_degree, rng);
+            let expected = DenseOrSparsePolynomial::divide_with_q_and_r(
+                &(&dividend).into(),
+                &(&divisor).into(),
+            )
+            .unwrap();
+            assert_eq!(dividend.div_rem(&divisor), expected);
+            assert_eq!(&dividend / &divisor, expected.0);
+        }
+    }
+
+    #[test]
+    fn div_rem_unnormalized_divisor() {
+        let rng = &mut test_rng();
+        let dividend = DensePolynomial::<Fr>::rand(300, rng);
+        let divisor = DensePolynomial::<Fr>::rand(150, rng);
+        let mut unnormalized = divisor.clone();
+        unnormalized.coeffs.resize(200, Fr::zero());
+        assert_eq!(dividend.div_rem(&unnormalized), dividend.div_rem(&divisor));
+        assert_eq!(&dividend / &unnormalized, &dividend / &divisor);
+    }
+
+    #[test]
+    fn divide_polynomials_extension_field() {
+        use ark_ff::Field;
+        use ark_test_curves::bls12_381::Fq2;
+
+        fn div<F: Field>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+            a / b
+        }
+
+        let rng = &mut test_rng();
+        let quotient = DensePolynomial::<Fq2>::rand(200, rng);
+        let divisor = DensePolynomial::<Fq2>::rand(150, rng);
+        let remainder = DensePolynomial::<Fq2>::rand(100, rng);
+        let dividend = &quotient.naive_mul(&divisor) + &remainder;
+        assert_eq!(div(&dividend, &divisor), quotient);
+    }
+
+    #[test]
+    #[should_panic(expected = "Dividing by zero polynomial")]
+    fn div_rem_by_zero() {
+        let dividend = DensePolynomial::<Fr>::rand(300, &mut test_rng());
+        let zero = DensePolynomial {
+            coeffs: vec![Fr::zero(); 100],
+        };
+        let _ = &dividend / &zero;
+    }
+
+    #[test]
+    fn inverse_mod_xn() {
+        let rng = &mut test_rng();
+        for (degree, n) in [(0, 1), (3, 10), (100, 37), (200, 513)] {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let inverse = p.inverse_mod_xn(n).unwrap();
+            assert!(inverse.coeffs.len() <= n);
+            let mut product = (&p * &inverse).coeffs;
+            product.truncate(n);
+            assert_eq!(
+                DensePolynomial::from_coefficients_vec(product),
+                DensePolynomial::from_coefficients_slice(&[Fr::one()])
+            );
+        }
+        let p = DensePolynomial::from_coefficients_slice(&[Fr::zero(), Fr::one()]);
+        assert_eq!(p.inverse_mod_xn(4), None);
+        assert_eq!(p.inverse_mod_xn(0), Some(DensePolynomial::zero()));
+        let p = DensePolynomial::<Fr>::rand(5, rng);
+        assert_eq!(p.inverse_mod_xn(0), Some(DensePolynomial::zero()));
+    }
+
+    #[test]
+    fn reverse() {
+        let p =
+            DensePolynomial::from_coefficients_slice(&[Fr::from(1u64), Fr::zero(), Fr::from(3u64)]);
+        let expected =
+            DensePolynomial::from_coefficients_slice(&[Fr::from(3u64), Fr::zero(), Fr::from(1u64)]);
+        assert_eq!(p.reverse(), expected);
+        let p = DensePolynomial::from_coefficients_slice(&[Fr::zero(), Fr::from(2u64)]);
+        assert_eq!(
+            p.reverse(),
+            DensePolynomial::from_coefficients_slice(&[Fr::from(2u64)])
+        );
+    }
+
     #[test]
     fn evaluate_polynomials() {
         let rng = &mut test_rng();
@@ -1008,6 +1211,7 @@ mod tests {
                 .elements()
                 .map(|e| poly.evaluate(&e))
                 .collect::<Vec<_>>();
+            assert_eq!(evaluations, poly.evaluate_over_domain_by_ref(coset).evals);
             assert_eq!(evaluations, poly.evaluate_over_domain(coset).evals);
         }
         let zero = DensePolynomial::zero();
@@ -1421,4 +1625,26 @@ mod tests {
         // Ensure the polynomial is not zero (as it has non-zero coefficients)
         assert!(!poly1.is_zero());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use crate::EvaluationDomain;
+
+        let rng = &mut test_rng();
+        let poly = DensePolynomial::<Fr>::rand(10, rng);
+        let json = serde_json::to_string(&poly).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DensePolynomial<Fr>>(&json).unwrap(),
+            poly
+        );
+
+        let domain = GeneralEvaluationDomain::<Fr>::new(16).unwrap();
+        let evals = poly.evaluate_over_domain_by_ref(domain);
+        let json = serde_json::to_string(&evals).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Evaluations<Fr>>(&json).unwrap(),
+            evals
+        );
+    }
 }
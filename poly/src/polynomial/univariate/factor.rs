@@ -0,0 +1,343 @@
+//! Root finding and factorization of dense univariate polynomials over finite
+//! fields, with the Cantor–Zassenhaus algorithm.
+
+use crate::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+use ark_ff::{BitIteratorBE, FftField, Field, Zero};
+use ark_std::{
+    rand::{rngs::StdRng, Rng, SeedableRng},
+    vec,
+    vec::*,
+};
+
+/// Arithmetic modulo a fixed nonconstant polynomial, where reductions are
+/// multiplications with a precomputed inverse instead of divisions.
+struct Modulus<F: FftField> {
+    modulus: DensePolynomial<F>,
+    /// The inverse of the reversal of `modulus`, modulo `x^precision`.
+    inverse: DensePolynomial<F>,
+    precision: usize,
+}
+
+impl<F: FftField> Modulus<F> {
+    fn new(modulus: &DensePolynomial<F>) -> Self {
+        // The quotient of a product of two reduced polynomials by `modulus`
+        // has at most `deg(modulus) - 1` coefficients.
+        let precision = modulus.degree();
+        let inverse = modulus.reverse().inverse_mod_xn(precision).unwrap();
+        Self {
+            modulus: modulus.clone(),
+            inverse,
+            precision,
+        }
+    }
+
+    fn reduce(&self, poly: DensePolynomial<F>) -> DensePolynomial<F> {
+        if poly.coeffs.len() < self.modulus.coeffs.len() {
+            return poly;
+        }
+        let quotient_len = poly.coeffs.len() - self.modulus.coeffs.len() + 1;
+        if quotient_len > self.precision {
+            return poly.divide(&self.modulus).1;
+        }
+        let reversed: Vec<F> = poly
+            .coeffs
+            .iter()
+            .rev()
+            .take(quotient_len)
+            .copied()
+            .collect();
+        let inverse = DensePolynomial::from_coefficients_slice(
+            &self.inverse.coeffs[..quotient_len.min(self.inverse.coeffs.len())],
+        );
+        let mut quotient = DensePolynomial::from_coefficients_vec(reversed)
+            .fast_or_naive_mul(&inverse)
+            .coeffs;
+        quotient.resize(quotient_len, F::zero());
+        quotient.reverse();
+        let quotient = DensePolynomial::from_coefficients_vec(quotient);
+        &poly - &self.modulus.fast_or_naive_mul(&quotient)
+    }
+
+    fn mul(&self, a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+        self.reduce(a.fast_or_naive_mul(b))
+    }
+
+    /// Returns `base^exponent`, for an exponent given by its little-endian
+    /// `u64` limbs.
+    fn pow(&self, base: &DensePolynomial<F>, exponent: &[u64]) -> DensePolynomial<F> {
+        let mut result = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+        for bit in BitIteratorBE::without_leading_zeros(exponent) {
+            result = self.mul(&result, &result);
+            if bit {
+                result = self.mul(&result, base);
+            }
+        }
+        result
+    }
+
+    /// Returns `base^q`, where `q` is the size of `F`.
+    fn frobenius(&self, base: &DensePolynomial<F>) -> DensePolynomial<F> {
+        (0..F::extension_degree()).fold(base.clone(), |acc, _| self.pow(&acc, F::characteristic()))
+    }
+}
+
+fn is_characteristic_two<F: Field>() -> bool {
+    F::characteristic()[0] == 2 && F::characteristic()[1..].iter().all(|l| *l == 0)
+}
+
+fn monic<F: FftField>(poly: &DensePolynomial<F>) -> DensePolynomial<F> {
+    poly * poly.coeffs.last().unwrap().inverse().unwrap()
+}
+
+/// Returns `a^((q^d - 1) / 2) - 1` in odd characteristic and the trace
+/// `a + a^2 + ... + a^(2^(kd - 1))` in characteristic two, where `F` has
+/// `q = p^k` elements.
+///
+/// For a random `a`, the gcd of this polynomial with a product of
+/// irreducible polynomials of degree `d` is a nontrivial factor with
+/// probability at least one half.
+fn splitting_polynomial<F: FftField>(
+    modulus: &Modulus<F>,
+    a: &DensePolynomial<F>,
+    degree: usize,
+) -> DensePolynomial<F> {
+    let steps = F::extension_degree() as usize * degree;
+    let mut power = a.clone();
+    let mut result = a.clone();
+    if is_characteristic_two::<F>() {
+        for _ in 1..steps {
+            power = modulus.mul(&power, &power);
+            result += &power;
+        }
+        result
+    } else {
+        // `(q^d - 1) / 2 = (1 + p + ... + p^(kd - 1)) (p - 1) / 2`.
+        for _ in 1..steps {
+            power = modulus.pow(&power, F::characteristic());
+            result = modulus.mul(&result, &power);
+        }
+        let characteristic = F::characteristic();
+        let half: Vec<u64> = (0..characteristic.len())
+            .map(|i| (characteristic[i] >> 1) | (characteristic.get(i + 1).map_or(0, |l| l << 63)))
+            .collect();
+        &modulus.pow(&result, &half) - &DensePolynomial::from_coefficients_vec(vec![F::one()])
+    }
+}
+
+/// Splits the monic square-free `poly`, whose irreducible factors all have
+/// degree `degree`, into these factors.
+fn split<F: FftField, R: Rng>(
+    poly: DensePolynomial<F>,
+    degree: usize,
+    rng: &mut R,
+    factors: &mut Vec<DensePolynomial<F>>,
+) {
+    if poly.degree() == degree {
+        factors.push(poly);
+        return;
+    }
+    if degree == 1
+        && poly.degree() == 2
+        && !is_characteristic_two::<F>()
+        && F::SQRT_PRECOMP.is_some()
+    {
+        // The roots of `x^2 + bx + c` are `(-b ± sqrt(b^2 - 4c)) / 2`.
+        let (c, b) = (poly.coeffs[0], poly.coeffs[1]);
+        let sqrt = (b.square() - c.double().double()).sqrt().unwrap();
+        let two_inv = F::one().double().inverse().unwrap();
+        for root in [(sqrt - b) * two_inv, (-sqrt - b) * two_inv] {
+            factors.push(DensePolynomial::from_coefficients_vec(vec![
+                -root,
+                F::one(),
+            ]));
+        }
+        return;
+    }
+    let modulus = Modulus::new(&poly);
+    loop {
+        let a = DensePolynomial::rand(poly.degree() - 1, rng);
+        let factor = poly.gcd(&splitting_polynomial(&modulus, &a, degree));
+        if factor.degree() > 0 && factor.degree() < poly.degree() {
+            let cofactor = &poly / &factor;
+            split(factor, degree, rng, factors);
+            split(cofactor, degree, rng, factors);
+            return;
+        }
+    }
+}
+
+impl<F: FftField> DensePolynomial<F> {
+    /// Splits the square-free polynomial `self` into the products of its
+    /// monic irreducible factors of each degree, and returns the nonconstant
+    /// products along with their degree, by increasing degree.
+    ///
+    /// The product of the irreducible factors of degree `d` is the gcd of
+    /// `self` with `x^(q^d) - x`, where `q` is the size of `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        assert!(!self.is_zero(), "the zero polynomial has no factorization");
+        let x = Self::from_coefficients_vec(vec![F::zero(), F::one()]);
+        let mut rest = monic(self);
+        let mut frobenius = x.clone();
+        let mut factors = Vec::new();
+        let mut degree = 0;
+        while rest.degree() >= 2 * (degree + 1) {
+            degree += 1;
+            let modulus = Modulus::new(&rest);
+            frobenius = modulus.frobenius(&modulus.reduce(frobenius));
+            let factor = rest.gcd(&(&frobenius - &x));
+            if factor.degree() > 0 {
+                rest = &rest / &factor;
+                factors.push((factor, degree));
+            }
+        }
+        if rest.degree() > 0 {
+            let degree = rest.degree();
+            factors.push((rest, degree));
+        }
+        factors
+    }
+
+    /// Splits the square-free polynomial `self`, whose irreducible factors
+    /// all have degree `degree`, into these monic factors, with the
+    /// Cantor–Zassenhaus algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, or if `degree` is zero or does not divide
+    /// the degree of `self`.
+    pub fn equal_degree_factorization<R: Rng>(&self, degree: usize, rng: &mut R) -> Vec<Self> {
+        assert!(!self.is_zero(), "the zero polynomial has no factorization");
+        assert!(
+            degree > 0 && self.degree() % degree == 0,
+            "the degree of the factors must divide the degree of the polynomial"
+        );
+        let mut factors = Vec::new();
+        if self.degree() > 0 {
+            split(monic(self), degree, rng, &mut factors);
+        }
+        factors
+    }
+
+    /// Returns the distinct roots of `self` in `F`, in no particular order.
+    ///
+    /// The roots are those of `gcd(self, x^q - x)`, where `q` is the size of
+    /// `F`, which is split into linear factors with
+    /// [`Self::equal_degree_factorization`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn roots(&self) -> Vec<F> {
+        assert!(!self.is_zero(), "the zero polynomial vanishes everywhere");
+        if self.degree() == 0 {
+            return Vec::new();
+        }
+        let x = Self::from_coefficients_vec(vec![F::zero(), F::one()]);
+        let modulus = Modulus::new(self);
+        let linear = self.gcd(&(&modulus.frobenius(&modulus.reduce(x.clone())) - &x));
+        // The random choices only affect the running time, so a fixed seed is
+        // used.
+        let mut rng = StdRng::seed_from_u64(0);
+        linear
+            .equal_degree_factorization(1, &mut rng)
+            .into_iter()
+            .map(|factor| -factor.coeffs[0])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+    use ark_ff::{binary::BinaryField128b, FftField, Field, One, UniformRand};
+    use ark_std::{test_rng, vec, vec::*};
+    use ark_test_curves::bls12_381::{Fq2, Fq6, Fr};
+
+    fn from_roots<F: FftField>(roots: &[F]) -> DensePolynomial<F> {
+        roots.iter().fold(
+            DensePolynomial::from_coefficients_vec(vec![F::one()]),
+            |acc, r| acc.naive_mul(&DensePolynomial::from_coefficients_vec(vec![-*r, F::one()])),
+        )
+    }
+
+    fn check_roots<F: FftField>(num_roots: usize, extra_degree: usize) {
+        let rng = &mut test_rng();
+        let mut expected: Vec<F> = (0..num_roots).map(|_| F::rand(rng)).collect();
+        if num_roots > 1 {
+            expected.push(expected[0]);
+        }
+        let poly = from_roots(&expected).naive_mul(&DensePolynomial::rand(extra_degree, rng));
+        let roots = poly.roots();
+        for root in &roots {
+            assert!(poly.evaluate(root).is_zero());
+        }
+        for (i, root) in roots.iter().enumerate() {
+            assert!(!roots[..i].contains(root));
+        }
+        for root in &expected {
+            assert!(roots.contains(root));
+        }
+    }
+
+    #[test]
+    fn roots() {
+        check_roots::<Fr>(0, 5);
+        check_roots::<Fr>(1, 0);
+        check_roots::<Fr>(2, 0);
+        check_roots::<Fr>(20, 10);
+        check_roots::<Fr>(100, 0);
+        check_roots::<Fq2>(10, 3);
+        check_roots::<Fq6>(4, 2);
+        check_roots::<BinaryField128b>(10, 3);
+        assert!(DensePolynomial::from_coefficients_vec(vec![Fr::one()])
+            .roots()
+            .is_empty());
+    }
+
+    #[test]
+    fn distinct_and_equal_degree_factorization() {
+        let rng = &mut test_rng();
+        let poly = DensePolynomial::<Fr>::rand(30, rng);
+        let monic = &poly * poly.coeffs.last().unwrap().inverse().unwrap();
+        let mut product = DensePolynomial::from_coefficients_vec(vec![Fr::one()]);
+        let mut total_degree = 0;
+        for (factor, degree) in poly.distinct_degree_factorization() {
+            assert_eq!(factor.degree() % degree, 0);
+            let factors = factor.equal_degree_factorization(degree, rng);
+            assert_eq!(factors.len(), factor.degree() / degree);
+            for irreducible in factors {
+                assert_eq!(irreducible.degree(), degree);
+                assert_eq!(irreducible.coeffs.last(), Some(&Fr::one()));
+                product = &product * &irreducible;
+            }
+            total_degree += factor.degree();
+        }
+        assert_eq!(total_degree, 30);
+        assert_eq!(product, monic);
+
+        // The product of two quadratics without roots has no linear factors.
+        let irreducible = |rng: &mut _| loop {
+            let q = DensePolynomial::<Fr>::from_coefficients_vec(vec![
+                Fr::rand(rng),
+                Fr::rand(rng),
+                Fr::one(),
+            ]);
+            if q.roots().is_empty() {
+                return q;
+            }
+        };
+        let (a, b) = (irreducible(rng), irreducible(rng));
+        let product = &a * &b;
+        assert_eq!(
+            product.distinct_degree_factorization(),
+            vec![(product.clone(), 2)]
+        );
+        let mut factors = product.equal_degree_factorization(2, rng);
+        factors.sort_by_key(|f| f != &a);
+        assert_eq!(factors, vec![a, b]);
+    }
+}
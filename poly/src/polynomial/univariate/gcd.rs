@@ -0,0 +1,522 @@
+//! Greatest common divisors, Bézout coefficients and resultants of dense
+//! univariate polynomials, with the half-GCD algorithm.
+//!
+//! The algorithms work over any field. Over prime fields, polynomials are
+//! multiplied with FFTs and divided with Newton's iteration when they are
+//! large enough, and schoolbook multiplication and long division are used
+//! otherwise.
+
+use crate::{
+    univariate::{DenseOrSparsePolynomial, DensePolynomial},
+    DenseUVPolynomial, Polynomial,
+};
+use ark_ff::{FftField, Field, Zero};
+use ark_std::{marker::PhantomData, vec, vec::*};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The degree below which remainder sequences are computed one division at
+/// a time instead of with the half-GCD recursion.
+const HALF_GCD_THRESHOLD: usize = 64;
+
+/// The multiplication and division of polynomials used by the remainder
+/// sequences.
+trait Arithmetic<F: Field> {
+    fn mul(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F>;
+
+    fn divide(
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+    ) -> (DensePolynomial<F>, DensePolynomial<F>);
+}
+
+/// Schoolbook multiplication and long division, over any field.
+struct Naive;
+
+impl<F: Field> Arithmetic<F> for Naive {
+    fn mul(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+        a.naive_mul(b)
+    }
+
+    fn divide(
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+    ) -> (DensePolynomial<F>, DensePolynomial<F>) {
+        DenseOrSparsePolynomial::from(a)
+            .divide_with_q_and_r(&DenseOrSparsePolynomial::from(b))
+            .expect("division failed")
+    }
+}
+
+/// FFT multiplication and Newton division, for large enough polynomials
+/// over FFT-friendly fields.
+struct Fast;
+
+impl<F: FftField> Arithmetic<F> for Fast {
+    fn mul(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+        a.fast_or_naive_mul(b)
+    }
+
+    fn divide(
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+    ) -> (DensePolynomial<F>, DensePolynomial<F>) {
+        a.divide(b)
+    }
+}
+
+/// A 2x2 matrix of polynomials, which maps a pair of consecutive remainders
+/// of the Euclidean algorithm to a later pair.
+struct Transform<F: Field, A>([[DensePolynomial<F>; 2]; 2], PhantomData<A>);
+
+impl<F: Field, A: Arithmetic<F>> Transform<F, A> {
+    fn identity() -> Self {
+        let one = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+        Self(
+            [
+                [one.clone(), DensePolynomial::zero()],
+                [DensePolynomial::zero(), one],
+            ],
+            PhantomData,
+        )
+    }
+
+    /// Returns the transform of the division step `(a, b) -> (b, a - q * b)`.
+    fn step(quotient: &DensePolynomial<F>) -> Self {
+        let one = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+        Self(
+            [
+                [DensePolynomial::zero(), one.clone()],
+                [one, -quotient.clone()],
+            ],
+            PhantomData,
+        )
+    }
+
+    /// Returns the transform that applies `other`, then `self`.
+    fn compose(&self, other: &Self) -> Self {
+        let [[a, b], [c, d]] = &self.0;
+        let [[e, f], [g, h]] = &other.0;
+        Self(
+            [
+                [&A::mul(a, e) + &A::mul(b, g), &A::mul(a, f) + &A::mul(b, h)],
+                [&A::mul(c, e) + &A::mul(d, g), &A::mul(c, f) + &A::mul(d, h)],
+            ],
+            PhantomData,
+        )
+    }
+
+    fn apply(
+        &self,
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+    ) -> (DensePolynomial<F>, DensePolynomial<F>) {
+        let [[m00, m01], [m10, m11]] = &self.0;
+        (
+            &A::mul(m00, a) + &A::mul(m01, b),
+            &A::mul(m10, a) + &A::mul(m11, b),
+        )
+    }
+}
+
+/// Returns `poly / x^k`, discarding the remainder.
+fn shift<F: Field>(poly: &DensePolynomial<F>, k: usize) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_slice(&poly.coeffs[k.min(poly.coeffs.len())..])
+}
+
+/// Runs the Euclidean algorithm on `(a, b)` until the first remainder of
+/// degree less than `ceil(deg(a) / 2)`, and returns the quotients of the
+/// division steps along with the transform that they compose to.
+///
+/// `a` must be nonzero, and of degree at least `deg(b)`.
+fn half_gcd<F: Field, A: Arithmetic<F>>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (Vec<DensePolynomial<F>>, Transform<F, A>) {
+    let m = a.degree().div_ceil(2);
+    if b.is_zero() || b.degree() < m {
+        return (Vec::new(), Transform::identity());
+    }
+    if a.degree() < HALF_GCD_THRESHOLD {
+        let mut quotients = Vec::new();
+        let mut transform = Transform::identity();
+        let (mut a, mut b) = (a.clone(), b.clone());
+        while !b.is_zero() && b.degree() >= m {
+            let (q, r) = A::divide(&a, &b);
+            transform = Transform::step(&q).compose(&transform);
+            quotients.push(q);
+            a = ark_std::mem::replace(&mut b, r);
+        }
+        return (quotients, transform);
+    }
+
+    // The quotients of the first half of the sequence only depend on the
+    // high coefficients of `a` and `b`.
+    let (mut quotients, first) = half_gcd(&shift(a, m), &shift(b, m));
+    let (a, b) = first.apply(a, b);
+    if b.is_zero() || b.degree() < m {
+        return (quotients, first);
+    }
+    let (q, r) = A::divide(&a, &b);
+    let transform = Transform::step(&q).compose(&first);
+    quotients.push(q);
+    let k = 2 * m - b.degree();
+    let (rest, second) = half_gcd(&shift(&b, k), &shift(&r, k));
+    quotients.extend(rest);
+    (quotients, second.compose(&transform))
+}
+
+/// Runs the Euclidean algorithm on `(a, b)`, and returns the quotients of
+/// its division steps, the transform that they compose to, and the last
+/// nonzero remainder.
+///
+/// `a` must be nonzero, and of degree at least `deg(b)`.
+fn remainder_sequence<F: Field, A: Arithmetic<F>>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (Vec<DensePolynomial<F>>, Transform<F, A>, DensePolynomial<F>) {
+    let mut quotients = Vec::new();
+    let mut transform = Transform::identity();
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while !b.is_zero() {
+        if b.degree() >= HALF_GCD_THRESHOLD && 2 * b.degree() > a.degree() {
+            let (qs, step) = half_gcd(&a, &b);
+            quotients.extend(qs);
+            (a, b) = step.apply(&a, &b);
+            transform = step.compose(&transform);
+        } else {
+            let (q, r) = A::divide(&a, &b);
+            transform = Transform::step(&q).compose(&transform);
+            quotients.push(q);
+            a = ark_std::mem::replace(&mut b, r);
+        }
+    }
+    (quotients, transform, a)
+}
+
+/// Returns the monic greatest common divisor of `lhs` and `rhs`, along with
+/// their Bézout coefficients, as in [`DensePolynomial::xgcd`].
+fn xgcd<F: Field, A: Arithmetic<F>>(
+    lhs: &DensePolynomial<F>,
+    rhs: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>) {
+    if lhs.is_zero() && rhs.is_zero() {
+        return (
+            DensePolynomial::zero(),
+            DensePolynomial::zero(),
+            DensePolynomial::zero(),
+        );
+    }
+    let swap = lhs.is_zero() || (!rhs.is_zero() && lhs.degree() < rhs.degree());
+    let (a, b) = if swap { (rhs, lhs) } else { (lhs, rhs) };
+    let (_, transform, gcd) = remainder_sequence::<F, A>(a, b);
+    let leading_inverse = gcd.coeffs.last().unwrap().inverse().unwrap();
+    let [[s, t], _] = transform.0;
+    let (s, t) = (&s * leading_inverse, &t * leading_inverse);
+    let gcd = &gcd * leading_inverse;
+    if swap {
+        (gcd, t, s)
+    } else {
+        (gcd, s, t)
+    }
+}
+
+/// Returns the resultant of `lhs` and `rhs`, as in
+/// [`DensePolynomial::resultant`].
+fn resultant<F: Field, A: Arithmetic<F>>(lhs: &DensePolynomial<F>, rhs: &DensePolynomial<F>) -> F {
+    if lhs.is_zero() || rhs.is_zero() {
+        return F::zero();
+    }
+    // `res(a, b) = (-1)^(deg(a) deg(b)) res(b, a)`.
+    let (a, b) = if lhs.degree() < rhs.degree() {
+        (rhs, lhs)
+    } else {
+        (lhs, rhs)
+    };
+    let mut negate = lhs.degree() < rhs.degree() && a.degree() * b.degree() % 2 == 1;
+    let (quotients, ..) = remainder_sequence::<F, A>(a, b);
+
+    // The remainder `r_i` has degree `deg(r_{i-1}) - deg(q_i)` and leading
+    // coefficient `lc(r_{i-1}) / lc(q_i)`, with `r_0 = a`.
+    let mut degrees = vec![a.degree()];
+    let mut leading = vec![*a.coeffs.last().unwrap()];
+    for q in &quotients {
+        degrees.push(degrees.last().unwrap() - q.degree());
+        leading.push(*leading.last().unwrap() / q.coeffs.last().unwrap());
+    }
+    let k = quotients.len();
+    if degrees[k] > 0 {
+        return F::zero();
+    }
+
+    // `res(r_{i-1}, r_i) = (-1)^(d_{i-1} d_i) lc(r_i)^(d_{i-1} - d_{i+1})
+    // res(r_i, r_{i+1})`, and the last remainder is a nonzero constant.
+    let mut result = leading[k].pow([degrees[k - 1] as u64]);
+    for i in 1..k {
+        negate ^= degrees[i - 1] * degrees[i] % 2 == 1;
+        result *= leading[i].pow([(degrees[i - 1] - degrees[i + 1]) as u64]);
+    }
+    if negate {
+        -result
+    } else {
+        result
+    }
+}
+
+/// Returns `poly` as a polynomial over the base prime field of `F`, if `F` is
+/// that prime field, so that the arithmetic of [`Fast`] applies.
+pub(crate) fn to_prime_field<F: Field>(
+    poly: &DensePolynomial<F>,
+) -> Option<DensePolynomial<F::BasePrimeField>> {
+    (F::extension_degree() == 1).then(|| {
+        DensePolynomial::from_coefficients_vec(
+            poly.coeffs
+                .iter()
+                .map(|c| c.to_base_prime_field_elements().next().unwrap())
+                .collect(),
+        )
+    })
+}
+
+/// Inverse of [`to_prime_field`].
+pub(crate) fn from_prime_field<F: Field>(
+    poly: DensePolynomial<F::BasePrimeField>,
+) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec(
+        poly.coeffs
+            .into_iter()
+            .map(F::from_base_prime_field)
+            .collect(),
+    )
+}
+
+impl<F: Field> DensePolynomial<F> {
+    /// Returns the monic greatest common divisor of `self` and `other`, or
+    /// zero if both are zero.
+    ///
+    /// Over prime fields, this takes `O(M(n) log n)` operations with the
+    /// half-GCD algorithm, where `M(n)` is the cost of multiplying polynomials
+    /// of degree `n`. Over extension fields, polynomials are multiplied
+    /// naively, and `M(n) = O(n^2)`.
+    pub fn gcd(&self, other: &Self) -> Self {
+        self.xgcd(other).0
+    }
+
+    /// Returns the monic greatest common divisor `g` of `self` and `other`,
+    /// along with Bézout coefficients `s` and `t` such that
+    /// `s * self + t * other = g`.
+    ///
+    /// If both polynomials are nonzero, then `deg(s) < deg(other) - deg(g)`
+    /// and `deg(t) < deg(self) - deg(g)`. If both are zero, then `g`, `s`
+    /// and `t` are zero.
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        if let (Some(lhs), Some(rhs)) = (to_prime_field(self), to_prime_field(other)) {
+            let (gcd, s, t) = xgcd::<_, Fast>(&lhs, &rhs);
+            return (
+                from_prime_field(gcd),
+                from_prime_field(s),
+                from_prime_field(t),
+            );
+        }
+        xgcd::<_, Naive>(self, other)
+    }
+
+    /// Returns the resultant of `self` and `other`, which is zero if and
+    /// only if they have a common root in the algebraic closure of `F`, or
+    /// if one of them is zero.
+    ///
+    /// The degrees and leading coefficients of the remainders of the
+    /// Euclidean algorithm are recovered from its quotients, so that this
+    /// takes the same number of operations as [`Self::gcd`].
+    pub fn resultant(&self, other: &Self) -> F {
+        if let (Some(lhs), Some(rhs)) = (to_prime_field(self), to_prime_field(other)) {
+            return F::from_base_prime_field(resultant::<_, Fast>(&lhs, &rhs));
+        }
+        resultant::<_, Naive>(self, other)
+    }
+
+    /// Returns the discriminant of `self`, which is zero if and only if
+    /// `self` has a repeated root in the algebraic closure of `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is constant.
+    pub fn discriminant(&self) -> F {
+        assert!(
+            !self.is_zero() && self.degree() > 0,
+            "the discriminant of a constant polynomial is undefined"
+        );
+        let n = self.degree();
+        let leading = *self.coeffs.last().unwrap();
+        let derivative = Self::from_coefficients_vec(
+            cfg_iter!(self.coeffs)
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| F::from(i as u64) * c)
+                .collect(),
+        );
+        if derivative.is_zero() {
+            return F::zero();
+        }
+        // `disc(f) = (-1)^(n (n - 1) / 2) res(f, f') / lc(f)`, where `f'` is
+        // seen as a polynomial of degree `n - 1` even if the characteristic of
+        // `F` divides `n`.
+        let result = self.resultant(&derivative)
+            * leading.pow([(n - 1 - derivative.degree()) as u64])
+            / leading;
+        if (n * (n - 1) / 2) % 2 == 1 {
+            -result
+        } else {
+            result
+        }
+    }
+}
+
+impl<F: FftField> DensePolynomial<F> {
+    /// Runs the Euclidean algorithm on `(self, other)` until the first
+    /// remainder `r` of degree less than `degree`, and returns `r` along with
+    /// the cofactors `s` and `t` such that `s * self + t * other = r`.
+    ///
+    /// `self` must be nonzero, and of degree at least `deg(other)`.
+    pub(crate) fn partial_xgcd(&self, other: &Self, degree: usize) -> (Self, Self, Self) {
+        // Truncating both polynomials by `x^shift` makes the half-GCD stop at
+        // the remainder of degree `degree` if `2 * degree >= deg(self)`.
+        let shift_by = (2 * degree).saturating_sub(self.degree());
+        let (_, mut transform) =
+            half_gcd::<F, Fast>(&shift(self, shift_by), &shift(other, shift_by));
+        let (mut a, mut b) = transform.apply(self, other);
+        while !b.is_zero() && b.degree() >= degree {
+            let (q, r) = Fast::divide(&a, &b);
+            transform = Transform::step(&q).compose(&transform);
+            a = ark_std::mem::replace(&mut b, r);
+        }
+        let [_, [s, t]] = transform.0;
+        (b, s, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+    use ark_ff::{Field, One, UniformRand, Zero};
+    use ark_std::{test_rng, vec, vec::*};
+    use ark_test_curves::bls12_381::{Fq2, Fr};
+
+    fn from_roots(leading: Fr, roots: &[Fr]) -> DensePolynomial<Fr> {
+        roots.iter().fold(
+            DensePolynomial::from_coefficients_vec(vec![leading]),
+            |acc, r| &acc * &DensePolynomial::from_coefficients_vec(vec![-*r, Fr::one()]),
+        )
+    }
+
+    #[test]
+    fn gcd_and_xgcd() {
+        let rng = &mut test_rng();
+        for (common, a, b) in [
+            (0, 0, 3),
+            (1, 5, 5),
+            (10, 20, 7),
+            (50, 150, 200),
+            (300, 40, 1),
+        ] {
+            let g = DensePolynomial::<Fr>::rand(common, rng);
+            let a = &g * &DensePolynomial::rand(a, rng);
+            let b = &g * &DensePolynomial::rand(b, rng);
+            let monic = &g * g.coeffs.last().unwrap().inverse().unwrap();
+
+            let (gcd, s, t) = a.xgcd(&b);
+            assert_eq!(gcd, monic);
+            assert_eq!(a.gcd(&b), monic);
+            assert_eq!(&(&s * &a) + &(&t * &b), gcd);
+            assert!(s.degree() < b.degree() - gcd.degree() || s.degree() == 0);
+            assert!(t.degree() < a.degree() - gcd.degree() || t.degree() == 0);
+
+            let (gcd, s, t) = b.xgcd(&a);
+            assert_eq!(gcd, monic);
+            assert_eq!(&(&s * &b) + &(&t * &a), gcd);
+        }
+
+        let zero = DensePolynomial::<Fr>::zero();
+        let a = DensePolynomial::<Fr>::rand(10, rng);
+        assert_eq!(zero.xgcd(&zero), (zero.clone(), zero.clone(), zero.clone()));
+        let (gcd, s, t) = zero.xgcd(&a);
+        assert_eq!(&(&s * &zero) + &(&t * &a), gcd);
+        assert_eq!(gcd.coeffs.last(), Some(&Fr::one()));
+    }
+
+    #[test]
+    fn resultant_matches_roots() {
+        let rng = &mut test_rng();
+        for (m, n) in [(1, 0), (3, 5), (100, 30), (200, 180)] {
+            let leading = Fr::rand(rng);
+            let roots: Vec<Fr> = (0..m).map(|_| Fr::rand(rng)).collect();
+            let a = from_roots(leading, &roots);
+            let b = DensePolynomial::<Fr>::rand(n, rng);
+            // `res(a, b) = lc(a)^deg(b) * prod b(r)` over the roots `r` of `a`.
+            let expected = roots
+                .iter()
+                .fold(leading.pow([n as u64]), |acc, r| acc * b.evaluate(r));
+            assert_eq!(a.resultant(&b), expected);
+            let sign = if m * n % 2 == 1 {
+                -Fr::one()
+            } else {
+                Fr::one()
+            };
+            assert_eq!(b.resultant(&a), sign * expected);
+
+            // A common root makes the resultant vanish.
+            let c = &b * &DensePolynomial::from_coefficients_vec(vec![-roots[0], Fr::one()]);
+            assert!(a.resultant(&c).is_zero());
+        }
+    }
+
+    #[test]
+    fn discriminant_matches_roots() {
+        let rng = &mut test_rng();
+        for n in [1, 2, 3, 10, 100] {
+            let leading = Fr::rand(rng);
+            let roots: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+            // `disc(f) = lc(f)^(2n - 2) prod_{i < j} (r_i - r_j)^2`.
+            let mut expected = leading.pow([2 * n as u64 - 2]);
+            for i in 0..n {
+                for j in 0..i {
+                    expected *= (roots[i] - roots[j]).square();
+                }
+            }
+            assert_eq!(from_roots(leading, &roots).discriminant(), expected);
+        }
+        let repeated = [Fr::from(3u64), Fr::from(5u64), Fr::from(3u64)];
+        assert!(from_roots(Fr::one(), &repeated).discriminant().is_zero());
+    }
+
+    #[test]
+    fn extension_field() {
+        let rng = &mut test_rng();
+        for (common, a, b) in [(0, 3, 5), (4, 30, 20), (40, 70, 90)] {
+            let g = DensePolynomial::<Fq2>::rand(common, rng);
+            let a = &g.naive_mul(&DensePolynomial::rand(a, rng));
+            let b = &g.naive_mul(&DensePolynomial::rand(b, rng));
+            let (gcd, s, t) = a.xgcd(b);
+            assert_eq!(gcd, &g * g.coeffs.last().unwrap().inverse().unwrap());
+            assert_eq!(&s.naive_mul(a) + &t.naive_mul(b), gcd);
+        }
+
+        let roots: Vec<Fq2> = (0..10).map(|_| Fq2::rand(rng)).collect();
+        let a = roots.iter().fold(
+            DensePolynomial::from_coefficients_vec(vec![Fq2::one()]),
+            |acc, r| {
+                acc.naive_mul(&DensePolynomial::from_coefficients_vec(vec![
+                    -*r,
+                    Fq2::one(),
+                ]))
+            },
+        );
+        let b = DensePolynomial::<Fq2>::rand(7, rng);
+        let expected: Fq2 = roots.iter().map(|r| b.evaluate(r)).product();
+        assert_eq!(a.resultant(&b), expected);
+        let square = a.naive_mul(&a);
+        assert!(square.discriminant().is_zero());
+        assert!(!a.discriminant().is_zero());
+    }
+}
@@ -6,14 +6,14 @@ use ark_std::{borrow::Cow, vec::*};
 use DenseOrSparsePolynomial::{DPolynomial, SPolynomial};
 
 mod dense;
+mod factor;
+mod gcd;
+mod multipoint;
 mod sparse;
 
 pub use dense::DensePolynomial;
 pub use sparse::SparsePolynomial;
 
-#[cfg(feature = "parallel")]
-use rayon::prelude::*;
-
 /// Represents either a sparse polynomial or a dense one.
 #[derive(Clone)]
 pub enum DenseOrSparsePolynomial<'a, F: Field> {
@@ -146,58 +146,48 @@ impl<'a, F: 'a + FftField> DenseOrSparsePolynomial<'a, F> {
     }
 
     fn eval_over_domain_helper<D: EvaluationDomain<F>>(self, domain: D) -> Evaluations<F, D> {
-        let eval_sparse_poly = |s: &SparsePolynomial<F>| {
-            let evals = domain.elements().map(|elem| s.evaluate(&elem)).collect();
-            Evaluations::from_vec_and_domain(evals, domain)
-        };
-
         match self {
-            SPolynomial(Cow::Borrowed(s)) => eval_sparse_poly(s),
-            SPolynomial(Cow::Owned(s)) => eval_sparse_poly(&s),
-            DPolynomial(Cow::Borrowed(d)) => {
-                if d.is_zero() {
-                    Evaluations::zero(domain)
-                } else {
-                    let mut chunks = d.coeffs.chunks(domain.size());
-                    let mut first = chunks.next().unwrap().to_vec();
-                    let offset = domain.coset_offset();
-                    // Reduce the coefficients of the polynomial mod X^domain.size()
-                    for (i, chunk) in chunks.enumerate() {
-                        if offset.is_one() {
-                            cfg_iter_mut!(first).zip(chunk).for_each(|(x, y)| *x += y);
-                        } else {
-                            let offset_power = offset.pow([((i + 1) * domain.size()) as u64]);
-                            cfg_iter_mut!(first)
-                                .zip(chunk)
-                                .for_each(|(x, y)| *x += offset_power * y);
-                        }
-                    }
-                    domain.fft_in_place(&mut first);
-                    Evaluations::from_vec_and_domain(first, domain)
-                }
+            SPolynomial(s) => {
+                let evals = domain.elements().map(|elem| s.evaluate(&elem)).collect();
+                Evaluations::from_vec_and_domain(evals, domain)
             },
-            DPolynomial(Cow::Owned(mut d)) => {
+            DPolynomial(d) => {
                 if d.is_zero() {
                     Evaluations::zero(domain)
                 } else {
-                    let mut chunks = d.coeffs.chunks_mut(domain.size());
-                    let first = chunks.next().unwrap();
-                    let offset = domain.coset_offset();
-                    // Reduce the coefficients of the polynomial mod X^domain.size()
-                    for (i, chunk) in chunks.enumerate() {
-                        if offset.is_one() {
-                            cfg_iter_mut!(first).zip(chunk).for_each(|(x, y)| *x += y);
-                        } else {
-                            let offset_power = offset.pow([((i + 1) * domain.size()) as u64]);
-                            cfg_iter_mut!(first)
-                                .zip(chunk)
-                                .for_each(|(x, y)| *x += offset_power * y);
-                        }
-                    }
-                    domain.fft_in_place(&mut d.coeffs);
-                    Evaluations::from_vec_and_domain(d.coeffs, domain)
+                    let mut coeffs = match d {
+                        Cow::Owned(d) => {
+                            let mut coeffs = d.coeffs;
+                            domain.reduce_mod_vanishing(&mut coeffs);
+                            coeffs
+                        },
+                        Cow::Borrowed(d) => reduce_mod_vanishing_from_slice(&domain, &d.coeffs),
+                    };
+                    domain.fft_in_place(&mut coeffs);
+                    Evaluations::from_vec_and_domain(coeffs, domain)
                 }
             },
         }
     }
 }
+
+/// Reduces `coeffs` modulo the vanishing polynomial of `domain` without
+/// copying all of them first: the chunks of `domain.size()` coefficients are
+/// folded in from the highest one down, as in Horner's method in `X^size`,
+/// so that the working buffer never holds more than `2 * domain.size()`
+/// coefficients.
+fn reduce_mod_vanishing_from_slice<F: FftField, D: EvaluationDomain<F>>(
+    domain: &D,
+    coeffs: &[F],
+) -> Vec<F> {
+    let size = domain.size();
+    let mut reduced = Vec::with_capacity(coeffs.len().min(2 * size));
+    for chunk in coeffs.chunks(size).rev() {
+        // `reduced <- chunk + X^size * reduced`. Every chunk but the highest
+        // has `size` coefficients, and `reduced` is empty for the highest one.
+        reduced.splice(0..0, chunk.iter().copied());
+        domain.reduce_mod_vanishing(&mut reduced);
+        reduced.resize(size, F::zero());
+    }
+    reduced
+}
@@ -0,0 +1,203 @@
+//! Multipoint evaluation and interpolation of dense univariate polynomials at
+//! arbitrary points, with subproduct trees.
+
+use crate::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+use ark_ff::{batch_inversion, FftField, Zero};
+use ark_std::{vec, vec::*};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of points below which the subproduct tree is not refined
+/// further, and polynomials are evaluated with Horner's method.
+const LEAF_SIZE: usize = 32;
+
+/// The subproduct tree of a list of points `x_0, ..., x_{n-1}`.
+///
+/// Its leaves are the products of the `x - x_i` over chunks of `LEAF_SIZE`
+/// consecutive points, and each inner node is the product of its (at most
+/// two) children.
+struct SubproductTree<'a, F: FftField> {
+    points: &'a [F],
+    /// The nodes of the tree, from the leaves to the root.
+    levels: Vec<Vec<DensePolynomial<F>>>,
+}
+
+impl<'a, F: FftField> SubproductTree<'a, F> {
+    fn new(points: &'a [F]) -> Self {
+        let leaves = cfg_chunks!(points, LEAF_SIZE)
+            .map(|chunk| {
+                chunk.iter().fold(
+                    DensePolynomial::from_coefficients_vec(vec![F::one()]),
+                    |acc, x| {
+                        acc.naive_mul(&DensePolynomial::from_coefficients_vec(vec![-*x, F::one()]))
+                    },
+                )
+            })
+            .collect();
+        let mut levels: Vec<Vec<DensePolynomial<F>>> = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let next = cfg_chunks!(levels.last().unwrap(), 2)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Self { points, levels }
+    }
+
+    /// Returns the product of all the `x - x_i`.
+    fn root(&self) -> &DensePolynomial<F> {
+        &self.levels.last().unwrap()[0]
+    }
+
+    /// Evaluates `poly` at every point, by reducing it modulo the nodes of the
+    /// tree from the root to the leaves.
+    fn evaluate(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        let mut remainders = vec![poly.div_rem(self.root()).1];
+        for level in self.levels.iter().rev().skip(1) {
+            remainders = cfg_iter!(level)
+                .enumerate()
+                .map(|(i, node)| remainders[i / 2].div_rem(node).1)
+                .collect();
+        }
+        cfg_chunks!(self.points, LEAF_SIZE)
+            .zip(&remainders)
+            .map(|(chunk, remainder)| chunk.iter().map(|x| remainder.evaluate(x)).collect())
+            .collect::<Vec<Vec<F>>>()
+            .concat()
+    }
+
+    /// Returns the sum of the `weights[i] * root / (x - x_i)`, by combining
+    /// the sums of the children of each node from the leaves to the root.
+    fn linear_combination(&self, weights: &[F]) -> DensePolynomial<F> {
+        let mut sums: Vec<DensePolynomial<F>> = cfg_chunks!(self.points, LEAF_SIZE)
+            .zip(cfg_chunks!(weights, LEAF_SIZE))
+            .zip(&self.levels[0])
+            .map(|((points, weights), leaf)| {
+                let mut sum = vec![F::zero(); points.len()];
+                for (x, w) in points.iter().zip(weights) {
+                    // Synthetic division of the leaf by `x - x_i`.
+                    let mut carry = F::zero();
+                    for (s, c) in sum.iter_mut().zip(&leaf.coeffs[1..]).rev() {
+                        carry = carry * x + c;
+                        *s += carry * w;
+                    }
+                }
+                DensePolynomial::from_coefficients_vec(sum)
+            })
+            .collect();
+        for children in &self.levels[..self.levels.len() - 1] {
+            sums = cfg_chunks!(sums, 2)
+                .zip(cfg_chunks!(children, 2))
+                .map(|(sums, children)| match (sums, children) {
+                    ([left, right], [left_node, right_node]) => {
+                        &(left * right_node) + &(right * left_node)
+                    },
+                    ([sum], _) => sum.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        sums.pop().unwrap()
+    }
+}
+
+impl<F: FftField> DensePolynomial<F> {
+    /// Evaluates `self` at every point of `points`, in `O(n log^2 n)`
+    /// operations for `n` points and a polynomial of degree `n`.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        if points.len() <= LEAF_SIZE {
+            return cfg_iter!(points).map(|x| self.evaluate(x)).collect();
+        }
+        SubproductTree::new(points).evaluate(self)
+    }
+
+    /// Returns the polynomial of degree less than `points.len()` that takes
+    /// the value `y` at `x` for every `(x, y)` in `points`, or `None` if the
+    /// `x` are not pairwise distinct.
+    pub fn interpolate_from_points(points: &[(F, F)]) -> Option<Self> {
+        if points.is_empty() {
+            return Some(Self::zero());
+        }
+        let (xs, ys): (Vec<F>, Vec<F>) = points.iter().copied().unzip();
+        let tree = SubproductTree::new(&xs);
+        // The Lagrange coefficients are `y_i / root'(x_i)`.
+        let root = tree.root();
+        let derivative = Self::from_coefficients_vec(
+            cfg_iter!(root.coeffs)
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| F::from(i as u64) * c)
+                .collect(),
+        );
+        let mut weights = tree.evaluate(&derivative);
+        if weights.iter().any(F::is_zero) {
+            return None;
+        }
+        batch_inversion(&mut weights);
+        cfg_iter_mut!(weights).zip(ys).for_each(|(w, y)| *w *= y);
+        Some(tree.linear_combination(&weights))
+    }
+
+    /// Returns the product of the `x - x_i` over all `x_i` in `points`.
+    pub(crate) fn vanishing_on(points: &[F]) -> Self {
+        if points.is_empty() {
+            return Self::from_coefficients_vec(vec![F::one()]);
+        }
+        SubproductTree::new(points).root().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+    use ark_ff::{UniformRand, Zero};
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn evaluate_many_matches_evaluate() {
+        let rng = &mut test_rng();
+        for (degree, num_points) in [(0, 0), (10, 5), (100, 100), (300, 517), (1000, 64)] {
+            let poly = DensePolynomial::<Fr>::rand(degree, rng);
+            let points: Vec<Fr> = (0..num_points).map(|_| Fr::rand(rng)).collect();
+            let expected: Vec<Fr> = points.iter().map(|x| poly.evaluate(x)).collect();
+            assert_eq!(poly.evaluate_many(&points), expected);
+        }
+    }
+
+    #[test]
+    fn interpolate_from_points_roundtrip() {
+        let rng = &mut test_rng();
+        for num_points in [0, 1, 2, 31, 33, 200, 513] {
+            let poly = DensePolynomial::<Fr>::rand(num_points.max(1) - 1, rng);
+            let points: Vec<(Fr, Fr)> = (0..num_points)
+                .map(|_| {
+                    let x = Fr::rand(rng);
+                    (x, poly.evaluate(&x))
+                })
+                .collect();
+            let expected = if num_points == 0 {
+                DensePolynomial::zero()
+            } else {
+                poly
+            };
+            assert_eq!(
+                DensePolynomial::interpolate_from_points(&points),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn interpolate_from_points_repeated_x() {
+        let rng = &mut test_rng();
+        let mut points: Vec<(Fr, Fr)> = (0..100).map(|_| (Fr::rand(rng), Fr::rand(rng))).collect();
+        points[70].0 = points[3].0;
+        assert_eq!(DensePolynomial::interpolate_from_points(&points), None);
+    }
+}
@@ -26,6 +26,9 @@ pub struct SparsePolynomial<F: Field> {
     coeffs: Vec<(usize, F)>,
 }
 
+#[cfg(feature = "serde")]
+ark_serialize::impl_serde_via_canonical!([F: Field] SparsePolynomial<F>);
+
 impl<F: Field> fmt::Debug for SparsePolynomial<F> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         for (i, coeff) in self.coeffs.iter().filter(|(_, c)| !c.is_zero()) {
@@ -0,0 +1,277 @@
+//! Reed–Solomon codes over evaluation domains.
+//!
+//! A [`ReedSolomonCode`] of dimension `k` and blowup factor `b` encodes
+//! polynomials of degree less than `k` as their evaluations over a coset of
+//! size `n = b * k`, which can be recovered from any `k` of them, or from a
+//! codeword with at most `(n - k) / 2` errors.
+
+use crate::{
+    univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, Evaluations,
+    GeneralEvaluationDomain, Polynomial,
+};
+use ark_ff::{FftField, Zero};
+use ark_std::vec::*;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A Reed–Solomon code, whose codewords are the evaluations over a coset
+/// `g * H_n` of the polynomials of degree less than `k`, for a blowup factor
+/// `n / k`.
+///
+/// The coset `g * H_k` of the message domain is made of every `n / k`-th
+/// element of the codeword domain, so that systematic encodings contain the
+/// message at these positions.
+#[derive(Copy, Clone, Hash, Debug, PartialEq, Eq)]
+pub struct ReedSolomonCode<F: FftField, D: EvaluationDomain<F> = GeneralEvaluationDomain<F>> {
+    message_domain: D,
+    codeword_domain: D,
+    _field: ark_std::marker::PhantomData<F>,
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> ReedSolomonCode<F, D> {
+    /// Constructs the code of dimension `dimension` whose codewords are
+    /// `blowup_factor` times longer, over the coset of `offset`.
+    ///
+    /// Returns `None` if `D` has no domain of size exactly `dimension` or
+    /// `blowup_factor * dimension`, or if the smaller domain is not a
+    /// subgroup of the larger one.
+    pub fn new(dimension: usize, blowup_factor: usize, offset: F) -> Option<Self> {
+        if dimension == 0 || blowup_factor == 0 {
+            return None;
+        }
+        let message_domain = D::new(dimension)?.get_coset(offset)?;
+        let codeword_domain = D::new(dimension.checked_mul(blowup_factor)?)?.get_coset(offset)?;
+        if message_domain.size() != dimension
+            || codeword_domain.size() != dimension * blowup_factor
+            || codeword_domain.element(blowup_factor) != message_domain.element(1)
+        {
+            return None;
+        }
+        Some(Self {
+            message_domain,
+            codeword_domain,
+            _field: ark_std::marker::PhantomData,
+        })
+    }
+
+    /// Returns the dimension `k` of the code.
+    pub fn dimension(&self) -> usize {
+        self.message_domain.size()
+    }
+
+    /// Returns the length `n` of the codewords.
+    pub fn length(&self) -> usize {
+        self.codeword_domain.size()
+    }
+
+    /// Returns the ratio `n / k` of the length and dimension of the code.
+    pub fn blowup_factor(&self) -> usize {
+        self.length() / self.dimension()
+    }
+
+    /// Returns the domain over which systematic messages are given.
+    pub const fn message_domain(&self) -> D {
+        self.message_domain
+    }
+
+    /// Returns the domain over which codewords are evaluated.
+    pub const fn codeword_domain(&self) -> D {
+        self.codeword_domain
+    }
+
+    /// Encodes the polynomial with coefficients `message`, with at most `k`
+    /// coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `message` has more than `k` elements.
+    pub fn encode(&self, message: &[F]) -> Evaluations<F, D> {
+        assert!(message.len() <= self.dimension(), "message is too long");
+        Evaluations::from_vec_and_domain(self.codeword_domain.fft(message), self.codeword_domain)
+    }
+
+    /// Encodes the polynomial that takes the values `message` over the
+    /// message domain, so that the codeword contains `message[i]` at
+    /// position `i * n / k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `message` does not have exactly `k` elements.
+    pub fn encode_systematic(&self, message: &[F]) -> Evaluations<F, D> {
+        assert_eq!(
+            message.len(),
+            self.dimension(),
+            "message has the wrong length"
+        );
+        self.encode(&self.message_domain.ifft(message))
+    }
+
+    /// Returns the systematic message contained in `codeword`.
+    pub fn systematic_message(&self, codeword: &[F]) -> Vec<F> {
+        codeword
+            .iter()
+            .step_by(self.blowup_factor())
+            .copied()
+            .collect()
+    }
+
+    /// Recovers the polynomial of degree less than `k` from a codeword in
+    /// which the `None` entries are erased.
+    ///
+    /// If `E` is the set of erased points and `Z_E` its vanishing polynomial,
+    /// then `f * Z_E` has degree less than `n` and is known over the whole
+    /// codeword domain, so that `f` is recovered with two FFTs and a
+    /// division.
+    ///
+    /// Returns `None` if fewer than `k` entries are known, or if they do not
+    /// agree with any codeword.
+    pub fn decode_erasures(&self, received: &[Option<F>]) -> Option<DensePolynomial<F>> {
+        assert_eq!(
+            received.len(),
+            self.length(),
+            "received word has the wrong length"
+        );
+        let erased: Vec<F> = received
+            .iter()
+            .zip(self.codeword_domain.elements())
+            .filter_map(|(value, x)| value.is_none().then_some(x))
+            .collect();
+        if self.length() - erased.len() < self.dimension() {
+            return None;
+        }
+        let vanishing = DensePolynomial::vanishing_on(&erased);
+        let mut product = self.codeword_domain.fft(&vanishing);
+        cfg_iter_mut!(product)
+            .zip(received)
+            .for_each(|(p, value)| *p *= value.unwrap_or_else(F::zero));
+        self.codeword_domain.ifft_in_place(&mut product);
+        let (message, _) = DensePolynomial::from_coefficients_vec(product).divide(&vanishing);
+        (message.is_zero() || message.degree() < self.dimension()).then_some(message)
+    }
+
+    /// Recovers the polynomial of degree less than `k` from a codeword with
+    /// at most `(n - k) / 2` errors, with Gao's algorithm.
+    ///
+    /// If `g_1` interpolates `received`, the extended Euclidean algorithm on
+    /// the vanishing polynomial of the codeword domain and `g_1` is stopped
+    /// at the first remainder `g = u * Z + v * g_1` of degree less than
+    /// `(n + k) / 2`, and the message is `g / v`.
+    ///
+    /// Returns `None` if there are too many errors to decode.
+    pub fn decode(&self, received: &[F]) -> Option<DensePolynomial<F>> {
+        assert_eq!(
+            received.len(),
+            self.length(),
+            "received word has the wrong length"
+        );
+        let interpolant =
+            DensePolynomial::from_coefficients_vec(self.codeword_domain.ifft(received));
+        let vanishing: DensePolynomial<F> = self.codeword_domain.vanishing_polynomial().into();
+        let (remainder, _, cofactor) =
+            vanishing.partial_xgcd(&interpolant, (self.length() + self.dimension()).div_ceil(2));
+        let (message, rest) = remainder.divide(&cofactor);
+        (rest.is_zero() && (message.is_zero() || message.degree() < self.dimension()))
+            .then_some(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        reed_solomon::ReedSolomonCode, univariate::DensePolynomial, DenseUVPolynomial,
+        GeneralEvaluationDomain, Radix2EvaluationDomain,
+    };
+    use ark_ff::{FftField, One, UniformRand, Zero};
+    use ark_std::{rand::Rng, test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn encode() {
+        let rng = &mut test_rng();
+        let code = ReedSolomonCode::<Fr>::new(16, 4, Fr::GENERATOR).unwrap();
+        assert_eq!(
+            (code.dimension(), code.length(), code.blowup_factor()),
+            (16, 64, 4)
+        );
+
+        let poly = DensePolynomial::<Fr>::rand(15, rng);
+        let codeword = code.encode(&poly.coeffs);
+        assert_eq!(
+            codeword.evals,
+            poly.evaluate_over_domain_by_ref(code.codeword_domain())
+                .evals
+        );
+        assert_eq!(codeword.interpolate(), poly);
+
+        let message: Vec<Fr> = (0..16).map(|_| Fr::rand(rng)).collect();
+        let codeword = code.encode_systematic(&message);
+        assert_eq!(code.systematic_message(&codeword.evals), message);
+
+        assert!(ReedSolomonCode::<Fr, Radix2EvaluationDomain<Fr>>::new(12, 4, Fr::one()).is_none());
+        assert!(ReedSolomonCode::<Fr>::new(16, 0, Fr::one()).is_none());
+    }
+
+    #[test]
+    fn decode_erasures() {
+        let rng = &mut test_rng();
+        let code = ReedSolomonCode::<Fr>::new(32, 4, Fr::GENERATOR).unwrap();
+        let poly = DensePolynomial::<Fr>::rand(31, rng);
+        let codeword = code.encode(&poly.coeffs);
+        for num_erasures in [0, 1, 50, 96, 97] {
+            let mut received: Vec<Option<Fr>> = codeword.evals.iter().copied().map(Some).collect();
+            let mut erased = 0;
+            while erased < num_erasures {
+                let i = rng.gen_range(0..code.length());
+                if received[i].take().is_some() {
+                    erased += 1;
+                }
+            }
+            let expected = (num_erasures <= 96).then(|| poly.clone());
+            assert_eq!(code.decode_erasures(&received), expected);
+        }
+
+        // Known values that do not come from a codeword are detected.
+        let mut received: Vec<Option<Fr>> = codeword.evals.iter().copied().map(Some).collect();
+        received[0] = Some(Fr::rand(rng));
+        received[1] = None;
+        assert_eq!(code.decode_erasures(&received), None);
+    }
+
+    #[test]
+    fn decode_errors() {
+        let rng = &mut test_rng();
+        for (dimension, blowup_factor) in [(1, 2), (8, 2), (64, 2), (32, 8)] {
+            let code = ReedSolomonCode::<Fr, GeneralEvaluationDomain<Fr>>::new(
+                dimension,
+                blowup_factor,
+                Fr::GENERATOR,
+            )
+            .unwrap();
+            let poly = DensePolynomial::<Fr>::rand(dimension - 1, rng);
+            let codeword = code.encode(&poly.coeffs).evals;
+            let max_errors = (code.length() - dimension) / 2;
+            for num_errors in [0, max_errors.min(1), max_errors] {
+                let mut received = codeword.clone();
+                let mut corrupted = Vec::new();
+                while corrupted.len() < num_errors {
+                    let i = rng.gen_range(0..code.length());
+                    if !corrupted.contains(&i) {
+                        received[i] += Fr::rand(rng);
+                        corrupted.push(i);
+                    }
+                }
+                assert_eq!(code.decode(&received), Some(poly.clone()));
+            }
+        }
+
+        // Beyond half the minimum distance, decoding fails.
+        let code = ReedSolomonCode::<Fr>::new(16, 4, Fr::GENERATOR).unwrap();
+        let mut received = code.encode(&DensePolynomial::rand(15, rng).coeffs).evals;
+        for value in received.iter_mut().take(40) {
+            *value = Fr::rand(rng);
+        }
+        assert_eq!(code.decode(&received), None);
+        assert!(code.decode(&[Fr::zero(); 64]).unwrap().is_zero());
+    }
+}
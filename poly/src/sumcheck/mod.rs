@@ -0,0 +1,415 @@
+//! The sumcheck protocol for sums of products of multilinear extensions.
+//!
+//! The prover convinces the verifier that `sum_{x in {0, 1}^n} g(x) = s`
+//! for a [`VirtualPolynomial`] `g` of degree `d` in each variable. In each of
+//! the `n` rounds, the prover sends the evaluations at `0, 1, ..., d` of the
+//! univariate restriction of `g` to its next variable, summed over the
+//! remaining ones, and the variable is bound to a challenge from a
+//! [`Transcript`]. The verifier is left with the claim that `g` evaluates
+//! to [`SubClaim::expected_evaluation`] at [`SubClaim::point`].
+//!
+//! Variables can be bound from `x_0` to `x_{n - 1}`, which reads contiguous
+//! pairs of evaluations, or from `x_{n - 1}` to `x_0`, which reads the two
+//! halves of the evaluations, as chosen by a [`BindingOrder`].
+//!
+//! The round polynomials are interpolated from their evaluations at
+//! `0, 1, ..., d`, which are only distinct in fields of characteristic
+//! larger than `d`, so higher degrees are rejected in smaller
+//! characteristics.
+//!
+//! [`SumcheckProof::prove_streaming`] proves the same statement over
+//! [`crate::StreamingMultilinearExtension`]s, with memory bounded by a
+//! table size rather than by the number of evaluations.
+
+mod prover;
+mod streaming;
+mod virtual_polynomial;
+
+pub use virtual_polynomial::VirtualPolynomial;
+
+use ark_ff::{batch_inversion, Field};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{fmt, vec::*};
+
+/// A Fiat–Shamir transcript, from which the challenges of the protocol are
+/// derived.
+pub trait Transcript<F: Field> {
+    /// Absorbs `elements`, with `label` for domain separation.
+    fn append_field_elements(&mut self, label: &'static [u8], elements: &[F]);
+
+    /// Squeezes a challenge, with `label` for domain separation.
+    fn challenge(&mut self, label: &'static [u8]) -> F;
+}
+
+/// The order in which the variables are bound to the challenges.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BindingOrder {
+    /// Binds `x_0` first, as [`crate::MultilinearExtension::fix_variables`].
+    LowToHigh,
+    /// Binds `x_{n - 1}` first.
+    HighToLow,
+}
+
+impl BindingOrder {
+    /// Returns the point in variable order from the challenges in round
+    /// order.
+    fn point<F: Field>(self, mut challenges: Vec<F>) -> Vec<F> {
+        if self == Self::HighToLow {
+            challenges.reverse();
+        }
+        challenges
+    }
+}
+
+/// A proof of the sumcheck protocol.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SumcheckProof<F: Field> {
+    /// The evaluations at `0, 1, ..., d` of the round polynomials.
+    pub round_polynomials: Vec<Vec<F>>,
+}
+
+/// The claim that the sumcheck protocol reduces to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SubClaim<F: Field> {
+    /// The point made of the challenges, in variable order.
+    pub point: Vec<F>,
+    /// The claimed evaluation of the polynomial at `point`.
+    pub expected_evaluation: F,
+}
+
+/// An error returned by the verifier of the sumcheck protocol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SumcheckError {
+    /// The proof does not have one round polynomial per variable.
+    WrongNumberOfRounds {
+        /// The number of variables.
+        expected: usize,
+        /// The number of round polynomials.
+        found: usize,
+    },
+    /// A round polynomial has too few or too many evaluations.
+    InvalidRoundPolynomial {
+        /// The index of the round.
+        round: usize,
+    },
+    /// A round polynomial does not sum to the claim of the previous round.
+    InconsistentRound {
+        /// The index of the round.
+        round: usize,
+    },
+    /// The characteristic of the field is at most the degree, so that the
+    /// round polynomials cannot be interpolated.
+    UnsupportedDegree {
+        /// The degree of the polynomial in each variable.
+        degree: usize,
+    },
+}
+
+impl ark_std::error::Error for SumcheckError {}
+
+impl fmt::Display for SumcheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Self::WrongNumberOfRounds { expected, found } => {
+                write!(f, "expected {expected} round polynomials, found {found}")
+            },
+            Self::InvalidRoundPolynomial { round } => {
+                write!(f, "round polynomial {round} has the wrong degree")
+            },
+            Self::InconsistentRound { round } => {
+                write!(f, "round polynomial {round} does not match the claim")
+            },
+            Self::UnsupportedDegree { degree } => {
+                write!(f, "degree {degree} is not smaller than the characteristic")
+            },
+        }
+    }
+}
+
+impl<F: Field> SumcheckProof<F> {
+    /// Verifies the proof that the polynomial of degree `degree` in each of
+    /// its `num_vars` variables sums to `claimed_sum` over the boolean
+    /// hypercube, and returns the claim on its evaluation that is left to
+    /// check.
+    pub fn verify<T: Transcript<F>>(
+        &self,
+        claimed_sum: F,
+        num_vars: usize,
+        degree: usize,
+        order: BindingOrder,
+        transcript: &mut T,
+    ) -> Result<SubClaim<F>, SumcheckError> {
+        if !has_distinct_nodes::<F>(degree.max(1)) {
+            return Err(SumcheckError::UnsupportedDegree { degree });
+        }
+        if self.round_polynomials.len() != num_vars {
+            return Err(SumcheckError::WrongNumberOfRounds {
+                expected: num_vars,
+                found: self.round_polynomials.len(),
+            });
+        }
+        transcript.append_field_elements(b"sumcheck_claim", &[claimed_sum]);
+        let mut claim = claimed_sum;
+        let mut challenges = Vec::with_capacity(num_vars);
+        for (round, evals) in self.round_polynomials.iter().enumerate() {
+            if evals.len() < 2 || evals.len() > degree.max(1) + 1 {
+                return Err(SumcheckError::InvalidRoundPolynomial { round });
+            }
+            if evals[0] + evals[1] != claim {
+                return Err(SumcheckError::InconsistentRound { round });
+            }
+            transcript.append_field_elements(b"sumcheck_round", evals);
+            let challenge = transcript.challenge(b"sumcheck_challenge");
+            claim = interpolate_at(evals, challenge);
+            challenges.push(challenge);
+        }
+        Ok(SubClaim {
+            point: order.point(challenges),
+            expected_evaluation: claim,
+        })
+    }
+}
+
+/// Returns whether `0, 1, ..., degree` are distinct elements of `F`, i.e.
+/// whether the characteristic of `F` is larger than `degree`.
+pub(crate) fn has_distinct_nodes<F: Field>(degree: usize) -> bool {
+    let (low, high) = F::characteristic().split_first().unwrap_or((&0, &[]));
+    *low > degree as u64 || high.iter().any(|limb| *limb != 0)
+}
+
+/// Evaluates at `point` the polynomial of degree less than `evals.len()` that
+/// takes the values `evals` at `0, 1, ..., evals.len() - 1`.
+///
+/// The characteristic of `F` must be at least `evals.len()`, so that the
+/// nodes are distinct.
+pub(crate) fn interpolate_at<F: Field>(evals: &[F], point: F) -> F {
+    let n = evals.len();
+    debug_assert!(has_distinct_nodes::<F>(n.saturating_sub(1)));
+    let nodes: Vec<F> = (0..n as u64).map(F::from).collect();
+    if let Some(i) = nodes.iter().position(|x| *x == point) {
+        return evals[i];
+    }
+    // The barycentric weight of `i` is `1 / prod_{j != i} (i - j)`, which is
+    // `(-1)^(n - 1 - i) / (i! * (n - 1 - i)!)`.
+    let mut factorials = vec![F::one(); n];
+    for i in 1..n {
+        factorials[i] = factorials[i - 1] * nodes[i];
+    }
+    let mut denominators: Vec<F> = (0..n)
+        .map(|i| {
+            let denominator = (point - nodes[i]) * factorials[i] * factorials[n - 1 - i];
+            if (n - 1 - i) % 2 == 0 {
+                denominator
+            } else {
+                -denominator
+            }
+        })
+        .collect();
+    batch_inversion(&mut denominators);
+    let numerator: F = nodes.iter().map(|x| point - x).product();
+    numerator
+        * evals
+            .iter()
+            .zip(denominators)
+            .map(|(e, d)| *e * d)
+            .sum::<F>()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sumcheck::{
+            has_distinct_nodes, interpolate_at, BindingOrder, SubClaim, SumcheckError,
+            SumcheckProof, Transcript, VirtualPolynomial,
+        },
+        DenseMultilinearExtension, MultilinearExtension, OracleMultilinearExtension,
+        SparseMultilinearExtension,
+    };
+    use ark_ff::{binary::BinaryField128b, Field, One, UniformRand, Zero};
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// A transcript that is not secure, but deterministic.
+    struct TestTranscript<F>(F);
+
+    impl<F: Field> Transcript<F> for TestTranscript<F> {
+        fn append_field_elements(&mut self, label: &'static [u8], elements: &[F]) {
+            self.0 += F::from(label.len() as u64);
+            for e in elements {
+                self.0 = (self.0 + e) * F::from(7u64);
+            }
+        }
+
+        fn challenge(&mut self, label: &'static [u8]) -> F {
+            self.0 = self.0.square() + F::from(label.len() as u64);
+            self.0
+        }
+    }
+
+    fn test_polynomial(num_vars: usize) -> VirtualPolynomial<Fr> {
+        let rng = &mut test_rng();
+        let mut poly = VirtualPolynomial::new(num_vars);
+        let f = poly.add_mle(&DenseMultilinearExtension::rand(num_vars, rng));
+        let g = poly.add_mle(&DenseMultilinearExtension::rand(num_vars, rng));
+        let h = poly.add_mle(&SparseMultilinearExtension::rand_with_config(
+            num_vars,
+            1 << (num_vars / 2),
+            rng,
+        ));
+        poly.add_product(Fr::rand(rng), [f, g, h]);
+        poly.add_product(Fr::rand(rng), [f, f]);
+        poly.add_product(Fr::rand(rng), [h]);
+        poly.add_product(Fr::rand(rng), []);
+        poly
+    }
+
+    #[test]
+    fn interpolation() {
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..5).map(|_| Fr::rand(rng)).collect();
+        let evaluate = |x: Fr| coeffs.iter().rev().fold(Fr::zero(), |acc, c| acc * x + c);
+        let evals: Vec<Fr> = (0..5u64).map(|i| evaluate(Fr::from(i))).collect();
+        for point in [Fr::from(3u64), Fr::from(9u64), Fr::rand(rng)] {
+            assert_eq!(interpolate_at(&evals, point), evaluate(point));
+        }
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        for num_vars in [1, 2, 7] {
+            let poly = test_polynomial(num_vars);
+            let sum = poly.sum_over_hypercube();
+            for order in [BindingOrder::LowToHigh, BindingOrder::HighToLow] {
+                let (proof, claim, mle_values) =
+                    SumcheckProof::prove(&poly, order, &mut TestTranscript(Fr::one()));
+                assert_eq!(claim.expected_evaluation, poly.evaluate(&claim.point));
+                let point = claim.point.clone();
+                let expected: Vec<Fr> = poly
+                    .mles()
+                    .iter()
+                    .map(|mle| mle.fix_variables(&point)[0])
+                    .collect();
+                assert_eq!(mle_values, expected);
+
+                let verified = proof
+                    .verify(sum, num_vars, 3, order, &mut TestTranscript(Fr::one()))
+                    .unwrap();
+                assert_eq!(verified, claim);
+
+                // A wrong sum or a tampered proof is rejected.
+                assert_eq!(
+                    proof.verify(
+                        sum + Fr::one(),
+                        num_vars,
+                        3,
+                        order,
+                        &mut TestTranscript(Fr::one())
+                    ),
+                    Err(SumcheckError::InconsistentRound { round: 0 })
+                );
+                let mut tampered = proof.clone();
+                tampered.round_polynomials[num_vars - 1][2] += Fr::one();
+                let result =
+                    tampered.verify(sum, num_vars, 3, order, &mut TestTranscript(Fr::one()));
+                assert!(result.map_or(true, |c: SubClaim<Fr>| c.expected_evaluation
+                    != poly.evaluate(&c.point)));
+                assert_eq!(
+                    proof.verify(sum, num_vars, 2, order, &mut TestTranscript(Fr::one())),
+                    Err(SumcheckError::InvalidRoundPolynomial { round: 0 })
+                );
+                assert_eq!(
+                    proof.verify(sum, num_vars + 1, 3, order, &mut TestTranscript(Fr::one())),
+                    Err(SumcheckError::WrongNumberOfRounds {
+                        expected: num_vars + 1,
+                        found: num_vars
+                    })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn prove_streaming() {
+        for num_vars in [0, 1, 6] {
+            let poly = test_polynomial(num_vars);
+            let expected = SumcheckProof::prove(
+                &poly,
+                BindingOrder::LowToHigh,
+                &mut TestTranscript(Fr::one()),
+            );
+            let oracles: Vec<_> = poly
+                .mles()
+                .iter()
+                .map(|mle| OracleMultilinearExtension::new(num_vars, |i| mle[i]))
+                .collect();
+            for max_table_size in [0, 1, 8, 1 << num_vars] {
+                assert_eq!(
+                    SumcheckProof::prove_streaming(
+                        num_vars,
+                        poly.mles(),
+                        poly.products(),
+                        max_table_size,
+                        &mut TestTranscript(Fr::one())
+                    ),
+                    expected
+                );
+                assert_eq!(
+                    SumcheckProof::prove_streaming(
+                        num_vars,
+                        &oracles,
+                        poly.products(),
+                        max_table_size,
+                        &mut TestTranscript(Fr::one())
+                    ),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn small_characteristic() {
+        assert!(has_distinct_nodes::<Fr>(1 << 20));
+        assert!(has_distinct_nodes::<BinaryField128b>(1));
+        assert!(!has_distinct_nodes::<BinaryField128b>(2));
+
+        // Sums of multilinear extensions have degree 1, and can be proven in
+        // characteristic two.
+        let rng = &mut test_rng();
+        let num_vars = 5;
+        let mut poly = VirtualPolynomial::<BinaryField128b>::new(num_vars);
+        let f = poly.add_mle(&DenseMultilinearExtension::rand(num_vars, rng));
+        let g = poly.add_mle(&DenseMultilinearExtension::rand(num_vars, rng));
+        poly.add_product(BinaryField128b::rand(rng), [f]);
+        poly.add_product(BinaryField128b::rand(rng), [g]);
+        let sum = poly.sum_over_hypercube();
+        let transcript = || TestTranscript(BinaryField128b::rand(&mut test_rng()));
+        let (proof, claim, _) =
+            SumcheckProof::prove(&poly, BindingOrder::LowToHigh, &mut transcript());
+        assert_eq!(claim.expected_evaluation, poly.evaluate(&claim.point));
+        assert_eq!(
+            proof.verify(sum, num_vars, 1, BindingOrder::LowToHigh, &mut transcript()),
+            Ok(claim)
+        );
+
+        // The evaluations at `0, 1, 2` of a polynomial of degree 2 collide.
+        assert_eq!(
+            proof.verify(sum, num_vars, 2, BindingOrder::LowToHigh, &mut transcript()),
+            Err(SumcheckError::UnsupportedDegree { degree: 2 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "smaller than the characteristic")]
+    fn prove_rejects_small_characteristic() {
+        let rng = &mut test_rng();
+        let mut poly = VirtualPolynomial::<BinaryField128b>::new(3);
+        let f = poly.add_mle(&DenseMultilinearExtension::rand(3, rng));
+        poly.add_product(BinaryField128b::one(), [f, f]);
+        SumcheckProof::prove(
+            &poly,
+            BindingOrder::LowToHigh,
+            &mut TestTranscript(BinaryField128b::one()),
+        );
+    }
+}
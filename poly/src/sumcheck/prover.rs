@@ -0,0 +1,164 @@
+//! The prover of the sumcheck protocol.
+
+use crate::{
+    sumcheck::{
+        has_distinct_nodes, BindingOrder, SubClaim, SumcheckProof, Transcript, VirtualPolynomial,
+    },
+    DenseMultilinearExtension,
+};
+use ark_ff::Field;
+use ark_std::{vec, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Returns the evaluations at `x_i = 0` and `x_i = 1` of the `b`-th pair of
+/// `table`, where `x_i` is the next variable to bind.
+#[inline]
+fn pair<F: Field>(table: &[F], b: usize, order: BindingOrder) -> (F, F) {
+    match order {
+        BindingOrder::LowToHigh => (table[2 * b], table[2 * b + 1]),
+        BindingOrder::HighToLow => (table[b], table[b + table.len() / 2]),
+    }
+}
+
+/// Adds to `sums` the evaluations at `0, 1, ..., d` of the products
+/// `products` at a pair, given the evaluations `pair(i)` of the `i`-th
+/// multilinear extension at `x_i = 0` and `x_i = 1`.
+pub(super) fn accumulate<F: Field>(
+    sums: &mut [F],
+    product: &mut [F],
+    products: &[(F, Vec<usize>)],
+    pair: impl Fn(usize) -> (F, F),
+) {
+    for (coefficient, mles) in products {
+        product.fill(*coefficient);
+        for i in mles {
+            let (low, high) = pair(*i);
+            let step = high - low;
+            let mut value = low;
+            for p in product.iter_mut() {
+                *p *= value;
+                value += step;
+            }
+        }
+        sums.iter_mut().zip(&*product).for_each(|(s, p)| *s += p);
+    }
+}
+
+/// Returns the evaluations at `0, 1, ..., degree` of the round polynomial,
+/// when `num_vars` variables are left.
+fn round_polynomial<F: Field>(
+    poly: &VirtualPolynomial<F>,
+    tables: &[DenseMultilinearExtension<F>],
+    num_vars: usize,
+    degree: usize,
+    order: BindingOrder,
+) -> Vec<F> {
+    let num_pairs = 1 << (num_vars - 1);
+    let zeros = || (vec![F::zero(); degree + 1], vec![F::zero(); degree + 1]);
+    let step = |(mut sums, mut product): (Vec<F>, Vec<F>), b| {
+        accumulate(&mut sums, &mut product, poly.products(), |i| {
+            pair(&tables[i].evaluations, b, order)
+        });
+        (sums, product)
+    };
+
+    #[cfg(feature = "parallel")]
+    let sums = (0..num_pairs)
+        .into_par_iter()
+        .fold(zeros, step)
+        .map(|(sums, _)| sums)
+        .reduce(
+            || vec![F::zero(); degree + 1],
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                a
+            },
+        );
+    #[cfg(not(feature = "parallel"))]
+    let (sums, _) = (0..num_pairs).fold(zeros(), step);
+    sums
+}
+
+/// Runs the rounds of the prover on `poly`, starting from the claim `claim`,
+/// and appends their round polynomials and challenges.
+///
+/// Returns the final claim and the evaluations of the multilinear extensions
+/// of `poly` at the bound point.
+pub(super) fn prove_rounds<F: Field, T: Transcript<F>>(
+    poly: &VirtualPolynomial<F>,
+    order: BindingOrder,
+    degree: usize,
+    mut claim: F,
+    round_polynomials: &mut Vec<Vec<F>>,
+    challenges: &mut Vec<F>,
+    transcript: &mut T,
+) -> (F, Vec<F>) {
+    let num_vars = poly.num_vars();
+    let mut tables = poly.mles().to_vec();
+    for round in 0..num_vars {
+        let evals = round_polynomial(poly, &tables, num_vars - round, degree, order);
+        transcript.append_field_elements(b"sumcheck_round", &evals);
+        let challenge = transcript.challenge(b"sumcheck_challenge");
+        claim = super::interpolate_at(&evals, challenge);
+        match order {
+            BindingOrder::LowToHigh => {
+                DenseMultilinearExtension::batch_fix_variables_in_place(&mut tables, &[challenge])
+            },
+            BindingOrder::HighToLow => {
+                DenseMultilinearExtension::batch_fix_high_variables_in_place(
+                    &mut tables,
+                    &[challenge],
+                )
+            },
+        }
+        round_polynomials.push(evals);
+        challenges.push(challenge);
+    }
+    (claim, tables.iter().map(|table| table[0]).collect())
+}
+
+impl<F: Field> SumcheckProof<F> {
+    /// Proves that `poly` sums to [`VirtualPolynomial::sum_over_hypercube`],
+    /// binding its variables in `order`.
+    ///
+    /// Returns the proof, the claim left to the verifier, and the
+    /// evaluations of the multilinear extensions of `poly` at the point of
+    /// the claim.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the characteristic of the field is at most the degree of
+    /// `poly`.
+    pub fn prove<T: Transcript<F>>(
+        poly: &VirtualPolynomial<F>,
+        order: BindingOrder,
+        transcript: &mut T,
+    ) -> (Self, SubClaim<F>, Vec<F>) {
+        let degree = poly.degree().max(1);
+        assert!(
+            has_distinct_nodes::<F>(degree),
+            "the degree must be smaller than the characteristic"
+        );
+        let num_vars = poly.num_vars();
+        let sum = poly.sum_over_hypercube();
+        transcript.append_field_elements(b"sumcheck_claim", &[sum]);
+
+        let mut round_polynomials = Vec::with_capacity(num_vars);
+        let mut challenges = Vec::with_capacity(num_vars);
+        let (claim, mle_values) = prove_rounds(
+            poly,
+            order,
+            degree,
+            sum,
+            &mut round_polynomials,
+            &mut challenges,
+            transcript,
+        );
+        let claim = SubClaim {
+            point: order.point(challenges),
+            expected_evaluation: claim,
+        };
+        (Self { round_polynomials }, claim, mle_values)
+    }
+}
@@ -0,0 +1,142 @@
+//! A prover of the sumcheck protocol over streamed multilinear extensions.
+
+use crate::{
+    evaluations::multivariate::multilinear::FixedVariables,
+    sumcheck::{
+        has_distinct_nodes,
+        prover::{accumulate, prove_rounds},
+        BindingOrder, SubClaim, SumcheckProof, Transcript, VirtualPolynomial,
+    },
+    StreamingMultilinearExtension,
+};
+use ark_ff::Field;
+use ark_std::{vec, vec::*};
+
+/// Returns the evaluations at `0, 1, ..., degree` of the round polynomial
+/// after the lowest variables are bound to `challenges`, in one pass over the
+/// streams of `mles`.
+fn streaming_round_polynomial<F: Field, M: StreamingMultilinearExtension<F>>(
+    mles: &[M],
+    products: &[(F, Vec<usize>)],
+    challenges: &[F],
+    num_pairs: usize,
+    degree: usize,
+) -> Vec<F> {
+    let mut streams: Vec<_> = mles
+        .iter()
+        .map(|mle| FixedVariables::new(mle.stream(), challenges))
+        .collect();
+    let mut pairs = vec![(F::zero(), F::zero()); mles.len()];
+    let mut sums = vec![F::zero(); degree + 1];
+    let mut product = vec![F::zero(); degree + 1];
+    for _ in 0..num_pairs {
+        for (pair, stream) in pairs.iter_mut().zip(&mut streams) {
+            *pair = (stream.next().unwrap(), stream.next().unwrap());
+        }
+        accumulate(&mut sums, &mut product, products, |i| pairs[i]);
+    }
+    sums
+}
+
+impl<F: Field> SumcheckProof<F> {
+    /// Proves that the polynomial `sum_j c_j * prod_{k in S_j} f_k` in
+    /// `num_vars` variables, given by the streamed multilinear extensions
+    /// `mles` and the products `products` as in [`VirtualPolynomial`], sums
+    /// to its sum over the boolean hypercube, binding its variables from
+    /// [`BindingOrder::LowToHigh`].
+    ///
+    /// This trades time for space, as in the streaming provers of Cormode,
+    /// Thaler and Yi, and of Blendy. The first `k` rounds each take a pass
+    /// over the streams and `O(k)` field elements of memory per multilinear
+    /// extension, where `k` is the least integer such that
+    /// `2^(num_vars - k) <= max_table_size`. The remaining variables are then
+    /// fixed in one more pass, and the rounds left are proven as in
+    /// [`Self::prove`] on tables of at most `max_table_size` evaluations.
+    /// With `max_table_size = 2^(num_vars / 2)`, the prover takes
+    /// `O(sqrt(N))` memory and `O(N log N)` time for `N = 2^num_vars`.
+    ///
+    /// The proof and the claim are the same as those of [`Self::prove`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a multilinear extension does not have `num_vars` variables,
+    /// if a product refers to an unknown multilinear extension, or if the
+    /// characteristic of the field is at most the degree of the polynomial.
+    pub fn prove_streaming<M, T>(
+        num_vars: usize,
+        mles: &[M],
+        products: &[(F, Vec<usize>)],
+        max_table_size: usize,
+        transcript: &mut T,
+    ) -> (Self, SubClaim<F>, Vec<F>)
+    where
+        M: StreamingMultilinearExtension<F>,
+        T: Transcript<F>,
+    {
+        assert!(
+            mles.iter().all(|mle| mle.stream_num_vars() == num_vars),
+            "the multilinear extension has the wrong number of variables"
+        );
+        assert!(
+            products
+                .iter()
+                .all(|(_, indices)| indices.iter().all(|i| *i < mles.len())),
+            "unknown multilinear extension"
+        );
+        let degree = products
+            .iter()
+            .map(|(_, indices)| indices.len())
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        assert!(
+            has_distinct_nodes::<F>(degree),
+            "the degree must be smaller than the characteristic"
+        );
+        let num_streaming_rounds = (0..=num_vars)
+            .find(|k| 1 << (num_vars - k) <= max_table_size)
+            .unwrap_or(num_vars);
+
+        let mut round_polynomials = Vec::with_capacity(num_vars);
+        let mut challenges = Vec::with_capacity(num_vars);
+        let mut claim = F::zero();
+        for round in 0..num_streaming_rounds {
+            let num_pairs = 1 << (num_vars - round - 1);
+            let evals = streaming_round_polynomial(mles, products, &challenges, num_pairs, degree);
+            if round == 0 {
+                transcript.append_field_elements(b"sumcheck_claim", &[evals[0] + evals[1]]);
+            }
+            transcript.append_field_elements(b"sumcheck_round", &evals);
+            let challenge = transcript.challenge(b"sumcheck_challenge");
+            claim = super::interpolate_at(&evals, challenge);
+            round_polynomials.push(evals);
+            challenges.push(challenge);
+        }
+
+        let poly = VirtualPolynomial::from_parts(
+            num_vars - num_streaming_rounds,
+            mles.iter()
+                .map(|mle| mle.stream_fix_variables(&challenges))
+                .collect(),
+            products.to_vec(),
+        );
+        if num_streaming_rounds == 0 {
+            claim = poly.sum_over_hypercube();
+            transcript.append_field_elements(b"sumcheck_claim", &[claim]);
+        }
+        let (claim, mle_values) = prove_rounds(
+            &poly,
+            BindingOrder::LowToHigh,
+            degree,
+            claim,
+            &mut round_polynomials,
+            &mut challenges,
+            transcript,
+        );
+        let claim = SubClaim {
+            point: challenges,
+            expected_evaluation: claim,
+        };
+        (Self { round_polynomials }, claim, mle_values)
+    }
+}
@@ -0,0 +1,169 @@
+//! Sums of products of multilinear extensions, which are the polynomials
+//! proven by the sumcheck protocol.
+
+use crate::{DenseMultilinearExtension, MultilinearExtension, Polynomial};
+use ark_ff::Field;
+use ark_std::vec::*;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A polynomial `sum_j c_j * prod_{k in S_j} f_k` in `num_vars` variables,
+/// given by its multilinear extensions `f_k` and its products `(c_j, S_j)`.
+///
+/// A multilinear extension can appear in several products while being stored
+/// once, and the degree of the polynomial in each variable is the size of its
+/// largest product.
+///
+/// # Example
+/// ```
+/// use ark_poly::{sumcheck::VirtualPolynomial, DenseMultilinearExtension, MultilinearExtension};
+/// use ark_test_curves::bls12_381::Fr;
+/// use ark_std::test_rng;
+///
+/// let rng = &mut test_rng();
+/// let (f, g) = (DenseMultilinearExtension::rand(3, rng), DenseMultilinearExtension::rand(3, rng));
+///
+/// // The polynomial `f * g + 2 * f`.
+/// let mut poly = VirtualPolynomial::new(3);
+/// let (f, g) = (poly.add_mle(&f), poly.add_mle(&g));
+/// poly.add_product(Fr::from(1u64), [f, g]);
+/// poly.add_product(Fr::from(2u64), [f]);
+/// assert_eq!(poly.degree(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VirtualPolynomial<F: Field> {
+    num_vars: usize,
+    mles: Vec<DenseMultilinearExtension<F>>,
+    products: Vec<(F, Vec<usize>)>,
+}
+
+impl<F: Field> VirtualPolynomial<F> {
+    /// Constructs the zero polynomial in `num_vars` variables.
+    pub const fn new(num_vars: usize) -> Self {
+        Self {
+            num_vars,
+            mles: Vec::new(),
+            products: Vec::new(),
+        }
+    }
+
+    /// Constructs the polynomial with the multilinear extensions `mles` and
+    /// the products `products`, which are assumed to be consistent.
+    pub(super) const fn from_parts(
+        num_vars: usize,
+        mles: Vec<DenseMultilinearExtension<F>>,
+        products: Vec<(F, Vec<usize>)>,
+    ) -> Self {
+        Self {
+            num_vars,
+            mles,
+            products,
+        }
+    }
+
+    /// Adds the multilinear extension `mle`, and returns its index for
+    /// [`Self::add_product`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mle` does not have `self.num_vars()` variables.
+    pub fn add_mle<M: MultilinearExtension<F>>(&mut self, mle: &M) -> usize {
+        assert_eq!(
+            mle.num_vars(),
+            self.num_vars,
+            "the multilinear extension has the wrong number of variables"
+        );
+        self.mles
+            .push(DenseMultilinearExtension::from_evaluations_vec(
+                self.num_vars,
+                mle.to_evaluations(),
+            ));
+        self.mles.len() - 1
+    }
+
+    /// Adds `coefficient` times the product of the multilinear extensions of
+    /// indices `mles` to the polynomial.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index was not returned by [`Self::add_mle`].
+    pub fn add_product(&mut self, coefficient: F, mles: impl IntoIterator<Item = usize>) {
+        let mles: Vec<usize> = mles.into_iter().collect();
+        assert!(
+            mles.iter().all(|i| *i < self.mles.len()),
+            "unknown multilinear extension"
+        );
+        self.products.push((coefficient, mles));
+    }
+
+    /// Returns the number of variables of the polynomial.
+    pub const fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the degree of the polynomial in each variable, which is the
+    /// size of its largest product.
+    pub fn degree(&self) -> usize {
+        self.products
+            .iter()
+            .map(|(_, mles)| mles.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the multilinear extensions of the polynomial.
+    pub fn mles(&self) -> &[DenseMultilinearExtension<F>] {
+        &self.mles
+    }
+
+    /// Returns the products `(c_j, S_j)` of the polynomial, as coefficients
+    /// and indices of multilinear extensions.
+    pub fn products(&self) -> &[(F, Vec<usize>)] {
+        &self.products
+    }
+
+    /// Returns the value of the polynomial given the values of its
+    /// multilinear extensions.
+    pub(crate) fn combine(&self, mle_values: &[F]) -> F {
+        self.products
+            .iter()
+            .map(|(coefficient, mles)| {
+                mles.iter()
+                    .fold(*coefficient, |acc, i| acc * mle_values[*i])
+            })
+            .sum()
+    }
+
+    /// Evaluates the polynomial at `point`.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(point.len(), self.num_vars, "the point has the wrong size");
+        let point = point.to_vec();
+        let mle_values: Vec<F> = cfg_iter!(self.mles)
+            .map(|mle| mle.evaluate(&point))
+            .collect();
+        self.combine(&mle_values)
+    }
+
+    /// Returns the sum of the evaluations of the polynomial over the boolean
+    /// hypercube.
+    pub fn sum_over_hypercube(&self) -> F {
+        let zeros = || (F::zero(), vec![F::zero(); self.mles.len()]);
+        let step = |(sum, mut mle_values): (F, Vec<F>), x: usize| {
+            mle_values
+                .iter_mut()
+                .zip(&self.mles)
+                .for_each(|(value, mle)| *value = mle[x]);
+            (sum + self.combine(&mle_values), mle_values)
+        };
+
+        #[cfg(feature = "parallel")]
+        let sum = (0..1usize << self.num_vars)
+            .into_par_iter()
+            .fold(zeros, step)
+            .map(|(sum, _)| sum)
+            .sum();
+        #[cfg(not(feature = "parallel"))]
+        let (sum, _) = (0..1usize << self.num_vars).fold(zeros(), step);
+        sum
+    }
+}
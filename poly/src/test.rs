@@ -1,6 +1,6 @@
-use crate::domain::*;
-use ark_ff::{PrimeField, UniformRand};
-use ark_std::test_rng;
+use crate::{domain::*, univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+use ark_ff::{binary::BinaryField128b, FftField, UniformRand};
+use ark_std::{test_rng, vec::*};
 use ark_test_curves::{
     bls12_381::{Fr, G1Projective},
     bn384_small_two_adicity::Fr as BNFr,
@@ -11,7 +11,7 @@ use ark_test_curves::{
 #[test]
 fn fft_composition() {
     fn test_fft_composition<
-        F: PrimeField,
+        F: FftField,
         T: DomainCoeff<F> + UniformRand + core::fmt::Debug + Eq,
         R: ark_std::rand::Rng,
         D: EvaluationDomain<F>,
@@ -57,4 +57,168 @@ fn fft_composition() {
     test_fft_composition::<Fr, G1Projective, _, GeneralEvaluationDomain<Fr>>(rng, 10);
     // This will result in a mixed-radix domain being used.
     test_fft_composition::<BNFr, BNFr, _, MixedRadixEvaluationDomain<_>>(rng, 12);
+    test_fft_composition::<Fr, G1Projective, _, BluesteinEvaluationDomain<Fr>>(rng, 6);
+    test_fft_composition::<BinaryField128b, BinaryField128b, _, AdditiveEvaluationDomain<_>>(
+        rng, 8,
+    );
+}
+
+// Test that batched (I)FFTs agree with (I)FFTs of each column.
+#[test]
+fn fft_batch() {
+    fn test_fft_batch<
+        F: FftField,
+        T: DomainCoeff<F> + UniformRand + core::fmt::Debug + Eq,
+        R: ark_std::rand::Rng,
+        D: EvaluationDomain<F>,
+    >(
+        rng: &mut R,
+        sizes: &[usize],
+    ) {
+        for &size in sizes {
+            let domain = D::new(size).unwrap();
+            let coset_domain = domain.get_coset(F::GENERATOR).unwrap();
+            let columns: Vec<Vec<T>> = (0..5)
+                .map(|i| (0..size - i % 2).map(|_| T::rand(rng)).collect())
+                .collect();
+
+            for d in [domain, coset_domain] {
+                let expected: Vec<Vec<T>> = columns.iter().map(|c| d.fft(c)).collect();
+                let mut batch = columns.clone();
+                d.fft_batch(&mut batch);
+                assert_eq!(batch, expected);
+                d.ifft_batch(&mut batch);
+                let mut padded = columns.clone();
+                for column in &mut padded {
+                    column.resize(d.size(), T::zero());
+                }
+                assert_eq!(batch, padded);
+
+                let mut column = padded[0].clone();
+                d.fft_slice_in_place(&mut column);
+                assert_eq!(column, expected[0]);
+                d.ifft_slice_in_place(&mut column);
+                assert_eq!(column, padded[0]);
+
+                let mut matrix = padded.concat();
+                d.fft_batch_column_major(&mut matrix);
+                assert_eq!(matrix, expected.concat());
+                d.ifft_batch_column_major(&mut matrix);
+                assert_eq!(matrix, padded.concat());
+            }
+
+            let mut batch = columns.clone();
+            domain.coset_fft_batch(F::GENERATOR, &mut batch);
+            let expected: Vec<Vec<T>> = columns.iter().map(|c| coset_domain.fft(c)).collect();
+            assert_eq!(batch, expected);
+            domain.coset_ifft_batch(F::GENERATOR, &mut batch);
+            let expected: Vec<Vec<T>> = expected.iter().map(|c| coset_domain.ifft(c)).collect();
+            assert_eq!(batch, expected);
+        }
+    }
+
+    let rng = &mut test_rng();
+
+    test_fft_batch::<Fr, Fr, _, Radix2EvaluationDomain<Fr>>(rng, &[2, 16, 2048]);
+    test_fft_batch::<Fr, Fr, _, GeneralEvaluationDomain<Fr>>(rng, &[64]);
+    test_fft_batch::<Fr, G1Projective, _, Radix2EvaluationDomain<Fr>>(rng, &[32]);
+    test_fft_batch::<BNFr, BNFr, _, MixedRadixEvaluationDomain<_>>(rng, &[12, 48]);
+    test_fft_batch::<BNFr, BNFr, _, GeneralEvaluationDomain<_>>(rng, &[24]);
+    test_fft_batch::<Fr, Fr, _, BluesteinEvaluationDomain<Fr>>(rng, &[6]);
+    test_fft_batch::<BinaryField128b, BinaryField128b, _, AdditiveEvaluationDomain<_>>(
+        rng,
+        &[2, 32],
+    );
+}
+
+// Test the rest of the `EvaluationDomain` API, whose default methods rely on
+// the accessors of each domain, against naive evaluations.
+#[test]
+fn domain_api() {
+    fn test_domain_api<F: FftField, R: ark_std::rand::Rng, D: EvaluationDomain<F>>(
+        rng: &mut R,
+        sizes: &[usize],
+    ) {
+        for &size in sizes {
+            let domain = D::new(size).unwrap();
+            for d in [domain, domain.get_coset(F::GENERATOR).unwrap()] {
+                for (i, x) in d.elements().enumerate() {
+                    assert_eq!(d.element(i), x);
+                    assert!(d.evaluate_vanishing_polynomial(x).is_zero());
+                }
+
+                let poly = DensePolynomial::<F>::rand(d.size() - 1, rng);
+                let evals = poly.evaluate_over_domain_by_ref(d);
+                assert_eq!(evals.interpolate_by_ref(), poly);
+
+                let tau = d.sample_element_outside_domain(rng);
+                let value: F = d
+                    .evaluate_all_lagrange_coefficients(tau)
+                    .iter()
+                    .zip(&evals.evals)
+                    .map(|(l, e)| *l * e)
+                    .sum();
+                assert_eq!(value, poly.evaluate(&tau));
+                assert_eq!(
+                    d.vanishing_polynomial().evaluate(&tau),
+                    d.evaluate_vanishing_polynomial(tau)
+                );
+
+                let poly = DensePolynomial::<F>::rand(3 * d.size(), rng);
+                let mut coeffs = poly.coeffs.clone();
+                d.reduce_mod_vanishing(&mut coeffs);
+                assert!(coeffs.len() <= d.size());
+                let expected: Vec<F> = d.elements().map(|x| poly.evaluate(&x)).collect();
+                assert_eq!(d.fft(&coeffs), expected);
+            }
+        }
+    }
+
+    let rng = &mut test_rng();
+
+    test_domain_api::<Fr, _, Radix2EvaluationDomain<Fr>>(rng, &[1, 2, 64]);
+    test_domain_api::<BNFr, _, MixedRadixEvaluationDomain<_>>(rng, &[12, 48]);
+    test_domain_api::<Fr, _, GeneralEvaluationDomain<Fr>>(rng, &[32]);
+    test_domain_api::<Fr, _, BluesteinEvaluationDomain<Fr>>(rng, &[6]);
+    test_domain_api::<BinaryField128b, _, AdditiveEvaluationDomain<_>>(rng, &[1, 2, 64]);
+}
+
+#[test]
+fn fft_extension() {
+    use ark_ff::{FftField, Field, Fp2, Fp2Config, MontFp, Zero};
+
+    struct Fr2Config;
+
+    impl Fp2Config for Fr2Config {
+        type Fp = Fr;
+
+        // The multiplicative generator is a quadratic non-residue.
+        const NONRESIDUE: Fr = MontFp!("7");
+
+        const FROBENIUS_COEFF_FP2_C1: &'static [Fr] = &[MontFp!("1"), MontFp!("-1")];
+    }
+
+    type Fr2 = Fp2<Fr2Config>;
+
+    fn test_fft_extension<R: ark_std::rand::Rng, D: EvaluationDomain<Fr>>(rng: &mut R, domain: D) {
+        let coeffs: Vec<Fr2> = (0..domain.size()).map(|_| Fr2::rand(rng)).collect();
+        let evals = domain.fft_extension(&coeffs);
+        let expected: Vec<Fr2> = domain
+            .elements()
+            .map(|x| {
+                let x = Fr2::from_base_prime_field(x);
+                coeffs.iter().rev().fold(Fr2::zero(), |acc, c| acc * x + c)
+            })
+            .collect();
+        assert_eq!(evals, expected);
+        assert_eq!(domain.ifft_extension(&evals), coeffs);
+    }
+
+    let rng = &mut test_rng();
+    for size in [1, 2, 64] {
+        let domain = GeneralEvaluationDomain::<Fr>::new(size).unwrap();
+        test_fft_extension(rng, domain);
+        test_fft_extension(rng, domain.get_coset(Fr::GENERATOR).unwrap());
+    }
+    test_fft_extension(rng, BluesteinEvaluationDomain::<Fr>::new(6).unwrap());
 }
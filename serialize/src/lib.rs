@@ -11,6 +11,8 @@
 mod error;
 mod flags;
 mod impls;
+#[cfg(feature = "serde")]
+pub mod serde;
 
 pub use ark_std::io::{Read, Write};
 
@@ -0,0 +1,202 @@
+//! Adapters between [`CanonicalSerialize`]/[`CanonicalDeserialize`] and
+//! [`serde`](https://serde.rs).
+//!
+//! Values are encoded with their canonical byte representation. For
+//! human-readable formats such as JSON this is a lowercase hex string, and
+//! for binary formats such as CBOR or bincode it is a byte array.
+//! Deserialization always validates the decoded value, and rejects encodings
+//! followed by trailing bytes.
+//!
+//! The modules [`compressed`] and [`uncompressed`] can be used with
+//! `#[serde(with = "...")]` on fields whose type implements the canonical
+//! serialization traits:
+//!
+//! ```ignore
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Signature {
+//!     #[serde(with = "ark_serialize::serde::compressed")]
+//!     r: G1Affine,
+//!     #[serde(with = "ark_serialize::serde::uncompressed")]
+//!     s: Fr,
+//! }
+//! ```
+
+use crate::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use ark_std::{fmt, string::String, vec::Vec};
+use serde::de::{Error as _, SeqAccess, Visitor};
+use serde::ser::Error as _;
+pub use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// (De)serializes values using [`Compress::Yes`].
+pub mod compressed {
+    use super::*;
+
+    /// Serializes with [`Compress::Yes`]; use as `#[serde(with = "ark_serialize::serde::compressed")]`.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: CanonicalSerialize,
+        S: Serializer,
+    {
+        serialize_with_mode(value, serializer, Compress::Yes)
+    }
+
+    /// Deserializes with [`Compress::Yes`]; use as `#[serde(with = "ark_serialize::serde::compressed")]`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: CanonicalDeserialize,
+        D: Deserializer<'de>,
+    {
+        deserialize_with_mode(deserializer, Compress::Yes)
+    }
+}
+
+/// (De)serializes values using [`Compress::No`].
+pub mod uncompressed {
+    use super::*;
+
+    /// Serializes with [`Compress::No`]; use as `#[serde(with = "ark_serialize::serde::uncompressed")]`.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: CanonicalSerialize,
+        S: Serializer,
+    {
+        serialize_with_mode(value, serializer, Compress::No)
+    }
+
+    /// Deserializes with [`Compress::No`]; use as `#[serde(with = "ark_serialize::serde::uncompressed")]`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: CanonicalDeserialize,
+        D: Deserializer<'de>,
+    {
+        deserialize_with_mode(deserializer, Compress::No)
+    }
+}
+
+fn serialize_with_mode<T, S>(
+    value: &T,
+    serializer: S,
+    compress: Compress,
+) -> Result<S::Ok, S::Error>
+where
+    T: CanonicalSerialize,
+    S: Serializer,
+{
+    let mut bytes = Vec::with_capacity(value.serialized_size(compress));
+    value
+        .serialize_with_mode(&mut bytes, compress)
+        .map_err(S::Error::custom)?;
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&hex_encode(&bytes))
+    } else {
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+fn deserialize_with_mode<'de, T, D>(deserializer: D, compress: Compress) -> Result<T, D::Error>
+where
+    T: CanonicalDeserialize,
+    D: Deserializer<'de>,
+{
+    let bytes = if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor)?
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor)?
+    };
+    let mut reader = bytes.as_slice();
+    let value =
+        T::deserialize_with_mode(&mut reader, compress, Validate::Yes).map_err(D::Error::custom)?;
+    if !reader.is_empty() {
+        return Err(D::Error::custom("trailing bytes after the encoded value"));
+    }
+    Ok(value)
+}
+
+/// Accepts a hex string, a byte array, or a sequence of bytes.
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a hex string or a byte array")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        hex_decode(v).ok_or_else(|| E::custom("invalid hex string"))
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut s = String::with_capacity(2 * bytes.len());
+    for byte in bytes {
+        s.push(DIGITS[(byte >> 4) as usize] as char);
+        s.push(DIGITS[(byte & 0xf) as usize] as char);
+    }
+    s
+}
+
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    const fn nibble(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+    let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    s.chunks(2)
+        .map(|pair| Some((nibble(pair[0])? << 4) | nibble(pair[1])?))
+        .collect()
+}
+
+/// Implements [`Serialize`] and [`Deserialize`] for a type by delegating to
+/// [`compressed`].
+///
+/// The generic parameters of the impl are given in square brackets:
+///
+/// ```ignore
+/// ark_serialize::impl_serde_via_canonical!([P: FpConfig<N>, const N: usize] Fp<P, N>);
+/// ```
+#[macro_export]
+macro_rules! impl_serde_via_canonical {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> $crate::serde::Serialize for $ty {
+            fn serialize<__S: $crate::serde::Serializer>(
+                &self,
+                serializer: __S,
+            ) -> Result<__S::Ok, __S::Error> {
+                $crate::serde::compressed::serialize(self, serializer)
+            }
+        }
+
+        impl<'de, $($generics)*> $crate::serde::Deserialize<'de> for $ty {
+            fn deserialize<__D: $crate::serde::Deserializer<'de>>(
+                deserializer: __D,
+            ) -> Result<Self, __D::Error> {
+                $crate::serde::compressed::deserialize(deserializer)
+            }
+        }
+    };
+}
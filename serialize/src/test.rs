@@ -317,3 +317,51 @@ fn test_serialize_macro() {
 
     assert_eq!(tuple_bytes, macro_bytes);
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use ark_std::format;
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Wrapper {
+        #[serde(with = "crate::serde::compressed")]
+        dummy: Dummy,
+        #[serde(with = "crate::serde::uncompressed")]
+        dummy_uncompressed: Dummy,
+        #[serde(with = "crate::serde::compressed")]
+        values: Vec<u64>,
+    }
+
+    let value = Wrapper {
+        dummy: Dummy,
+        dummy_uncompressed: Dummy,
+        values: vec![1, 2, u64::MAX],
+    };
+
+    let json = serde_json::to_string(&value).unwrap();
+    let expected_values = String::from("0300000000000000")
+        + "0100000000000000"
+        + "0200000000000000"
+        + "ffffffffffffffff";
+    assert_eq!(
+        json,
+        format!(r#"{{"dummy":"64","dummy_uncompressed":"64c8","values":"{expected_values}"}}"#)
+    );
+    assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), value);
+
+    let mut cbor = Vec::new();
+    ciborium::into_writer(&value, &mut cbor).unwrap();
+    assert_eq!(
+        ciborium::from_reader::<Wrapper, _>(&cbor[..]).unwrap(),
+        value
+    );
+
+    let invalid = r#"{"dummy":"6","dummy_uncompressed":"64c8","values":"00"}"#;
+    assert!(serde_json::from_str::<Wrapper>(invalid).is_err());
+
+    let trailing =
+        format!(r#"{{"dummy":"6400","dummy_uncompressed":"64c8","values":"{expected_values}"}}"#);
+    assert!(serde_json::from_str::<Wrapper>(&trailing).is_err());
+}
@@ -0,0 +1,28 @@
+use ark_algebra_bench_templates::{criterion_main, f_bench, field_common, prime_field, sqrt};
+use ark_ff::fields::{Fp256, MontBackend};
+use ark_test_curves::solinas::{Fp25519, Secp256k1Fq};
+
+#[derive(ark_ff::MontConfig)]
+#[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
+#[generator = "2"]
+pub struct Fp25519MontConfig;
+pub type Fp25519Mont = Fp256<MontBackend<Fp25519MontConfig, 4>>;
+
+#[derive(ark_ff::MontConfig)]
+#[modulus = "115792089237316195423570985008687907853269984665640564039457584007908834671663"]
+#[generator = "3"]
+pub struct Secp256k1FqMontConfig;
+pub type Secp256k1FqMont = Fp256<MontBackend<Secp256k1FqMontConfig, 4>>;
+
+// Compare `SolinasBackend` against `MontBackend` for the same moduli.
+f_bench!(prime, "Solinas", Fp25519);
+f_bench!(prime, "Montgomery", Fp25519Mont);
+f_bench!(prime, "Solinas", Secp256k1Fq);
+f_bench!(prime, "Montgomery", Secp256k1FqMont);
+
+criterion_main!(
+    fp25519::benches,
+    fp25519mont::benches,
+    secp256k1fq::benches,
+    secp256k1fqmont::benches,
+);
@@ -0,0 +1,137 @@
+//! Tests of the binary fields of [`ark_ff::binary`].
+
+#[cfg(test)]
+mod tests {
+    use ark_algebra_test_templates::num_bigint::BigUint;
+    use ark_algebra_test_templates::*;
+    use ark_ff::{binary::*, AdditiveGroup, BigInt, FftField, Field, One, PrimeField, UniformRand};
+    use ark_std::{str::FromStr, test_rng};
+
+    test_field!(bf1; BinaryField1b; binary);
+    test_field!(bf2; BinaryField2b; binary);
+    test_field!(bf4; BinaryField4b; binary);
+    test_field!(bf8; BinaryField8b; binary);
+    test_field!(bf16; BinaryField16b; binary);
+    test_field!(bf32; BinaryField32b; binary);
+    test_field!(bf64; BinaryField64b; binary);
+    test_field!(bf128; BinaryField128b; binary);
+    test_field!(poly64; BinaryPolyField64b; binary);
+    test_field!(poly128; BinaryPolyField128b; binary);
+
+    #[test]
+    fn test_gf2_prime_field() {
+        let one = BinaryField1b::one();
+        assert_eq!(BinaryField1b::MODULUS, BigInt([2]));
+        assert_eq!(BinaryField1b::from_bigint(BigInt([1])), Some(one));
+        assert_eq!(BinaryField1b::from_bigint(BigInt([2])), None);
+        assert_eq!(one.into_bigint(), BigInt([1]));
+        assert_eq!(BinaryField1b::from_le_bytes_mod_order(&[3, 1]), one);
+        assert_eq!(BinaryField1b::from_str("-3"), Ok(one));
+        assert_eq!(BigUint::from(one), BigUint::from(1u8));
+        assert_eq!(BinaryField1b::from(BigUint::from(6u8)), BinaryField1b::ZERO);
+        assert_eq!(<BinaryField1b as FftField>::GENERATOR, one);
+    }
+
+    /// Checks that `X_i^2 = X_{i-1} X_i + 1`, where `X_i` is the element with
+    /// coordinates `(0, 1)` over the previous field of the tower.
+    macro_rules! check_tower_generator {
+        ($($field:ident),+) => {
+            $({
+                let bits = <$field as Field>::extension_degree();
+                let x = $field::new(1 << (bits / 2));
+                let x_prev = $field::new(if bits == 2 { 1 } else { 1 << (bits / 4) });
+                assert_eq!(x.square(), x_prev * x + $field::one(), "{}", stringify!($field));
+            })+
+        };
+    }
+
+    #[test]
+    fn test_tower_generators() {
+        check_tower_generator!(
+            BinaryField2b,
+            BinaryField4b,
+            BinaryField8b,
+            BinaryField16b,
+            BinaryField32b,
+            BinaryField64b,
+            BinaryField128b
+        );
+    }
+
+    /// Checks that the embedding of `$sub` into `$field` is a ring morphism.
+    macro_rules! check_embedding {
+        ($($sub:ident => $field:ident),+) => {
+            let mut rng = test_rng();
+            $(
+                for _ in 0..100 {
+                    let (a, b) = ($sub::rand(&mut rng), $sub::rand(&mut rng));
+                    let (x, y) = ($field::from(a), $field::from(b));
+                    assert_eq!(x * y, $field::from(a * b));
+                    assert_eq!(x + y, $field::from(a + b));
+                    assert_eq!(x.inverse(), a.inverse().map($field::from));
+                }
+            )+
+        };
+    }
+
+    #[test]
+    fn test_tower_embeddings() {
+        check_embedding!(
+            BinaryField2b => BinaryField4b,
+            BinaryField4b => BinaryField8b,
+            BinaryField8b => BinaryField16b,
+            BinaryField16b => BinaryField32b,
+            BinaryField32b => BinaryField64b,
+            BinaryField64b => BinaryField128b,
+            BinaryField2b => BinaryField128b,
+            BinaryField8b => BinaryField128b
+        );
+    }
+
+    #[test]
+    fn test_poly_moduli() {
+        let x = BinaryPolyField64b::new(2);
+        assert_eq!(x.pow([64]), BinaryPolyField64b::new(0b1_1011));
+        let x = BinaryPolyField128b::new(2);
+        assert_eq!(x.pow([128]), BinaryPolyField128b::new(0b1000_0111));
+    }
+
+    /// Checks that `GENERATOR` has order `2^k - 1`, given the prime factors of
+    /// `2^k - 1`.
+    macro_rules! check_generator {
+        ($($field:ident: [$($p:expr),+]),+) => {
+            $({
+                let order = u128::MAX >> (128 - <$field as Field>::extension_degree());
+                let g = <$field as FftField>::GENERATOR;
+                assert_eq!(g.pow([order as u64, (order >> 64) as u64]), $field::one());
+                $(
+                    let cofactor = order / $p;
+                    let exp = [cofactor as u64, (cofactor >> 64) as u64];
+                    assert_ne!(g.pow(exp), $field::one(), "{}", stringify!($field));
+                )+
+            })+
+        };
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generator!(
+            BinaryField2b: [3],
+            BinaryField4b: [3, 5],
+            BinaryField8b: [3, 5, 17],
+            BinaryField16b: [3, 5, 17, 257],
+            BinaryField32b: [3, 5, 17, 257, 65537],
+            BinaryField64b: [3, 5, 17, 257, 641, 65537, 6700417],
+            BinaryPolyField64b: [3, 5, 17, 257, 641, 65537, 6700417],
+            BinaryField128b: [3, 5, 17, 257, 641, 65537, 274177, 6700417, 67280421310721],
+            BinaryPolyField128b: [3, 5, 17, 257, 641, 65537, 274177, 6700417, 67280421310721]
+        );
+    }
+
+    #[test]
+    fn test_from_integers() {
+        assert_eq!(BinaryField128b::from(3u64), BinaryField128b::one());
+        assert_eq!(BinaryField128b::from(-2i32), BinaryField128b::ZERO);
+        assert_eq!(BinaryPolyField64b::from(true), BinaryPolyField64b::one());
+    }
+}
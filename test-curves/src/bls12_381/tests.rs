@@ -3,6 +3,8 @@ use crate::bls12_381::*;
 use ark_algebra_test_templates::*;
 
 test_field!(fr; Fr; mont_prime_field);
+#[cfg(feature = "ct")]
+test_constant_time!(fr_ct; Fr);
 #[cfg(feature = "bls12_381_curve")]
 test_field!(fq; Fq; mont_prime_field);
 #[cfg(feature = "bls12_381_curve")]
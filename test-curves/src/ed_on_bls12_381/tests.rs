@@ -4,3 +4,6 @@ use ark_algebra_test_templates::*;
 test_field!(fr; Fr; mont_prime_field);
 test_field!(fq; Fq; mont_prime_field);
 test_group!(g; Projective; te);
+
+#[cfg(feature = "ct")]
+test_constant_time!(g_ct; Projective; group);
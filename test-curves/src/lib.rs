@@ -31,3 +31,9 @@ pub mod bn384_small_two_adicity;
 pub mod secp256k1;
 
 pub mod fp128;
+
+pub mod solinas;
+
+pub mod small_fields;
+
+pub mod binary_fields;
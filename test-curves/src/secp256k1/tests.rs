@@ -4,3 +4,10 @@ use ark_algebra_test_templates::{test_field, test_group};
 test_field!(fq; Fq; mont_prime_field);
 test_field!(fr; Fr; mont_prime_field);
 test_group!(g1; G1Projective);
+
+#[cfg(feature = "ct")]
+ark_algebra_test_templates::test_constant_time!(fq_ct; Fq);
+#[cfg(feature = "ct")]
+ark_algebra_test_templates::test_constant_time!(fr_ct; Fr);
+#[cfg(feature = "ct")]
+ark_algebra_test_templates::test_constant_time!(g1_ct; G1Projective; group);
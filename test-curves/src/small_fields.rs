@@ -0,0 +1,76 @@
+//! 31-bit prime fields using [`SmallFpBackend`](ark_ff::SmallFpBackend).
+use ark_ff::fields::{SmallFp, SmallFpConfig};
+
+/// BabyBear: `p = 15 * 2^27 + 1`
+#[derive(SmallFpConfig)]
+#[modulus = "2013265921"]
+#[generator = "31"]
+pub struct BabyBearConfig;
+pub type BabyBear = SmallFp<BabyBearConfig>;
+
+/// KoalaBear: `p = 2^31 - 2^24 + 1`
+#[derive(SmallFpConfig)]
+#[modulus = "2130706433"]
+#[generator = "3"]
+pub struct KoalaBearConfig;
+pub type KoalaBear = SmallFp<KoalaBearConfig>;
+
+/// Mersenne-31: `p = 2^31 - 1`
+#[derive(SmallFpConfig)]
+#[modulus = "2147483647"]
+#[generator = "7"]
+pub struct Mersenne31Config;
+pub type Mersenne31 = SmallFp<Mersenne31Config>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solinas::GoldilocksConfig;
+    use ark_algebra_test_templates::*;
+    use ark_ff::{
+        simd_dispatch, AdditiveGroup, Field, PackedField, PackedFp64, PackedSmallFp,
+        SolinasBackend, UniformRand,
+    };
+    use ark_std::{test_rng, vec::Vec};
+
+    test_field!(baby_bear; BabyBear; prime);
+    test_field!(koala_bear; KoalaBear; prime);
+    test_field!(mersenne31; Mersenne31; prime);
+
+    /// Checks that lane-wise arithmetic on `P` agrees with scalar arithmetic.
+    fn check_packed<P: PackedField>() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let mut a: Vec<P::Scalar> = (0..P::WIDTH).map(|_| P::Scalar::rand(&mut rng)).collect();
+            let mut b: Vec<P::Scalar> = (0..P::WIDTH).map(|_| P::Scalar::rand(&mut rng)).collect();
+            // Exercise the extreme values too.
+            b[0] = P::Scalar::ZERO;
+            b[1] = -P::Scalar::ONE;
+            b[2] = -P::Scalar::ONE;
+            a[2] = -P::Scalar::ONE;
+            let (pa, pb) = (P::from_slice(&a), P::from_slice(&b));
+            for i in 0..P::WIDTH {
+                assert_eq!((pa + pb).extract(i), a[i] + b[i]);
+                assert_eq!((pa - pb).extract(i), a[i] - b[i]);
+                assert_eq!((pa * pb).extract(i), a[i] * b[i]);
+                assert_eq!((-pb).extract(i), -b[i]);
+                assert_eq!(pa.square().extract(i), a[i].square());
+            }
+            let mut out = b.clone();
+            pa.write_to_slice(&mut out);
+            assert_eq!(out, a);
+            assert_eq!(P::broadcast(a[0]).extract(P::WIDTH - 1), a[0]);
+        }
+    }
+
+    #[test]
+    fn test_packed() {
+        check_packed::<PackedSmallFp<BabyBearConfig>>();
+        check_packed::<PackedSmallFp<KoalaBearConfig>>();
+        check_packed::<PackedSmallFp<Mersenne31Config>>();
+        check_packed::<PackedFp64<SolinasBackend<GoldilocksConfig, 1>>>();
+        // Within `simd_dispatch`, the SIMD kernels are inlined.
+        simd_dispatch(check_packed::<PackedSmallFp<BabyBearConfig>>);
+        simd_dispatch(check_packed::<PackedFp64<SolinasBackend<GoldilocksConfig, 1>>>);
+    }
+}
@@ -0,0 +1,163 @@
+//! Prime fields with pseudo-Mersenne moduli `p = 2^k - c`, using
+//! [`SolinasBackend`].
+use ark_ff::fields::{Fp256, Fp64, SolinasBackend};
+
+/// `p = 2^31 - 1`
+#[derive(ark_ff::SolinasConfig)]
+#[modulus = "2147483647"]
+#[generator = "7"]
+pub struct M31Config;
+pub type M31 = Fp64<SolinasBackend<M31Config, 1>>;
+
+/// `p = 2^64 - 2^32 + 1`
+#[derive(ark_ff::SolinasConfig)]
+#[modulus = "18446744069414584321"]
+#[generator = "7"]
+pub struct GoldilocksConfig;
+pub type Goldilocks = Fp64<SolinasBackend<GoldilocksConfig, 1>>;
+
+/// `p = 2^255 - 19`
+#[derive(ark_ff::SolinasConfig)]
+#[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
+#[generator = "2"]
+pub struct Fp25519Config;
+pub type Fp25519 = Fp256<SolinasBackend<Fp25519Config, 4>>;
+
+/// `p = 2^256 - 2^32 - 977`
+#[derive(ark_ff::SolinasConfig)]
+#[modulus = "115792089237316195423570985008687907853269984665640564039457584007908834671663"]
+#[generator = "3"]
+pub struct Secp256k1FqConfig;
+pub type Secp256k1Fq = Fp256<SolinasBackend<Secp256k1FqConfig, 4>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::*;
+    use ark_ff::{
+        fields::{Fp256, Fp64, MontBackend},
+        PrimeField, SolinasFp,
+    };
+    use ark_std::{test_rng, vec, One};
+
+    test_field!(m31; M31; prime);
+    test_field!(goldilocks; Goldilocks; prime);
+    test_field!(fp25519; Fp25519; prime);
+    test_field!(secp256k1_fq; Secp256k1Fq; prime);
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "2147483647"]
+    #[generator = "7"]
+    struct M31MontConfig;
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "18446744069414584321"]
+    #[generator = "7"]
+    struct GoldilocksMontConfig;
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
+    #[generator = "2"]
+    struct Fp25519MontConfig;
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "115792089237316195423570985008687907853269984665640564039457584007908834671663"]
+    #[generator = "3"]
+    struct Secp256k1FqMontConfig;
+
+    /// Checks that arithmetic in `S` agrees with arithmetic in the
+    /// Montgomery field `M` of the same modulus.
+    fn check_against_montgomery<S: PrimeField, M: PrimeField<BigInt = S::BigInt>>() {
+        let mut rng = test_rng();
+        let to_mont = |s: S| M::from_bigint(s.into_bigint()).unwrap();
+        // Include the elements closest to the modulus, which exercise the
+        // final conditional subtraction.
+        let mut samples = vec![S::ZERO, S::one(), -S::one(), -S::one().double()];
+        samples.extend((0..100).map(|_| S::rand(&mut rng)));
+        for a in &samples {
+            for b in &samples {
+                assert_eq!(to_mont(*a * b), to_mont(*a) * to_mont(*b));
+                assert_eq!(to_mont(*a + b), to_mont(*a) + to_mont(*b));
+                assert_eq!(to_mont(*a - b), to_mont(*a) - to_mont(*b));
+            }
+            assert_eq!(to_mont(a.square()), to_mont(*a).square());
+            assert_eq!(a.inverse().map(to_mont), to_mont(*a).inverse());
+        }
+    }
+
+    /// secp256k1 over [`Secp256k1Fq`], to run the group tests on top of
+    /// [`SolinasBackend`]. The published curve in [`crate::secp256k1`] keeps
+    /// [`MontBackend`].
+    #[cfg(feature = "secp256k1")]
+    mod secp256k1 {
+        use super::Secp256k1Fq as Fq;
+        use crate::secp256k1::Fr;
+        use ark_algebra_test_templates::test_group;
+        use ark_ec::{
+            models::CurveConfig,
+            short_weierstrass::{Affine, Projective, SWCurveConfig},
+        };
+        use ark_ff::{AdditiveGroup, Field, SolinasFp};
+
+        #[derive(Clone, Default, PartialEq, Eq)]
+        struct Config;
+
+        impl CurveConfig for Config {
+            type BaseField = Fq;
+            type ScalarField = Fr;
+
+            const COFACTOR: &'static [u64] = &[0x1];
+            const COFACTOR_INV: Fr = Fr::ONE;
+        }
+
+        impl SWCurveConfig for Config {
+            const COEFF_A: Fq = Fq::ZERO;
+            const COEFF_B: Fq = SolinasFp!("7");
+            const GENERATOR: Affine<Self> = Affine::new_unchecked(
+                SolinasFp!(
+                    "55066263022277343669578718895168534326250603453777594175500187360389116729240"
+                ),
+                SolinasFp!(
+                    "32670510020758816978083085130507043184471273380659243275938904335757337482424"
+                ),
+            );
+
+            #[inline(always)]
+            fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+                Self::BaseField::ZERO
+            }
+        }
+
+        type G1Projective = Projective<Config>;
+
+        test_group!(g1; G1Projective);
+
+        #[cfg(feature = "ct")]
+        ark_algebra_test_templates::test_constant_time!(g1_ct; G1Projective; group);
+    }
+
+    #[test]
+    fn test_against_montgomery() {
+        check_against_montgomery::<M31, Fp64<MontBackend<M31MontConfig, 1>>>();
+        check_against_montgomery::<Goldilocks, Fp64<MontBackend<GoldilocksMontConfig, 1>>>();
+        check_against_montgomery::<Fp25519, Fp256<MontBackend<Fp25519MontConfig, 4>>>();
+        check_against_montgomery::<Secp256k1Fq, Fp256<MontBackend<Secp256k1FqMontConfig, 4>>>();
+    }
+
+    #[test]
+    fn test_solinas_macro() {
+        const NEG_ONE: M31 = SolinasFp!("-1");
+        // Inputs larger than the modulus are reduced.
+        const U64_MAX: M31 = SolinasFp!("18446744073709551615");
+        const P_PLUS_ONE: Fp25519 = SolinasFp!(
+            "57896044618658097711785492504343953926634992332820282019728792003956564819950"
+        );
+        const MAX: Secp256k1Fq = SolinasFp!(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(NEG_ONE, -M31::one());
+        assert_eq!(U64_MAX, M31::from(u64::MAX));
+        assert_eq!(P_PLUS_ONE, Fp25519::one());
+        assert_eq!(MAX, Secp256k1Fq::from(4294968272u64));
+    }
+}
@@ -0,0 +1,212 @@
+//! Timing-leakage tests in the style of dudect
+//! ([Reparaz, Balasch, Verbauwhede 2016](https://ia.cr/2016/1123)).
+//!
+//! An operation is run on inputs drawn from two classes: a fixed input, and
+//! uniformly random inputs. The classes are interleaved at random, and
+//! Welch's t-test is applied to the two resulting distributions of running
+//! times. A large t-statistic is strong evidence that the running time
+//! depends on the input.
+//!
+//! The measurements are slow and sensitive to the load of the machine, so
+//! the timing tests are ignored by default. Run them with
+//! `cargo test --features ct -- --ignored`.
+
+use ark_std::{rand::Rng, vec::*};
+use core::hint::black_box;
+use std::time::Instant;
+
+/// Number of measurements taken per test.
+pub const MEASUREMENTS: usize = 20_000;
+
+/// dudect considers a t-statistic above this threshold to be a definite
+/// timing leak.
+pub const T_THRESHOLD: f64 = 10.0;
+
+/// Runs `op` on [`MEASUREMENTS`] inputs, each of which is either `fixed` or
+/// sampled with `random`, and returns the absolute value of Welch's
+/// t-statistic between the running times of the two classes.
+///
+/// Measurements above the 90th percentile are discarded, as they are
+/// dominated by interrupts and other environmental noise.
+pub fn welch_t_statistic<T: Copy, O, R: Rng>(
+    rng: &mut R,
+    fixed: T,
+    mut random: impl FnMut(&mut R) -> T,
+    mut op: impl FnMut(T) -> O,
+) -> f64 {
+    let inputs: Vec<(bool, T)> = (0..MEASUREMENTS)
+        .map(|_| {
+            if rng.gen() {
+                (true, fixed)
+            } else {
+                (false, random(rng))
+            }
+        })
+        .collect();
+
+    // Warm up caches and branch predictors.
+    for &(_, input) in inputs.iter().take(MEASUREMENTS / 10) {
+        black_box(op(black_box(input)));
+    }
+
+    let timings: Vec<(bool, u128)> = inputs
+        .iter()
+        .map(|&(class, input)| {
+            let input = black_box(input);
+            let start = Instant::now();
+            black_box(op(input));
+            (class, start.elapsed().as_nanos())
+        })
+        .collect();
+
+    let mut sorted: Vec<u128> = timings.iter().map(|(_, t)| *t).collect();
+    sorted.sort_unstable();
+    let cutoff = sorted[sorted.len() * 9 / 10];
+
+    let (mut fixed_stats, mut random_stats) = (Stats::default(), Stats::default());
+    for (class, t) in timings.into_iter().filter(|(_, t)| *t <= cutoff) {
+        if class {
+            fixed_stats.push(t as f64);
+        } else {
+            random_stats.push(t as f64);
+        }
+    }
+    fixed_stats.t_statistic(&random_stats).abs()
+}
+
+/// Online mean and variance (Welford's algorithm).
+#[derive(Default)]
+struct Stats {
+    n: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Stats {
+    fn push(&mut self, x: f64) {
+        self.n += 1.0;
+        let delta = x - self.mean;
+        self.mean += delta / self.n;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        self.m2 / (self.n - 1.0)
+    }
+
+    fn t_statistic(&self, other: &Self) -> f64 {
+        let denominator = (self.variance() / self.n + other.variance() / other.n).sqrt();
+        if denominator == 0.0 {
+            0.0
+        } else {
+            (self.mean - other.mean) / denominator
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! test_constant_time {
+    ($mod_name:ident; $group:ty; group) => {
+        mod $mod_name {
+            use super::*;
+            use ark_ec::PrimeGroup;
+            use ark_std::{test_rng, One, UniformRand, Zero};
+            use $crate::constant_time::{welch_t_statistic, T_THRESHOLD};
+
+            const ITERATIONS: usize = 100;
+
+            type ScalarField = <$group as PrimeGroup>::ScalarField;
+
+            #[test]
+            fn test_mul_ct_correctness() {
+                let rng = &mut test_rng();
+                let scalars = [
+                    ScalarField::zero(),
+                    ScalarField::one(),
+                    -ScalarField::one(),
+                    ScalarField::from(16u64),
+                ];
+                for _ in 0..ITERATIONS {
+                    let g = <$group>::rand(rng);
+                    let s = ScalarField::rand(rng);
+                    assert_eq!(g.mul_ct(&s), g * s);
+                    for s in scalars {
+                        assert_eq!(g.mul_ct(&s), g * s);
+                    }
+                    assert!(<$group>::zero().mul_ct(&s).is_zero());
+                }
+            }
+
+            #[test]
+            #[ignore = "timing measurement; run with --ignored"]
+            fn test_ct_scalar_mul() {
+                let rng = &mut test_rng();
+                let g = <$group>::rand(rng);
+                let t =
+                    welch_t_statistic(rng, ScalarField::one(), ScalarField::rand, |s| g.mul_ct(&s));
+                assert!(t < T_THRESHOLD, "mul_ct leaks timing: t = {t}");
+            }
+        }
+    };
+    ($mod_name:ident; $field:ty) => {
+        mod $mod_name {
+            use super::*;
+            use ark_ff::{AdditiveGroup, Field};
+            use ark_std::{test_rng, One, UniformRand, Zero};
+            use $crate::constant_time::{welch_t_statistic, T_THRESHOLD};
+
+            fn random_nonzero<R: ark_std::rand::Rng>(rng: &mut R) -> $field {
+                loop {
+                    let a = <$field>::rand(rng);
+                    if !a.is_zero() {
+                        return a;
+                    }
+                }
+            }
+
+            #[test]
+            #[ignore = "timing measurement; run with --ignored"]
+            fn test_ct_mul() {
+                let rng = &mut test_rng();
+                let b = <$field>::rand(rng);
+                let t = welch_t_statistic(rng, <$field>::zero(), <$field>::rand, |a| a * b);
+                assert!(t < T_THRESHOLD, "mul leaks timing: t = {t}");
+            }
+
+            #[test]
+            #[ignore = "timing measurement; run with --ignored"]
+            fn test_ct_add_sub_neg() {
+                let rng = &mut test_rng();
+                let b = <$field>::rand(rng);
+                let t = welch_t_statistic(rng, <$field>::zero(), <$field>::rand, |a| {
+                    (a + b) - (-a).double()
+                });
+                assert!(t < T_THRESHOLD, "add/sub/neg leak timing: t = {t}");
+            }
+
+            #[test]
+            #[ignore = "timing measurement; run with --ignored"]
+            fn test_ct_inverse() {
+                let rng = &mut test_rng();
+                let t = welch_t_statistic(rng, <$field>::one(), random_nonzero, |a| a.inverse());
+                assert!(t < T_THRESHOLD, "inverse leaks timing: t = {t}");
+            }
+
+            #[test]
+            #[ignore = "timing measurement; run with --ignored"]
+            fn test_ct_sqrt() {
+                let rng = &mut test_rng();
+                let t = welch_t_statistic(rng, <$field>::one(), <$field>::rand, |a| a.sqrt());
+                assert!(t < T_THRESHOLD, "sqrt leaks timing: t = {t}");
+            }
+
+            #[test]
+            #[ignore = "timing measurement; run with --ignored"]
+            fn test_ct_legendre() {
+                let rng = &mut test_rng();
+                let t = welch_t_statistic(rng, <$field>::one(), <$field>::rand, |a| a.legendre());
+                assert!(t < T_THRESHOLD, "legendre leaks timing: t = {t}");
+            }
+        }
+    };
+}
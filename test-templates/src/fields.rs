@@ -37,6 +37,18 @@ pub fn sum_of_products_test_helper<F: ark_ff::Field, const N: usize>(rng: &mut i
     assert_eq!(result_1, result_2, "length: {N}");
 }
 
+/// The random half of [`sum_of_products_test_helper`], for fields of
+/// characteristic two, where `2` has no inverse.
+pub fn binary_field_sum_of_products_test_helper<F: ark_ff::Field, const N: usize>(
+    rng: &mut impl Rng,
+) {
+    let a: [_; N] = core::array::from_fn(|_| F::rand(rng));
+    let b: [_; N] = core::array::from_fn(|_| F::rand(rng));
+    let result_1 = F::sum_of_products(&a, &b);
+    let result_2 = a.into_iter().zip(b).map(|(a, b)| a * b).sum::<F>();
+    assert_eq!(result_1, result_2, "length: {N}");
+}
+
 pub fn prime_field_sum_of_products_test_helper<F: ark_ff::PrimeField, const N: usize>(
     a_max: F,
     b_max: F,
@@ -51,7 +63,47 @@ pub fn prime_field_sum_of_products_test_helper<F: ark_ff::PrimeField, const N: u
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __test_field {
+    // The checks below differ between fields of odd characteristic and binary
+    // fields, whose elements are packed into bits, where `2` has no inverse,
+    // and where the small towers sample zero often.
+    (@buffer_size generic; $field: ty) => {
+        buffer_bit_byte_size(<$field as Field>::BasePrimeField::MODULUS_BIT_SIZE as usize).1 *
+        (<$field>::extension_degree() as usize)
+    };
+    (@buffer_size binary; $field: ty) => {
+        buffer_bit_byte_size(<$field>::extension_degree() as usize).1
+    };
+    (@inverses generic; $one: ident; $($x: ident),+) => {
+        $(assert_eq!($x * $x.inverse().unwrap(), $one, "Mul by inverse failed");)+
+    };
+    (@inverses binary; $one: ident; $($x: ident),+) => {
+        $(
+            match $x.inverse() {
+                Some(inverse) => assert_eq!($x * inverse, $one, "Mul by inverse failed"),
+                None => assert!($x.is_zero(), "Mul by inverse failed"),
+            }
+        )+
+    };
+    (@square_legendre generic; $b: ident) => {
+        assert_eq!($b.legendre(), LegendreSymbol::QuadraticResidue);
+    };
+    (@square_legendre binary; $b: ident) => {
+        if $b.is_zero() {
+            assert_eq!($b.legendre(), LegendreSymbol::Zero);
+        } else {
+            assert_eq!($b.legendre(), LegendreSymbol::QuadraticResidue);
+        }
+    };
+    (@sum_of_products generic; $field: ty; $rng: ident; $($n: literal),+) => {
+        $($crate::fields::sum_of_products_test_helper::<$field, $n>($rng);)+
+    };
+    (@sum_of_products binary; $field: ty; $rng: ident; $($n: literal),+) => {
+        $($crate::fields::binary_field_sum_of_products_test_helper::<$field, $n>($rng);)+
+    };
     ($field: ty) => {
+        $crate::__test_field!(@common $field; generic);
+    };
+    (@common $field: ty; $kind: ident) => {
         #[test]
         pub fn test_frobenius() {
             use ark_ff::Field;
@@ -89,9 +141,7 @@ macro_rules! __test_field {
                 for validate in [Validate::Yes, Validate::No] {
                     let buf_size = <$field>::zero().serialized_size(compress);
 
-                    let buffer_size =
-                        buffer_bit_byte_size(<$field as Field>::BasePrimeField::MODULUS_BIT_SIZE as usize).1 *
-                        (<$field>::extension_degree() as usize);
+                    let buffer_size = $crate::__test_field!(@buffer_size $kind; $field);
                     assert_eq!(buffer_size, buf_size);
 
                     let mut rng = ark_std::test_rng();
@@ -139,6 +189,20 @@ macro_rules! __test_field {
 
         }
 
+        $crate::__if_serde! {
+            #[test]
+            fn test_serde() {
+                use ark_std::UniformRand;
+                let mut rng = ark_std::test_rng();
+                for _ in 0..ITERATIONS {
+                    let a = <$field>::rand(&mut rng);
+                    let json = $crate::serde_json::to_string(&a).unwrap();
+                    let b: $field = $crate::serde_json::from_str(&json).unwrap();
+                    assert_eq!(a, b);
+                }
+            }
+        }
+
         #[test]
         fn test_add_properties() {
             use ark_std::UniformRand;
@@ -241,9 +305,7 @@ macro_rules! __test_field {
                 assert_eq!(zero * c, zero, "Mul by zero failed");
 
                 // Inverses
-                assert_eq!(a * a.inverse().unwrap(), one, "Mul by inverse failed");
-                assert_eq!(b * b.inverse().unwrap(), one, "Mul by inverse failed");
-                assert_eq!(c * c.inverse().unwrap(), one, "Mul by inverse failed");
+                $crate::__test_field!(@inverses $kind; one; a, b, c);
 
                 // Associativity and commutativity simultaneously
                 let t0 = (a * b) * c;
@@ -313,16 +375,7 @@ macro_rules! __test_field {
             let rng = &mut test_rng();
 
             for _ in 0..ITERATIONS {
-                $crate::fields::sum_of_products_test_helper::<$field, 1>(rng);
-                $crate::fields::sum_of_products_test_helper::<$field, 2>(rng);
-                $crate::fields::sum_of_products_test_helper::<$field, 3>(rng);
-                $crate::fields::sum_of_products_test_helper::<$field, 4>(rng);
-                $crate::fields::sum_of_products_test_helper::<$field, 5>(rng);
-                $crate::fields::sum_of_products_test_helper::<$field, 6>(rng);
-                $crate::fields::sum_of_products_test_helper::<$field, 7>(rng);
-                $crate::fields::sum_of_products_test_helper::<$field, 8>(rng);
-                $crate::fields::sum_of_products_test_helper::<$field, 9>(rng);
-                $crate::fields::sum_of_products_test_helper::<$field, 10>(rng);
+                $crate::__test_field!(@sum_of_products $kind; $field; rng; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
             }
         }
 
@@ -348,7 +401,7 @@ macro_rules! __test_field {
 
                     let a = <$field>::rand(rng);
                     let b = a.square();
-                    assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
+                    $crate::__test_field!(@square_legendre $kind; b);
                 }
             }
         }
@@ -374,6 +427,55 @@ macro_rules! __test_field {
             }
         }
     };
+    ($field: ty; binary) => {
+        $crate::__test_field!(@common $field; binary);
+
+        #[test]
+        fn test_characteristic_two() {
+            use ark_ff::AdditiveGroup;
+            use ark_std::UniformRand;
+            let mut rng = test_rng();
+            let zero = <$field>::zero();
+            let one = <$field>::one();
+            assert_eq!(<$field>::characteristic(), [2]);
+            assert!(one.double().is_zero());
+            assert_eq!(<$field>::from(2u64), zero);
+            assert_eq!(<$field>::from(3u64), one);
+            assert!(<$field>::from(2u64).inverse().is_none());
+
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let b = <$field>::rand(&mut rng);
+                // Every element is its own negation.
+                assert_eq!(-a, a);
+                assert_eq!(a - b, a + b);
+                // Squaring is additive, and a bijection, so the square root
+                // is unique.
+                assert_eq!((a + b).square(), a.square() + b.square());
+                assert_eq!(a.square().sqrt().unwrap(), a);
+                assert_eq!(a.sqrt().unwrap().square(), a);
+                if !a.is_zero() {
+                    assert_eq!(a.legendre(), LegendreSymbol::QuadraticResidue);
+                }
+            }
+        }
+
+        #[test]
+        fn test_base_prime_field_elements() {
+            use ark_std::UniformRand;
+            type Base = <$field as Field>::BasePrimeField;
+            let mut rng = test_rng();
+            let degree = <$field>::extension_degree() as usize;
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let coeffs: Vec<Base> = a.to_base_prime_field_elements().collect();
+                assert_eq!(coeffs.len(), degree);
+                assert_eq!(<$field>::from_base_prime_field_elems(coeffs).unwrap(), a);
+            }
+            let too_many = vec![Base::one(); degree + 1];
+            assert!(<$field>::from_base_prime_field_elems(too_many).is_none());
+        }
+    };
     ($field: ty; fft) => {
         $crate::__test_field!($field);
 
@@ -185,6 +185,23 @@ macro_rules! __test_group {
                 }
             }
         }
+
+        $crate::__if_serde! {
+            #[test]
+            fn test_serde() {
+                let mut rng = ark_std::test_rng();
+                for a in [<$group>::zero(), <$group>::rand(&mut rng)] {
+                    let json = $crate::serde_json::to_string(&a).unwrap();
+                    let b: $group = $crate::serde_json::from_str(&json).unwrap();
+                    assert_eq!(a, b);
+
+                    let a = a.into_affine();
+                    let json = $crate::serde_json::to_string(&a).unwrap();
+                    let b: <$group as CurveGroup>::Affine = $crate::serde_json::from_str(&json).unwrap();
+                    assert_eq!(a, b);
+                }
+            }
+        }
     };
     ($group:ty; msm) => {
         #[test]
@@ -2,6 +2,8 @@
 pub mod groups;
 #[macro_use]
 pub mod fields;
+#[macro_use]
+pub mod constant_time;
 pub mod glv;
 pub mod msm;
 #[macro_use]
@@ -13,3 +15,22 @@ pub use h2c::*;
 pub use num_bigint;
 pub use num_integer;
 pub use num_traits;
+pub use serde_json;
+
+/// Expands to its input if the `serde` feature of this crate is enabled.
+///
+/// This lets the test macros gate tests on the features of this crate rather
+/// than on those of the crate invoking them.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_serde {
+    ($($item:tt)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_serde {
+    ($($item:tt)*) => {};
+}